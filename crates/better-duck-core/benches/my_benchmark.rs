@@ -4,11 +4,76 @@
 use better_duck_core::ffi::{
     duckdb_append_int32, duckdb_appender, duckdb_bind_int32, duckdb_prepared_statement,
 };
-use better_duck_core::{connection::Connection, types::appendable::AppendAble};
-use std::hint::black_box;
+use better_duck_core::{
+    connection::Connection,
+    types::{
+        appendable::AppendAble,
+        from_row::{row_field, FromRow},
+        value::DuckValue,
+    },
+    DuckRow,
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+/// The system allocator, counting every allocation so benchmarks can report how many
+/// they make. DuckDB's own C++ allocations do not go through it.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: every call is forwarded unchanged to `System`, which upholds the contract.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(
+        &self,
+        layout: Layout,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: the caller upholds `GlobalAlloc::alloc`'s contract for `layout`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+    ) {
+        // SAFETY: `ptr` was allocated by `System` with `layout`, as the caller guarantees.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: `ptr` was allocated by `System` with `layout`, as the caller guarantees.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Returns how many allocations `f` makes per call, averaged over `runs` calls.
+fn allocations_per_call(
+    runs: usize,
+    mut f: impl FnMut(),
+) -> f64 {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..runs {
+        f();
+    }
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) as f64 / runs as f64
+}
+
 /// A minimal two-integer row used for appender benchmarks.
 struct BenchRow(i32, i32);
 
@@ -53,6 +118,122 @@ fn bench_query_1000_rows(c: &mut Criterion) {
     });
 }
 
+/// Benchmark a small query whose rows are collected into a fresh `Vec` each time,
+/// against draining them into one reused `Vec`. Besides the timings, prints how many
+/// Rust-side allocations each variant makes per query.
+fn bench_small_query_collect_vs_drain_into(c: &mut Criterion) {
+    fn collect(conn: &mut Connection) {
+        let rows: Vec<_> = conn
+            .query("SELECT 1 AS a, 2 AS b")
+            .expect("select")
+            .collect::<better_duck_core::error::Result<_>>()
+            .expect("rows");
+        black_box(rows);
+    }
+    fn drain_into(
+        conn: &mut Connection,
+        rows: &mut Vec<DuckRow>,
+    ) {
+        conn.query("SELECT 1 AS a, 2 AS b").expect("select").drain_into(rows).expect("rows");
+        black_box(rows);
+    }
+
+    let mut conn = Connection::open_in_memory().expect("in-memory db");
+    let mut rows = Vec::new();
+    let collected = allocations_per_call(1_000, || collect(&mut conn));
+    let drained = allocations_per_call(1_000, || drain_into(&mut conn, &mut rows));
+    println!("small_query allocations per query: collect {collected:.1}, drain_into {drained:.1}");
+
+    let mut group = c.benchmark_group("small_query_10k");
+    group.bench_function("collect", |b| {
+        b.iter(|| {
+            for _ in 0..10_000 {
+                collect(&mut conn);
+            }
+        });
+    });
+    group.bench_function("drain_into", |b| {
+        b.iter(|| {
+            for _ in 0..10_000 {
+                drain_into(&mut conn, &mut rows);
+            }
+        });
+    });
+    group.finish();
+}
+
+/// Benchmark reading a 100k-row INTEGER column: row iterator vs `column_chunk`.
+fn bench_column_chunk_100k(c: &mut Criterion) {
+    const SQL: &str = "SELECT i::INTEGER AS v FROM range(100000) t(i)";
+    let mut conn = Connection::open_in_memory().expect("in-memory db");
+    let mut group = c.benchmark_group("int_column_100k");
+    group.bench_function("row_iterator", |b| {
+        b.iter(|| {
+            let values: Vec<Option<i32>> = conn
+                .query(SQL)
+                .expect("select")
+                .map(|row| match row.expect("row").get_idx(0) {
+                    Some(DuckValue::Int(v)) => Some(*v),
+                    _ => None,
+                })
+                .collect();
+            black_box(values);
+        });
+    });
+    group.bench_function("column_chunk", |b| {
+        b.iter(|| {
+            let values = conn.query(SQL).expect("select").column_chunk::<i32>(0).expect("column");
+            black_box(values);
+        });
+    });
+    group.finish();
+}
+
+/// A two-column row decoded by the typed-query benchmark.
+struct User {
+    id: i64,
+    name: String,
+}
+
+impl FromRow for User {
+    fn from_row(row: &DuckRow) -> better_duck_core::error::Result<Self> {
+        Ok(User {
+            id: row_field(row, "id")?.try_into()?,
+            name: row_field(row, "name")?.try_into()?,
+        })
+    }
+}
+
+/// Benchmark decoding 100k rows into `User`: `query` + `from_row` vs `query_typed`.
+fn bench_query_typed_100k(c: &mut Criterion) {
+    const SQL: &str = "SELECT i AS id, 'user_' || i AS name FROM range(100000) t(i)";
+    let mut conn = Connection::open_in_memory().expect("in-memory db");
+    let mut group = c.benchmark_group("query_typed_100k");
+    group.bench_function("query_from_row", |b| {
+        b.iter(|| {
+            let users = conn
+                .query(SQL)
+                .expect("select")
+                .map(|row| User::from_row(&row?))
+                .collect::<better_duck_core::error::Result<Vec<_>>>()
+                .expect("users");
+            black_box(users);
+        });
+    });
+    group.bench_function("query_typed", |b| {
+        b.iter(|| {
+            let mut stmt = conn.db().prepare(SQL).expect("prepare");
+            let users = stmt
+                .query_typed::<User>()
+                .expect("select")
+                .collect::<better_duck_core::error::Result<Vec<_>>>()
+                .expect("users");
+            black_box(users.iter().map(|u| u.id + u.name.len() as i64).sum::<i64>());
+        });
+    });
+    group.finish();
+}
+
 /// Benchmark executing a parameterised SELECT 100 times with different bindings.
 fn bench_execute_with_param(c: &mut Criterion) {
     let mut conn = Connection::open_in_memory().expect("in-memory db");
@@ -76,6 +257,32 @@ fn bench_execute_with_param(c: &mut Criterion) {
     });
 }
 
+/// Benchmark binding a 768-element embedding as a `&[f32]` (bulk `ARRAY` value) against
+/// a `Vec<f32>` (element-wise `DuckValue` list).
+fn bench_bind_f32_embedding(c: &mut Criterion) {
+    let mut conn = Connection::open_in_memory().expect("in-memory db");
+    let embedding: Vec<f32> = (0..768).map(|i| i as f32).collect();
+    let sql = "SELECT array_length(?::FLOAT[768]) AS n";
+    let mut group = c.benchmark_group("bind_f32_768");
+    group.bench_function("slice", |b| {
+        b.iter(|| {
+            let mut slice = embedding.as_slice();
+            let result =
+                conn.execute_with(sql, &mut [&mut slice as &mut dyn AppendAble]).expect("select");
+            black_box(result.count());
+        });
+    });
+    group.bench_function("vec_elementwise", |b| {
+        b.iter(|| {
+            let mut vec = embedding.clone();
+            let result =
+                conn.execute_with(sql, &mut [&mut vec as &mut dyn AppendAble]).expect("select");
+            black_box(result.count());
+        });
+    });
+    group.finish();
+}
+
 /// Benchmark bulk-inserting 10 000 rows via the DuckDB appender API.
 fn bench_appender_10k_rows(c: &mut Criterion) {
     c.bench_function("appender_10k_rows", |b| {
@@ -93,5 +300,14 @@ fn bench_appender_10k_rows(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_query_1000_rows, bench_execute_with_param, bench_appender_10k_rows);
+criterion_group!(
+    benches,
+    bench_query_1000_rows,
+    bench_small_query_collect_vs_drain_into,
+    bench_query_typed_100k,
+    bench_column_chunk_100k,
+    bench_execute_with_param,
+    bench_bind_f32_embedding,
+    bench_appender_10k_rows
+);
 criterion_main!(benches);
@@ -0,0 +1,349 @@
+//! Query results in the [Arrow C Data Interface] format.
+//!
+//! [`Connection::query_arrow`](crate::connection::Connection::query_arrow) returns an
+//! [`ArrowResult`]: the result's [`ArrowSchema`] plus an iterator of [`ArrowArray`]
+//! record batches, one per DuckDB chunk. Both are `#[repr(C)]` mirrors of the structs
+//! from the C Data Interface, so they can be handed to arrow-rs (or any other Arrow
+//! implementation) without copying the data, e.g. by moving them into
+//! `arrow::ffi::FFI_ArrowSchema` / `FFI_ArrowArray` with [`std::ptr::read`].
+//!
+//! Ownership follows the C Data Interface: a struct owns its buffers until its
+//! `release` callback runs. Dropping an [`ArrowSchema`] or [`ArrowArray`] calls it;
+//! taking the value out with [`std::mem::take`] leaves a released struct behind, so
+//! the consumer becomes responsible for releasing it.
+//!
+//! Requires the `arrow` feature.
+//!
+//! [Arrow C Data Interface]: https://arrow.apache.org/docs/format/CDataInterface.html
+
+use std::{
+    ffi::{c_char, c_void, CStr},
+    ptr, slice,
+};
+
+use crate::{
+    error::{Error, Result},
+    ffi::{
+        self, duckdb_arrow, duckdb_arrow_array, duckdb_arrow_column_count, duckdb_arrow_row_count,
+        duckdb_arrow_rows_changed, duckdb_arrow_schema, duckdb_destroy_arrow,
+        duckdb_query_arrow_array, duckdb_query_arrow_error, duckdb_query_arrow_schema,
+        DuckDBSuccess,
+    },
+};
+
+/// The C Data Interface `ArrowSchema`: the type of one field, with nested fields as
+/// `children`. The top-level schema of a result is a struct (`+s`) whose children are
+/// the columns.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ArrowSchema {
+    /// Null-terminated format string, e.g. `i` for `INTEGER` or `+s` for a struct.
+    pub format: *const c_char,
+    /// Null-terminated field name, or null.
+    pub name: *const c_char,
+    /// Binary-encoded key/value metadata, or null.
+    pub metadata: *const c_char,
+    /// `ARROW_FLAG_*` bits, e.g. `2` for a nullable field.
+    pub flags: i64,
+    /// Number of entries in `children`.
+    pub n_children: i64,
+    /// Child fields.
+    pub children: *mut *mut ArrowSchema,
+    /// Dictionary value type for dictionary-encoded fields, or null.
+    pub dictionary: *mut ArrowSchema,
+    /// Frees the schema's data; `None` once released.
+    pub release: Option<unsafe extern "C" fn(*mut ArrowSchema)>,
+    /// Producer-owned data used by `release`.
+    pub private_data: *mut c_void,
+}
+
+impl ArrowSchema {
+    /// Returns `true` if the schema has been released (or was never filled in).
+    pub fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+
+    /// Returns the format string, or `""` if it is unset.
+    pub fn format(&self) -> &str {
+        // SAFETY: a non-null `format` is a null-terminated string owned by this schema.
+        unsafe { c_str_or_empty(self.format) }
+    }
+
+    /// Returns the field name, or `""` if it is unset.
+    pub fn name(&self) -> &str {
+        // SAFETY: a non-null `name` is a null-terminated string owned by this schema.
+        unsafe { c_str_or_empty(self.name) }
+    }
+
+    /// Returns the child fields; for a result schema these are the columns.
+    pub fn children(&self) -> impl Iterator<Item = &ArrowSchema> {
+        let children: &[*mut ArrowSchema] = if self.children.is_null() || self.n_children <= 0 {
+            &[]
+        } else {
+            // SAFETY: `children` points to `n_children` child pointers owned by this schema.
+            unsafe { slice::from_raw_parts(self.children, self.n_children as usize) }
+        };
+        // SAFETY: each child pointer is valid for as long as the parent is not released.
+        children.iter().map(|child| unsafe { &**child })
+    }
+}
+
+/// An empty, released schema.
+impl Default for ArrowSchema {
+    fn default() -> Self {
+        ArrowSchema {
+            format: ptr::null(),
+            name: ptr::null(),
+            metadata: ptr::null(),
+            flags: 0,
+            n_children: 0,
+            children: ptr::null_mut(),
+            dictionary: ptr::null_mut(),
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+}
+
+impl Drop for ArrowSchema {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // SAFETY: the schema has not been released yet; `release` frees it and sets
+            // `self.release` to null.
+            unsafe { release(self) };
+        }
+    }
+}
+
+/// The C Data Interface `ArrowArray`: the data of one record batch. Its layout is
+/// described by the result's [`ArrowSchema`]; the top-level array is a struct array
+/// with one child per column.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ArrowArray {
+    /// Number of rows.
+    pub length: i64,
+    /// Number of null rows, or `-1` if not computed.
+    pub null_count: i64,
+    /// Logical offset into the buffers.
+    pub offset: i64,
+    /// Number of entries in `buffers`.
+    pub n_buffers: i64,
+    /// Number of entries in `children`.
+    pub n_children: i64,
+    /// Data buffers, as laid out by the field's format.
+    pub buffers: *mut *const c_void,
+    /// Child arrays.
+    pub children: *mut *mut ArrowArray,
+    /// Dictionary values for dictionary-encoded arrays, or null.
+    pub dictionary: *mut ArrowArray,
+    /// Frees the array's data; `None` once released.
+    pub release: Option<unsafe extern "C" fn(*mut ArrowArray)>,
+    /// Producer-owned data used by `release`.
+    pub private_data: *mut c_void,
+}
+
+impl ArrowArray {
+    /// Returns `true` if the array has been released (or was never filled in).
+    pub fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+}
+
+/// An empty, released array.
+impl Default for ArrowArray {
+    fn default() -> Self {
+        ArrowArray {
+            length: 0,
+            null_count: 0,
+            offset: 0,
+            n_buffers: 0,
+            n_children: 0,
+            buffers: ptr::null_mut(),
+            children: ptr::null_mut(),
+            dictionary: ptr::null_mut(),
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+}
+
+impl Drop for ArrowArray {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // SAFETY: the array has not been released yet; `release` frees it and sets
+            // `self.release` to null.
+            unsafe { release(self) };
+        }
+    }
+}
+
+/// The result of [`Connection::query_arrow`](crate::connection::Connection::query_arrow).
+///
+/// Owns the underlying `duckdb_arrow` and destroys it in [`Drop`]. Iterating yields one
+/// [`ArrowArray`] record batch per chunk of up to 2048 rows.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::connection::Connection;
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let result = conn.query_arrow("SELECT 1 AS a")?;
+/// let columns: Vec<_> = result.schema().children().map(|c| c.name().to_owned()).collect();
+/// assert_eq!(columns, ["a"]);
+/// let rows: i64 = result.map(|batch| batch.map(|b| b.length)).sum::<Result<_, _>>()?;
+/// assert_eq!(rows, 1);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ArrowResult {
+    inn: duckdb_arrow,
+    schema: ArrowSchema,
+}
+
+impl ArrowResult {
+    /// Takes ownership of a successful `duckdb_query_arrow` result and reads its schema.
+    pub(crate) fn new(inn: duckdb_arrow) -> Result<ArrowResult> {
+        let mut result = ArrowResult { inn, schema: ArrowSchema::default() };
+        let mut out = &mut result.schema as *mut ArrowSchema as duckdb_arrow_schema;
+        // SAFETY: `inn` is a valid duckdb_arrow and `out` points to a released schema
+        // that DuckDB fills in; the schema then owns its data until dropped.
+        let rc = unsafe { duckdb_query_arrow_schema(inn, &mut out) };
+        if rc != DuckDBSuccess {
+            return Err(result.error(rc, "failed to read the Arrow schema"));
+        }
+        Ok(result)
+    }
+
+    /// Returns the result's schema: a struct with one child field per column.
+    pub fn schema(&self) -> &ArrowSchema {
+        &self.schema
+    }
+
+    /// Takes the schema out of the result, e.g. to import it into arrow-rs. Later calls
+    /// to [`schema`](ArrowResult::schema) see a released, empty schema.
+    pub fn take_schema(&mut self) -> ArrowSchema {
+        std::mem::take(&mut self.schema)
+    }
+
+    /// Returns the number of columns in the result.
+    pub fn column_count(&self) -> u64 {
+        // SAFETY: `self.inn` is a valid duckdb_arrow.
+        unsafe { duckdb_arrow_column_count(self.inn) }
+    }
+
+    /// Returns the number of rows in the result.
+    pub fn row_count(&self) -> u64 {
+        // SAFETY: `self.inn` is a valid duckdb_arrow.
+        unsafe { duckdb_arrow_row_count(self.inn) }
+    }
+
+    /// Returns the number of rows changed by an INSERT/UPDATE/DELETE.
+    pub fn changes(&self) -> u64 {
+        // SAFETY: `self.inn` is a valid duckdb_arrow.
+        unsafe { duckdb_arrow_rows_changed(self.inn) }
+    }
+
+    fn error(
+        &self,
+        rc: ffi::duckdb_state,
+        fallback: &str,
+    ) -> Error {
+        // SAFETY: `self.inn` is a valid duckdb_arrow; a non-null error string is owned by it.
+        let message = unsafe { c_str_or_empty(duckdb_query_arrow_error(self.inn)) };
+        let message = if message.is_empty() { fallback } else { message };
+        Error::DuckDBFailure {
+            error: ffi::Error::new(rc),
+            message: Some(message.to_owned()),
+            error_type: None,
+        }
+    }
+}
+
+impl Iterator for ArrowResult {
+    type Item = Result<ArrowArray>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut array = ArrowArray::default();
+        let mut out = &mut array as *mut ArrowArray as duckdb_arrow_array;
+        // SAFETY: `self.inn` is a valid duckdb_arrow and `out` points to a released array.
+        // DuckDB fills it with the next chunk, or leaves it untouched at the end.
+        let rc = unsafe { duckdb_query_arrow_array(self.inn, &mut out) };
+        if rc != DuckDBSuccess {
+            return Some(Err(self.error(rc, "failed to fetch an Arrow record batch")));
+        }
+        if array.is_released() {
+            return None;
+        }
+        Some(Ok(array))
+    }
+}
+
+impl Drop for ArrowResult {
+    fn drop(&mut self) {
+        // SAFETY: `self.inn` was returned by `duckdb_query_arrow` and is destroyed once.
+        // Arrays handed out by the iterator own their own data and stay valid.
+        unsafe { duckdb_destroy_arrow(&mut self.inn) };
+    }
+}
+
+/// Borrows a possibly-null C string, treating null (and invalid UTF-8) as `""`.
+///
+/// # Safety
+///
+/// A non-null `ptr` must point to a null-terminated string that outlives `'a`.
+unsafe fn c_str_or_empty<'a>(ptr: *const c_char) -> &'a str {
+    if ptr.is_null() {
+        return "";
+    }
+    // SAFETY: upheld by the caller.
+    unsafe { CStr::from_ptr(ptr) }.to_str().unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::Connection;
+
+    #[test]
+    fn test_query_arrow_schema_field_name() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let result = conn.query_arrow("SELECT 1 AS a").unwrap();
+        assert_eq!(result.column_count(), 1);
+        assert_eq!(result.schema().format(), "+s");
+        let fields: Vec<_> = result.schema().children().collect();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name(), "a");
+        assert_eq!(fields[0].format(), "i");
+    }
+
+    #[test]
+    fn test_query_arrow_batches_cover_all_rows() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let batches: Vec<_> = conn
+            .query_arrow("SELECT range AS v FROM range(5000)")
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert!(batches.len() > 1);
+        assert_eq!(batches.iter().map(|b| b.length).sum::<i64>(), 5000);
+        assert!(batches.iter().all(|b| b.n_children == 1 && !b.is_released()));
+    }
+
+    #[test]
+    fn test_take_schema_leaves_released_schema() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let mut result = conn.query_arrow("SELECT 'x' AS s").unwrap();
+        let schema = result.take_schema();
+        assert!(!schema.is_released());
+        assert!(result.schema().is_released());
+        assert_eq!(schema.children().next().unwrap().format(), "u");
+    }
+
+    #[test]
+    fn test_query_arrow_invalid_sql_errors() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        assert!(conn.query_arrow("SELEC 1").is_err());
+    }
+}
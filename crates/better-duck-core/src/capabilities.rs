@@ -0,0 +1,138 @@
+use std::ffi::CStr;
+
+use crate::ffi::duckdb_library_version;
+
+/// Version and feature information about the linked DuckDB library.
+///
+/// Obtained from [`capabilities`]. Runtime flags are derived from the library
+/// version, so they stay accurate when linking against a system DuckDB instead of
+/// the bundled one; compile-time flags mirror this crate's cargo features.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// The raw version string reported by DuckDB (e.g. `"v1.5.3"`).
+    pub version: String,
+    /// Major version component.
+    pub major: u32,
+    /// Minor version component.
+    pub minor: u32,
+    /// Patch version component.
+    pub patch: u32,
+    /// `duckdb_append_default` is available (DuckDB 1.1+).
+    pub append_default: bool,
+    /// The appender supports selecting a subset of columns via
+    /// `duckdb_appender_add_column` (DuckDB 1.2+).
+    pub appender_column_selection: bool,
+    /// The JSON extension was compiled in (`json` feature).
+    pub json: bool,
+    /// The Parquet extension was compiled in (`parquet` feature).
+    pub parquet: bool,
+    /// Chrono-based date/time conversions are enabled (`chrono` feature).
+    pub chrono: bool,
+    /// `rust_decimal::Decimal` conversions are enabled (`decimal` feature).
+    pub decimal: bool,
+    /// `bigdecimal::BigDecimal` conversions are enabled (`bigdecimal` feature).
+    pub bigdecimal: bool,
+}
+
+impl Capabilities {
+    /// Returns `true` if the linked library is at least version `major.minor.patch`.
+    #[inline]
+    pub fn at_least(
+        &self,
+        major: u32,
+        minor: u32,
+        patch: u32,
+    ) -> bool {
+        (self.major, self.minor, self.patch) >= (major, minor, patch)
+    }
+}
+
+/// Parses a DuckDB version string such as `v1.5.3` or `v1.5.3-dev42` into its
+/// numeric components. Missing or malformed components are reported as `0`.
+fn parse_version(version: &str) -> (u32, u32, u32) {
+    let core = version.trim_start_matches('v');
+    let core = core.split(['-', '+']).next().unwrap_or_default();
+    let mut parts = core.split('.').map(|p| p.parse::<u32>().unwrap_or(0));
+    (parts.next().unwrap_or(0), parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+}
+
+/// Returns the version of the linked DuckDB library, e.g. `"v1.5.3"`.
+///
+/// Useful for logging which DuckDB an application runs against; see [`capabilities`]
+/// for the parsed components and the features that depend on them.
+///
+/// # Example
+///
+/// ```rust
+/// assert!(better_duck_core::library_version().starts_with('v'));
+/// ```
+pub fn library_version() -> &'static str {
+    // SAFETY: `duckdb_library_version` returns a pointer to a static, null-terminated
+    // string owned by the library; it is never freed.
+    unsafe { CStr::from_ptr(duckdb_library_version()) }.to_str().unwrap_or_default()
+}
+
+/// Reports the version of the linked DuckDB library and which optional features
+/// are available.
+///
+/// # Example
+///
+/// ```rust
+/// let caps = better_duck_core::capabilities();
+/// assert!(caps.major >= 1);
+/// if caps.append_default {
+///     // safe to rely on `duckdb_append_default`
+/// }
+/// ```
+pub fn capabilities() -> Capabilities {
+    let version = library_version().to_owned();
+    let (major, minor, patch) = parse_version(&version);
+    let mut caps = Capabilities {
+        version,
+        major,
+        minor,
+        patch,
+        append_default: false,
+        appender_column_selection: false,
+        json: cfg!(feature = "json"),
+        parquet: cfg!(feature = "parquet"),
+        chrono: cfg!(feature = "chrono"),
+        decimal: cfg!(feature = "decimal"),
+        bigdecimal: cfg!(feature = "bigdecimal"),
+    };
+    caps.append_default = caps.at_least(1, 1, 0);
+    caps.appender_column_selection = caps.at_least(1, 2, 0);
+    caps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("v1.5.3"), (1, 5, 3));
+        assert_eq!(parse_version("v1.2.0-dev1234"), (1, 2, 0));
+        assert_eq!(parse_version("0.10"), (0, 10, 0));
+        assert_eq!(parse_version("garbage"), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_library_version() {
+        let version = library_version();
+        assert!(!version.is_empty());
+        assert!(version.starts_with('v'), "unexpected version {version}");
+        assert_eq!(capabilities().version, version);
+    }
+
+    #[test]
+    fn test_capabilities_of_linked_library() {
+        let caps = capabilities();
+        assert!(caps.version.starts_with('v'), "unexpected version {}", caps.version);
+        assert!(caps.major >= 1);
+        assert!(caps.at_least(caps.major, caps.minor, caps.patch));
+        assert!(!caps.at_least(caps.major + 1, 0, 0));
+        assert!(caps.append_default);
+    }
+}
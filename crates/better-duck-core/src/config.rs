@@ -2,7 +2,13 @@ use crate::{
     error::{Error, Result},
     ffi,
 };
-use std::{default::Default, ffi::CString, os::raw::c_char, ptr};
+use std::{
+    collections::HashMap,
+    default::Default,
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    ptr,
+};
 
 use strum::{Display, EnumString};
 
@@ -42,6 +48,11 @@ pub enum DefaultNullOrder {
     NullsLast,
 }
 
+/// Smallest block size DuckDB accepts for a database file (16 KiB).
+const MIN_BLOCK_SIZE: u64 = 16 * 1024;
+/// Largest, and default, block size DuckDB accepts for a database file (256 KiB).
+const MAX_BLOCK_SIZE: u64 = 256 * 1024;
+
 /// duckdb configuration
 /// Refer to <https://github.com/duckdb/duckdb/blob/master/src/main/config.cpp>
 #[derive(Default)]
@@ -55,7 +66,6 @@ impl Config {
     }
 
     /// enable autoload extensions
-    #[allow(unused)]
     pub fn enable_autoload_extension(
         mut self,
         enabled: bool,
@@ -66,7 +76,6 @@ impl Config {
     }
 
     /// Access mode of the database ([AUTOMATIC], READ_ONLY or READ_WRITE)
-    #[allow(unused)]
     pub fn access_mode(
         mut self,
         mode: AccessMode,
@@ -76,7 +85,6 @@ impl Config {
     }
 
     /// Metadata from DuckDB callers
-    #[allow(unused)]
     pub fn custom_user_agent(
         mut self,
         custom_user_agent: &str,
@@ -86,7 +94,6 @@ impl Config {
     }
 
     /// The order type used when none is specified ([ASC] or DESC)
-    #[allow(unused)]
     pub fn default_order(
         mut self,
         order: DefaultOrder,
@@ -96,7 +103,6 @@ impl Config {
     }
 
     /// Null ordering used when none is specified ([NULLS_FIRST] or NULLS_LAST)
-    #[allow(unused)]
     pub fn default_null_order(
         mut self,
         null_order: DefaultNullOrder,
@@ -106,7 +112,6 @@ impl Config {
     }
 
     /// Allow the database to access external state (through e.g. COPY TO/FROM, CSV readers, pandas replacement scans, etc)
-    #[allow(unused)]
     pub fn enable_external_access(
         mut self,
         enabled: bool,
@@ -116,7 +121,6 @@ impl Config {
     }
 
     /// Whether or not object cache is used to cache e.g. Parquet metadata
-    #[allow(unused)]
     pub fn enable_object_cache(
         mut self,
         enabled: bool,
@@ -126,14 +130,12 @@ impl Config {
     }
 
     /// Allow to load third-party duckdb extensions.
-    #[allow(unused)]
     pub fn allow_unsigned_extensions(mut self) -> Result<Config> {
         self.set("allow_unsigned_extensions", "true")?;
         Ok(self)
     }
 
     /// The maximum memory of the system (e.g. 1GB)
-    #[allow(unused)]
     pub fn max_memory(
         mut self,
         memory: &str,
@@ -143,7 +145,6 @@ impl Config {
     }
 
     /// The number of total threads used by the system
-    #[allow(unused)]
     pub fn threads(
         mut self,
         thread_num: i64,
@@ -152,8 +153,38 @@ impl Config {
         Ok(self)
     }
 
+    /// The block size used for newly created database files, in bytes.
+    ///
+    /// Must be a power of two between 16 KiB and 256 KiB (the default). Existing database
+    /// files keep the block size they were created with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without touching the DuckDB config if `bytes` is not a supported
+    /// block size.
+    pub fn default_block_size(
+        mut self,
+        bytes: u64,
+    ) -> Result<Config> {
+        if !bytes.is_power_of_two() || !(MIN_BLOCK_SIZE..=MAX_BLOCK_SIZE).contains(&bytes) {
+            return Err(Error::DuckDBFailure {
+                error: ffi::Error::new(ffi::DuckDBError),
+                message: Some(format!(
+                    "invalid block size {bytes}: must be a power of two between \
+                     {MIN_BLOCK_SIZE} and {MAX_BLOCK_SIZE} bytes"
+                )),
+                error_type: None,
+            });
+        }
+        self.set("default_block_size", &bytes.to_string())?;
+        Ok(self)
+    }
+
     /// Add any setting to the config. DuckDB will return an error if the setting is unknown or
     /// otherwise invalid.
+    ///
+    /// An unknown key is only reported when the database is opened with this config; use
+    /// [`try_with`](Config::try_with) to reject it right away.
     pub fn with(
         mut self,
         key: impl AsRef<str>,
@@ -163,46 +194,108 @@ impl Config {
         Ok(self)
     }
 
+    /// Add a setting to the config, checking first that `key` is an option DuckDB knows.
+    ///
+    /// Unlike [`with`](Config::with), which leaves unknown keys for DuckDB to reject when
+    /// the database is opened, `try_with` looks `key` up in the list of options reported
+    /// by `duckdb_get_config_flag` (case-insensitively) and fails before anything is
+    /// set. Options defined by extensions are not in that list; set those with `with`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameterName`] if `key` is not a known option, or an error
+    /// if DuckDB rejects `value`.
+    pub fn try_with(
+        mut self,
+        key: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<Config> {
+        let key = key.as_ref();
+        if !is_known_option(key) {
+            return Err(Error::InvalidParameterName(key.to_owned()));
+        }
+        self.set(key, value.as_ref())?;
+        Ok(self)
+    }
+
+    /// Builds a config from key/value pairs, e.g. options loaded from a TOML or env file.
+    ///
+    /// Each pair is applied with [`try_with`](Config::try_with), so keys are checked
+    /// against DuckDB's known options up front.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first unknown key or rejected value and returns an error naming it.
+    /// `HashMap` iteration order is unspecified, so with several bad entries any one of
+    /// them may be reported.
+    pub fn from_map(map: &HashMap<String, String>) -> Result<Config> {
+        map.iter().try_fold(Config::default(), |config, (key, value)| config.try_with(key, value))
+    }
+
     fn set(
         &mut self,
         key: &str,
         value: &str,
     ) -> Result<()> {
-        if self.config.is_none() {
-            let mut config: ffi::duckdb_config = ptr::null_mut();
-            // SAFETY: `config` is a valid output pointer; `duckdb_create_config` initializes it.
-            let state = unsafe { ffi::duckdb_create_config(&mut config) };
-            if state != ffi::DuckDBSuccess {
-                return Err(Error::DuckDBFailure(
-                    ffi::Error::new(state),
-                    Some("failed to create duckdb_config".to_owned()),
-                ));
-            }
-            self.config = Some(config);
-        }
+        let config = match self.config {
+            Some(config) => config,
+            None => {
+                let mut config: ffi::duckdb_config = ptr::null_mut();
+                // SAFETY: `config` is a valid output pointer; `duckdb_create_config`
+                // initializes it.
+                let state = unsafe { ffi::duckdb_create_config(&mut config) };
+                if state != ffi::DuckDBSuccess {
+                    return Err(Error::DuckDBFailure {
+                        error: ffi::Error::new(state),
+                        message: Some("failed to create duckdb_config".to_owned()),
+                        error_type: None,
+                    });
+                }
+                *self.config.insert(config)
+            },
+        };
 
         let c_key = CString::new(key)?;
         let c_value = CString::new(value)?;
-        // SAFETY: `self.config` is Some — either it was just initialized above or it was
-        // already Some. `c_key` and `c_value` are valid null-terminated C strings that
-        // outlive this call. `duckdb_set_config` does not retain the string pointers.
+        // SAFETY: `config` is a valid duckdb_config owned by `self`. `c_key` and `c_value`
+        // are valid null-terminated C strings that outlive this call. `duckdb_set_config`
+        // does not retain the string pointers.
         let state = unsafe {
             ffi::duckdb_set_config(
-                self.config.expect("config always initialized before this point"),
+                config,
                 c_key.as_ptr() as *const c_char,
                 c_value.as_ptr() as *const c_char,
             )
         };
         if state != ffi::DuckDBSuccess {
-            return Err(Error::DuckDBFailure(
-                ffi::Error::new(state),
-                Some(format!("set {key}:{value} error")),
-            ));
+            return Err(Error::DuckDBFailure {
+                error: ffi::Error::new(state),
+                message: Some(format!("set {key}:{value} error")),
+                error_type: None,
+            });
         }
         Ok(())
     }
 }
 
+/// Returns `true` if `key` names one of the options DuckDB lists through
+/// `duckdb_get_config_flag`, ignoring ASCII case as DuckDB does.
+fn is_known_option(key: &str) -> bool {
+    // SAFETY: `duckdb_config_count` has no preconditions.
+    let count = unsafe { ffi::duckdb_config_count() };
+    (0..count).any(|index| {
+        let mut name: *const c_char = ptr::null();
+        let mut description: *const c_char = ptr::null();
+        // SAFETY: `index < count`; `name` and `description` are valid output pointers that
+        // DuckDB points at static strings.
+        let state = unsafe { ffi::duckdb_get_config_flag(index, &mut name, &mut description) };
+        // SAFETY: on success `name` is a non-null, null-terminated static C string.
+        state == ffi::DuckDBSuccess
+            && !name.is_null()
+            && unsafe { CStr::from_ptr(name) }.to_bytes().eq_ignore_ascii_case(key.as_bytes())
+    })
+}
+
 impl Drop for Config {
     fn drop(&mut self) {
         // SAFETY: `cfg` is a valid duckdb_config created in `set` and not yet destroyed.
@@ -332,12 +425,49 @@ mod tests {
         assert!(config.is_ok());
     }
 
+    #[test]
+    fn test_default_block_size() {
+        assert!(Config::default().default_block_size(16 * 1024).is_ok());
+        assert!(Config::default().default_block_size(256 * 1024).is_ok());
+        for invalid in [0, 8 * 1024, 100_000, 512 * 1024] {
+            let err = Config::default().default_block_size(invalid).err().unwrap();
+            assert!(err.to_string().contains("invalid block size"), "{invalid}: {err}");
+        }
+    }
+
     #[test]
     fn test_with() {
         let config = Config::default().with("some_key", "some_value");
         assert!(config.is_ok());
     }
 
+    #[test]
+    fn test_invalid_value_is_an_error() {
+        let err = Config::default().max_memory("1 gigglebyte").err().expect("bad memory string");
+        assert!(err.to_string().contains("max_memory"), "{err}");
+        assert!(Config::default().threads(-1).is_err());
+        assert!(Config::default().try_with("threads", "many").is_err());
+    }
+
+    #[test]
+    fn test_try_with_checks_the_key() {
+        assert!(Config::default().try_with("threads", "4").is_ok());
+        assert!(Config::default().try_with("MAX_MEMORY", "1GB").is_ok());
+        let err = Config::default().try_with("some_key", "some_value").err().unwrap();
+        assert_eq!(err, Error::InvalidParameterName("some_key".to_owned()));
+    }
+
+    #[test]
+    fn test_from_map() {
+        let mut map: HashMap<String, String> =
+            [("threads", "2"), ("max_memory", "512MB")].map(|(k, v)| (k.into(), v.into())).into();
+        assert!(Config::from_map(&map).is_ok());
+
+        map.insert("thread_count".into(), "4".into());
+        let err = Config::from_map(&map).err().expect("unknown key");
+        assert!(err.to_string().contains("thread_count"), "{err}");
+    }
+
     #[test]
     fn test_set_multiple_options() {
         let config = Config::default()
@@ -1,11 +1,29 @@
-use std::path::Path;
+use std::{
+    path::Path,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    thread,
+    time::Duration,
+};
 
 use crate::{
-    config::Config,
-    error::Result,
+    config::{AccessMode, Config},
+    error::{DuckDBConversionError, DuckDBErrorType, Error, Result},
     helpers::path::path_to_cstring,
-    raw::{appender::Appender, connection::RawConnection, result::DuckResult},
-    types::appendable::AppendAble,
+    raw::{
+        appender::Appender,
+        connection::{InterruptTarget, RawConnection},
+        result::DuckResult,
+        row::DuckRow,
+        statement::{CachedStatement, PreparedInfo},
+    },
+    sql::{quote_identifier, quote_literal},
+    table_function::TableFunction,
+    transaction::Transaction,
+    types::{appendable::AppendAble, value::DuckValue, DuckDialect},
+    udf::ScalarFunction,
 };
 
 /// A high-level DuckDB connection.
@@ -24,6 +42,13 @@ use crate::{
 /// ```
 pub struct Connection(RawConnection);
 
+impl Connection {
+    /// Wraps an already established raw connection.
+    pub(crate) fn from_raw(raw: RawConnection) -> Connection {
+        Connection(raw)
+    }
+}
+
 // File-db implementation
 impl Connection {
     /// Opens a connection to a DuckDB database at the given file path.
@@ -54,6 +79,26 @@ impl Connection {
         let config = config.with("duckdb_api", "rust")?;
         RawConnection::open_with_flags(&c_path, config).map(Connection)
     }
+
+    /// Opens an existing DuckDB database file in read-only mode.
+    ///
+    /// Shorthand for [`open_with_flags`](Connection::open_with_flags) with
+    /// `Config::access_mode(AccessMode::ReadOnly)`. Statements that write, such as
+    /// `CREATE TABLE` or `INSERT`, fail on the returned connection. Several processes may
+    /// open the same file read-only at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPath`] if `path` does not exist, since a read-only open
+    /// cannot create the file, or an error if DuckDB cannot open it.
+    #[must_use = "connection should be used or explicitly dropped"]
+    pub fn open_read_only<P: AsRef<Path>>(path: P) -> Result<Connection> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(Error::InvalidPath(path.to_path_buf()));
+        }
+        Self::open_with_flags(path, Config::default().access_mode(AccessMode::ReadOnly)?)
+    }
 }
 
 // In-memory implementation
@@ -81,26 +126,64 @@ impl Connection {
     pub fn open_in_memory_with_flags(config: Config) -> Result<Connection> {
         Self::open_with_flags(":memory:", config)
     }
+
+    /// Opens a connection to the in-memory database called `name`, shared by every
+    /// connection opened with the same name in this process.
+    ///
+    /// The first call creates the database; later calls connect to it, so tables
+    /// created through one connection are visible through the others. The data lives
+    /// until the last connection to it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be established.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::{connection::Connection, types::value::DuckValue};
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut writer = Connection::open_in_memory_named("doc_shared")?;
+    /// writer.execute_batch("CREATE TABLE t AS SELECT 1 AS id")?;
+    ///
+    /// let mut reader = Connection::open_in_memory_named("doc_shared")?;
+    /// let id = reader.query_row("SELECT id FROM t", |row| Ok(row.get("id").cloned()))?;
+    /// assert_eq!(id, Some(DuckValue::Int(1)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "connection should be used or explicitly dropped"]
+    pub fn open_in_memory_named(name: &str) -> Result<Connection> {
+        let config = Config::default().with("duckdb_api", "rust")?;
+        RawConnection::open_in_memory_named(name, config).map(Connection)
+    }
 }
 
 impl Connection {
     /// Executes one or more SQL statements separated by semicolons.
     ///
-    /// The result of each statement is discarded. Use this for DDL
-    /// (`CREATE TABLE`, `DROP TABLE`) and simple DML (`INSERT`, `UPDATE`, `DELETE`).
+    /// The statements run one at a time, in order, and the result of each is discarded.
+    /// Use this for DDL (`CREATE TABLE`, `DROP TABLE`), simple DML (`INSERT`, `UPDATE`,
+    /// `DELETE`) and migration scripts. Each statement commits on its own unless the
+    /// batch opens a transaction itself.
     ///
     /// # Errors
     ///
-    /// Returns an error if any statement fails to execute.
+    /// Stops at the first failing statement. In a batch of several statements the
+    /// error is [`Error::BatchStatementFailed`], carrying the failing statement's
+    /// 0-based index; a single statement returns its error directly. Syntax errors are
+    /// found before anything runs and are reported without an index.
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use better_duck_core::connection::Connection;
+    /// # use better_duck_core::{connection::Connection, error::Error};
     /// # fn main() -> better_duck_core::error::Result<()> {
     /// let mut conn = Connection::open_in_memory()?;
-    /// conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
-    /// conn.execute_batch("INSERT INTO t VALUES (1)")?;
+    /// conn.execute_batch("CREATE TABLE t (id INTEGER); INSERT INTO t VALUES (1)")?;
+    ///
+    /// let err = conn.execute_batch("INSERT INTO t VALUES (2); INSERT INTO missing VALUES (3)");
+    /// assert!(matches!(err, Err(Error::BatchStatementFailed(1, _))));
     /// # Ok(())
     /// # }
     /// ```
@@ -110,7 +193,7 @@ impl Connection {
         &mut self,
         sql: impl AsRef<str>,
     ) -> Result<()> {
-        self.0.query(sql).map(|_| ())
+        self.0.execute_batch(sql)
     }
 
     /// Prepares and executes a SQL statement, returning the result.
@@ -148,102 +231,1195 @@ impl Connection {
         self.0.execute(sql, &mut [])
     }
 
-    /// Prepares and executes a parameterized SQL statement, returning the result.
+    /// Runs a query and returns its rows.
+    ///
+    /// The returned [`DuckResult`] is an iterator yielding `Result<DuckRow>`, so a `SELECT`
+    /// can be consumed directly in a `for` loop. Unlike [`execute`](Connection::execute),
+    /// the SQL is sent straight to DuckDB without going through a prepared statement, so it
+    /// cannot take parameters.
     ///
     /// # Errors
     ///
-    /// Returns an error if preparation, binding, or execution fails.
-    #[must_use = "the DuckResult carries both affected-row count (.changes()) and a row iterator — consume it"]
-    pub fn execute_with(
+    /// Returns an error if DuckDB cannot execute the query or if `sql` contains a nul byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # use better_duck_core::types::value::DuckValue;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// for row in conn.query("SELECT 42 AS answer")? {
+    ///     assert_eq!(row?.get("answer"), Some(&DuckValue::Int(42)));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DuckRow`]: crate::DuckRow
+    #[must_use = "query returns a DuckResult; iterate it to read the rows"]
+    pub fn query(
         &mut self,
         sql: impl AsRef<str>,
-        binds: &mut [&mut dyn AppendAble],
     ) -> Result<DuckResult> {
-        self.0.execute(sql, binds)
+        self.0.query(sql)
     }
 
-    /// Creates an appender for bulk-inserting rows into the given table and schema.
+    /// Runs a query and returns its result in the Arrow C Data Interface format.
+    ///
+    /// The [`ArrowResult`](crate::arrow::ArrowResult) exposes the schema and iterates
+    /// one record batch per chunk, ready to be imported into arrow-rs without copying.
+    /// Requires the `arrow` feature.
     ///
     /// # Errors
     ///
-    /// Returns an error if the table does not exist or the appender cannot be created.
-    #[must_use = "appender should be used to insert rows"]
-    #[allow(unused)]
-    pub fn appender(
+    /// Returns an error if DuckDB cannot execute the query or if `sql` contains a nul byte.
+    #[cfg(feature = "arrow")]
+    #[must_use = "query_arrow returns an ArrowResult; iterate it to read the record batches"]
+    pub fn query_arrow(
         &mut self,
-        table: &str,
-        schema: &str,
-    ) -> Result<Appender> {
-        self.0.appender(table, schema)
+        sql: impl AsRef<str>,
+    ) -> Result<crate::arrow::ArrowResult> {
+        self.0.query_arrow(sql)
     }
-}
 
-impl Connection {
-    /// Closes the connection explicitly.
+    /// Runs a query whose result may yield at most `max_rows` rows.
     ///
-    /// The connection is also closed automatically on drop.
+    /// Guards against accidentally pulling a huge result into memory: the query runs as
+    /// a streaming query, so DuckDB produces rows one chunk at a time, and once iteration
+    /// passes `max_rows` the iterator yields [`Error::ResultTooLarge`] and stops. Rows
+    /// past the limit are never produced beyond the chunk that holds the first of them.
+    /// Like any streaming result, read or drop it before running another statement on
+    /// this connection.
+    ///
+    /// `sql` must be a single statement.
     ///
     /// # Errors
     ///
-    /// Always returns `Ok(())`.
-    #[must_use = "close result should be checked"]
-    #[inline]
-    #[allow(unused)]
-    pub fn close(&mut self) -> Result<()> {
-        self.0.close()
+    /// Returns an error if DuckDB cannot prepare or execute the query or if `sql`
+    /// contains a nul byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let rows: Vec<_> = conn.query_limited("SELECT * FROM range(10)", 100)?.collect();
+    /// assert_eq!(rows.len(), 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "query_limited returns a DuckResult; iterate it to read the rows"]
+    pub fn query_limited(
+        &mut self,
+        sql: impl AsRef<str>,
+        max_rows: u64,
+    ) -> Result<DuckResult> {
+        self.0.prepare(sql)?.stream().map(|res| res.with_row_limit(max_rows))
     }
 
-    /// Returns `true` if the connection is open.
-    #[inline]
-    #[allow(unused)]
-    pub fn is_open(&self) -> bool {
-        !self.0.con.is_null()
+    /// Runs a query like [`query`](Connection::query), interrupting it if it has not
+    /// finished within `timeout`.
+    ///
+    /// A watchdog thread waits for the timeout and interrupts the connection through an
+    /// [`InterruptHandle`]. It is woken and joined as soon as the query returns, so no
+    /// thread outlives the call. A query that completes just as the timeout elapses
+    /// still returns its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the query was interrupted by the timeout, or any
+    /// error raised by DuckDB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let mut result = conn.query_with_timeout("SELECT 42 AS answer", Duration::from_secs(5))?;
+    /// assert!(result.next().is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_with_timeout(
+        &mut self,
+        sql: impl AsRef<str>,
+        timeout: Duration,
+    ) -> Result<DuckResult> {
+        let handle = self.interrupt_handle();
+        let (done, finished) = mpsc::channel::<()>();
+        let watchdog = thread::spawn(move || {
+            // Dropping `done` disconnects the channel and wakes this thread early.
+            if finished.recv_timeout(timeout) != Err(RecvTimeoutError::Timeout) {
+                return false;
+            }
+            // An interrupt that lands before DuckDB starts executing is discarded, so
+            // repeat it until the query has returned.
+            loop {
+                handle.interrupt();
+                if finished.recv_timeout(Duration::from_millis(10))
+                    != Err(RecvTimeoutError::Timeout)
+                {
+                    return true;
+                }
+            }
+        });
+        let result = self.query(sql);
+        drop(done);
+        let timed_out = watchdog.join().unwrap_or(false);
+        match result {
+            Err(err)
+                if timed_out && err.duckdb_error_type() == Some(DuckDBErrorType::Interrupt) =>
+            {
+                Err(Error::Timeout(timeout))
+            },
+            result => result,
+        }
     }
 
-    /// Returns a reference to the underlying `RawConnection`.
+    /// Runs a query that is expected to return a single row and maps it with `f`.
     ///
-    /// This provides access to low-level operations such as `prepare`.
-    #[inline]
-    #[allow(unused)]
-    #[allow(private_interfaces)]
-    pub fn db(&self) -> &RawConnection {
-        &self.0
+    /// Only the first row is passed to `f`; any further rows are discarded without being
+    /// read. Add a `LIMIT 1` to the query if the extra rows are expensive to produce.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::QueryReturnedNoRows`] if the query produced no rows, or any error
+    /// raised by DuckDB or by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # use better_duck_core::types::value::DuckValue;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let answer = conn.query_row("SELECT 42 AS answer", |row| Ok(row.get("answer").cloned()))?;
+    /// assert_eq!(answer, Some(DuckValue::Int(42)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_row<T>(
+        &mut self,
+        sql: impl AsRef<str>,
+        f: impl FnOnce(&DuckRow) -> Result<T>,
+    ) -> Result<T> {
+        let mut result = self.query(sql)?;
+        match result.next() {
+            Some(row) => f(&row?),
+            None => Err(Error::QueryReturnedNoRows),
+        }
     }
-}
 
-// SAFETY: DuckDB connections are safe to move between threads (they do not hold
-// thread-local state). Each `Connection` owns its `RawConnection` exclusively.
-unsafe impl Send for Connection {}
+    /// Runs a query that is expected to return a single value, e.g.
+    /// `SELECT max(id) FROM t`, and converts it into `T`.
+    ///
+    /// Only the first row is read, like [`query_row`](Connection::query_row). A `NULL`
+    /// value goes through [`DuckDialect::from_null`], so ask for an `Option<T>` when the
+    /// query can return one, such as an aggregate over an empty table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::QueryReturnedNoRows`] if the query produced no rows,
+    /// [`Error::InvalidColumnCount`] if it did not produce exactly one column, or a
+    /// conversion error if the value cannot be converted into `T`, including a `NULL`
+    /// for a `T` that cannot hold one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.execute_batch("CREATE TABLE t (id INTEGER); INSERT INTO t VALUES (3), (7)")?;
+    /// let max: i32 = conn.query_scalar("SELECT max(id) FROM t")?;
+    /// assert_eq!(max, 7);
+    /// let none: Option<i32> = conn.query_scalar("SELECT max(id) FROM t WHERE id > 10")?;
+    /// assert_eq!(none, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_scalar<T: DuckDialect>(
+        &mut self,
+        sql: impl AsRef<str>,
+    ) -> Result<T> {
+        let mut result = self.query(sql)?;
+        if result.col_count != 1 {
+            return Err(Error::InvalidColumnCount(result.col_count as usize, 1));
+        }
+        let row = result.next().ok_or(Error::QueryReturnedNoRows)??;
+        let value = match row.get_idx(0) {
+            Some(value) if !value.is_null() => value,
+            _ => return Ok(T::from_null()?),
+        };
+        let mut raw = value.to_duck()?;
+        let converted = T::from_duck(raw);
+        // SAFETY: `raw` was created by `to_duck` above and is destroyed once.
+        unsafe { crate::ffi::duckdb_destroy_value(&mut raw) };
+        Ok(converted?)
+    }
 
-#[cfg(test)]
-mod connection_tests {
-    use super::*;
-    use crate::config::Config;
+    /// Executes a SQL statement and returns the number of rows it changed.
+    ///
+    /// Shorthand for `execute(sql)?.changes()`. Statements that do not touch any
+    /// rows (DDL, `SELECT`, an `UPDATE` whose `WHERE` matches nothing) return `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB cannot prepare or execute the statement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
+    /// let n = conn.execute_returning_changes("INSERT INTO t VALUES (1), (2)")?;
+    /// assert_eq!(n, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "the affected-row count should be checked"]
+    pub fn execute_returning_changes(
+        &mut self,
+        sql: impl AsRef<str>,
+    ) -> Result<u64> {
+        self.execute(sql).map(|mut res| res.changes())
+    }
 
-    #[test]
-    fn test_open_in_memory() {
-        let mut conn = Connection::open_in_memory().unwrap();
-        assert!(conn.is_open());
-        conn.close().unwrap();
-        assert!(!conn.is_open());
+    /// Returns the current value of a DuckDB setting as text, e.g. `"threads"` or
+    /// `"memory_limit"`.
+    ///
+    /// Wraps `SELECT current_setting(key)`; useful to verify that a `Config` option
+    /// passed at open time, or a `SET` statement, was honored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB does not know the setting `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.execute_batch("SET threads = 2")?;
+    /// assert_eq!(conn.current_setting("threads")?, "2");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn current_setting(
+        &mut self,
+        key: &str,
+    ) -> Result<String> {
+        // `current_setting` needs a constant argument, so the key is inlined as a
+        // quoted literal rather than bound as a parameter.
+        let sql = format!("SELECT current_setting({})::VARCHAR AS value", quote_literal(key));
+        self.query_row(sql, |row| match row.get("value") {
+            Some(DuckValue::Text(value)) => Ok(value.clone()),
+            _ => Err(Error::ConversionError(DuckDBConversionError::NullValue)),
+        })
     }
 
-    #[test]
-    fn test_open_with_flags() {
-        let config = Config::default().with("duckdb_api", "rust").unwrap();
-        let mut conn = Connection::open_with_flags(":memory:", config).unwrap();
-        assert!(conn.is_open());
-        conn.close().unwrap();
-        assert!(!conn.is_open());
+    /// Returns the physical plan DuckDB would use for `sql`, without running it.
+    ///
+    /// Runs `EXPLAIN sql` and joins the rendered plan text of its rows, so the
+    /// `explain_key`/`explain_value` columns don't have to be picked apart by hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sql` cannot be planned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let plan = conn.explain("SELECT * FROM range(10) WHERE range > 5")?;
+    /// assert!(plan.contains("FILTER"), "{plan}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn explain(
+        &mut self,
+        sql: &str,
+    ) -> Result<String> {
+        self.plan_text(format!("EXPLAIN {sql}"))
     }
 
-    #[test]
-    fn test_batch_execution() {
-        let mut conn = Connection::open_in_memory().unwrap();
-        let exec = conn.execute_batch("CREATE TABLE test (id INTEGER, name TEXT)");
-        assert!(exec.is_ok(), "{}", exec.unwrap_err());
-        let exec = conn.execute_batch("INSERT INTO test VALUES (1, 'example')");
-        assert!(exec.is_ok(), "{}", exec.unwrap_err());
-        conn.close().unwrap();
+    /// Runs `sql` under `EXPLAIN ANALYZE` and returns the profiled plan, with the time
+    /// and row count of every operator.
+    ///
+    /// The statement really executes, including any writes it makes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sql` fails to plan or run.
+    pub fn explain_analyze(
+        &mut self,
+        sql: &str,
+    ) -> Result<String> {
+        self.plan_text(format!("EXPLAIN ANALYZE {sql}"))
+    }
+
+    /// Joins the `explain_value` column of an `EXPLAIN` result.
+    fn plan_text(
+        &mut self,
+        sql: String,
+    ) -> Result<String> {
+        let mut plan = String::new();
+        for row in self.query(sql)? {
+            if let Some(DuckValue::Text(text)) = row?.get("explain_value") {
+                if !plan.is_empty() {
+                    plan.push('\n');
+                }
+                plan.push_str(text);
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Prepares `sql` without running it and reports its parameters.
+    ///
+    /// Checks the syntax and that every referenced table and column exists, so a query
+    /// builder can verify arity before binding. The statement is destroyed before
+    /// returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sql` fails to parse or plan, or contains a nul byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// assert_eq!(conn.validate("SELECT ? + 1, ?")?.param_count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(
+        &mut self,
+        sql: &str,
+    ) -> Result<PreparedInfo> {
+        Ok(self.0.prepare(sql)?.info())
+    }
+
+    /// Returns the git commit DuckDB was built from, as reported by `pragma_version()`.
+    ///
+    /// Together with [`library_version`](crate::library_version) this pins down
+    /// exactly which DuckDB build the connection runs on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn source_id(&mut self) -> Result<String> {
+        self.query_scalar("SELECT source_id FROM pragma_version()")
+    }
+
+    /// Changes a DuckDB setting for this connection, e.g. `threads` or `memory_limit`.
+    ///
+    /// Runs `SET name = 'value'`. The name is quoted as an identifier and the value as
+    /// a string literal, which DuckDB casts to the setting's type, so neither can inject
+    /// SQL. Pragmas that take no value, such as `enable_profiling`, are plain
+    /// statements; run them with [`execute_batch`](Connection::execute_batch).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB does not know the setting or rejects the value.
+    pub fn set_pragma(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        self.execute_batch(format!("SET {} = {}", quote_identifier(name), quote_literal(value)))
+    }
+
+    /// Returns the current value of a DuckDB setting with its own type, e.g.
+    /// [`DuckValue::BigInt`] for `threads`.
+    ///
+    /// Like [`current_setting`](Connection::current_setting), but without converting the
+    /// value to text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB does not know the setting `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::{connection::Connection, types::value::DuckValue};
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.set_pragma("threads", "2")?;
+    /// assert_eq!(conn.get_pragma("threads")?, DuckValue::BigInt(2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_pragma(
+        &mut self,
+        name: &str,
+    ) -> Result<DuckValue> {
+        let sql = format!("SELECT current_setting({}) AS value", quote_literal(name));
+        self.query_row(sql, |row| Ok(row.get("value").cloned().unwrap_or(DuckValue::Null)))
+    }
+
+    /// Returns whether the table (or view) `schema.table` exists in the current database.
+    ///
+    /// Names are matched exactly as stored in `information_schema.tables`, i.e. as
+    /// written in `CREATE TABLE` for quoted identifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB fails to run the catalog query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
+    /// assert!(conn.table_exists("main", "t")?);
+    /// assert!(!conn.table_exists("main", "missing")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn table_exists(
+        &mut self,
+        schema: &str,
+        table: &str,
+    ) -> Result<bool> {
+        let row = self
+            .execute_with(
+                "SELECT 1 FROM information_schema.tables \
+                 WHERE table_catalog = current_database() AND table_schema = ? \
+                 AND table_name = ?",
+                crate::params![schema, table],
+            )?
+            .next()
+            .transpose()?;
+        Ok(row.is_some())
+    }
+
+    /// Returns the `(name, type)` of each column of `table` in the current schema, in
+    /// declaration order, e.g. `("id", "INTEGER")`. A missing table has no columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB fails to run the catalog query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.execute_batch("CREATE TABLE t (id INTEGER, name VARCHAR)")?;
+    /// assert_eq!(
+    ///     conn.columns("t")?,
+    ///     [("id".to_owned(), "INTEGER".to_owned()), ("name".to_owned(), "VARCHAR".to_owned())]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn columns(
+        &mut self,
+        table: &str,
+    ) -> Result<Vec<(String, String)>> {
+        self.execute_with(
+            "SELECT column_name, data_type FROM information_schema.columns \
+             WHERE table_catalog = current_database() AND table_schema = current_schema() \
+             AND table_name = ? ORDER BY ordinal_position",
+            crate::params![table],
+        )?
+        .map(|row| {
+            let row = row?;
+            let text = |name: &str| match row.get(name) {
+                Some(DuckValue::Text(value)) => Ok(value.clone()),
+                _ => Err(Error::ConversionError(DuckDBConversionError::NullValue)),
+            };
+            Ok((text("column_name")?, text("data_type")?))
+        })
+        .collect()
+    }
+
+    /// Prepares and executes a parameterized SQL statement, returning the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if preparation, binding, or execution fails.
+    #[must_use = "the DuckResult carries both affected-row count (.changes()) and a row iterator — consume it"]
+    pub fn execute_with(
+        &mut self,
+        sql: impl AsRef<str>,
+        binds: &mut [&mut dyn AppendAble],
+    ) -> Result<DuckResult> {
+        self.0.execute(sql, binds)
+    }
+
+    /// Runs the `INSERT` statement `sql` once per row, binding each row's values from
+    /// parameter 1, and returns the total number of rows inserted.
+    ///
+    /// Unlike [`appender`](Connection::appender), this goes through the regular query
+    /// path, so column defaults, `ON CONFLICT` clauses and `RETURNING` all apply. The
+    /// statement is prepared once. Each execution commits on its own unless it runs
+    /// inside a [`transaction`](Connection::transaction), which is also much faster for
+    /// many rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sql` cannot be prepared, or if binding or executing a row
+    /// fails. Rows before the failing one stay inserted unless a surrounding
+    /// transaction is rolled back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.execute_batch("CREATE TABLE t (id INTEGER PRIMARY KEY)")?;
+    /// let inserted = conn.bulk_insert(
+    ///     "INSERT INTO t VALUES (?) ON CONFLICT DO NOTHING",
+    ///     &mut [1, 2, 2, 3],
+    /// )?;
+    /// assert_eq!(inserted, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bulk_insert<T: AppendAble>(
+        &mut self,
+        sql: &str,
+        rows: &mut [T],
+    ) -> Result<u64> {
+        let mut stmt = CachedStatement::prepare(&self.0, sql)?;
+        let mut changes = 0;
+        for row in rows {
+            stmt.reset_bindings()?;
+            stmt.bind(1, row)?;
+            changes += stmt.execute()?.changes();
+        }
+        Ok(changes)
+    }
+
+    /// Makes the extension `name` (e.g. `"json"`, `"httpfs"`) available on this
+    /// connection, running `INSTALL` and `LOAD` as needed.
+    ///
+    /// Extensions that are already loaded — including those compiled into the bundled
+    /// library via the `json`/`parquet` features — are left alone, and `INSTALL` is
+    /// skipped for extensions that are already installed, so no download is attempted
+    /// for them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB fails to install or load the extension. Loading an
+    /// unsigned extension without `Config::allow_unsigned_extensions` fails with a
+    /// message pointing at that option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.load_extension("httpfs")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn load_extension(
+        &mut self,
+        name: &str,
+    ) -> Result<()> {
+        let (installed, loaded) = self.extension_status(name)?;
+        if loaded {
+            return Ok(());
+        }
+        if !installed {
+            self.execute_batch(format!("INSTALL {}", quote_literal(name)))?;
+        }
+        self.execute_batch(format!("LOAD {}", quote_literal(name))).map_err(unsigned_extension_hint)
+    }
+
+    /// Installs the extension `name` from a custom repository, given as a URL or as a
+    /// repository alias such as `core_nightly` or `community`.
+    ///
+    /// The extension still has to be loaded, e.g. with
+    /// [`load_extension`](Connection::load_extension).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB cannot download or install the extension.
+    pub fn install_extension_from(
+        &mut self,
+        name: &str,
+        repo: &str,
+    ) -> Result<()> {
+        self.execute_batch(format!("INSTALL {} FROM {}", quote_literal(name), quote_literal(repo)))
+    }
+
+    /// Returns whether the extension `name` is `(installed, loaded)`, according to
+    /// `duckdb_extensions()`. Unknown names report `(false, false)`.
+    fn extension_status(
+        &mut self,
+        name: &str,
+    ) -> Result<(bool, bool)> {
+        let row = self
+            .execute_with(
+                "SELECT installed, loaded FROM duckdb_extensions() WHERE extension_name = ?",
+                crate::params![name],
+            )?
+            .next()
+            .transpose()?;
+        Ok(match row {
+            Some(row) => (
+                row.get("installed") == Some(&DuckValue::Boolean(true)),
+                row.get("loaded") == Some(&DuckValue::Boolean(true)),
+            ),
+            None => (false, false),
+        })
+    }
+
+    /// Registers a scalar function implemented by a Rust closure, so SQL on this
+    /// database can call it by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFunction`] if DuckDB rejects the function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::{connection::Connection, udf::ScalarFunction};
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.register_scalar_function(ScalarFunction::new("halve", |x: f64| x / 2.0)?)?;
+    /// assert_eq!(conn.query_scalar::<f64>("SELECT halve(3)")?, 1.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_scalar_function(
+        &mut self,
+        function: ScalarFunction,
+    ) -> Result<()> {
+        function.register(&self.0)
+    }
+
+    /// Registers a table function whose rows come from a Rust iterator, so SQL on this
+    /// database can read it with `SELECT * FROM name()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFunction`] if DuckDB rejects the function.
+    pub fn register_table_function(
+        &mut self,
+        function: TableFunction,
+    ) -> Result<()> {
+        function.register(&self.0)
+    }
+
+    /// Begins a transaction, returning a guard that rolls it back unless committed.
+    ///
+    /// See [`Transaction`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `BEGIN TRANSACTION` fails, e.g. because a transaction was
+    /// already opened with raw SQL.
+    pub fn transaction(&mut self) -> Result<Transaction<'_>> {
+        Transaction::new(self)
+    }
+
+    /// Creates an appender for bulk-inserting rows into the given table and schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table does not exist or the appender cannot be created.
+    #[must_use = "appender should be used to insert rows"]
+    #[allow(unused)]
+    pub fn appender(
+        &mut self,
+        table: &str,
+        schema: &str,
+    ) -> Result<Appender> {
+        self.0.appender(table, schema)
+    }
+
+    /// Creates `n` appenders for the same table, each on its own connection to this
+    /// database, so a partitioned input can be appended from `n` threads at once.
+    ///
+    /// Building rows runs in parallel, but each flush commits in its own transaction and
+    /// DuckDB serializes those commits: flushing does not get faster with more threads.
+    /// Rows of one appender become visible to the others only once it has flushed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.execute_batch("CREATE TABLE t (id BIGINT)")?;
+    /// let appenders = conn.parallel_appender("t", "main", 2)?;
+    /// std::thread::scope(|s| {
+    ///     for (part, mut appender) in appenders.into_iter().enumerate() {
+    ///         s.spawn(move || {
+    ///             appender.append(&mut (part as i64)).unwrap();
+    ///             appender.save().unwrap();
+    ///         });
+    ///     }
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a connection cannot be opened, or if the table does not exist.
+    pub fn parallel_appender(
+        &self,
+        table: &str,
+        schema: &str,
+        n: usize,
+    ) -> Result<Vec<Appender>> {
+        (0..n).map(|_| Appender::new(self.0.try_clone()?, table, schema)).collect()
+    }
+}
+
+impl Connection {
+    /// Closes the connection explicitly.
+    ///
+    /// The connection is also closed automatically on drop.
+    ///
+    /// # Errors
+    ///
+    /// Always returns `Ok(())`.
+    #[must_use = "close result should be checked"]
+    #[inline]
+    #[allow(unused)]
+    pub fn close(&mut self) -> Result<()> {
+        self.0.close()
+    }
+
+    /// Writes the contents of the write-ahead log into the database file.
+    ///
+    /// Runs `CHECKPOINT`, which is a no-op for in-memory databases.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint fails, e.g. because this connection's open
+    /// transaction has uncommitted changes or another connection is writing.
+    pub fn checkpoint(&mut self) -> Result<()> {
+        self.0.query("CHECKPOINT").map(drop)
+    }
+
+    /// Checkpoints like [`checkpoint`](Connection::checkpoint), but first aborts every
+    /// running transaction instead of failing when another connection is writing.
+    ///
+    /// Runs `FORCE CHECKPOINT`. Use it to bound the write-ahead log of a long-lived
+    /// writer at a point where losing other connections' open transactions is
+    /// acceptable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint fails.
+    pub fn force_checkpoint(&mut self) -> Result<()> {
+        self.0.query("FORCE CHECKPOINT").map(drop)
+    }
+
+    /// Attaches the database at `path` under `alias`, so its tables can be queried as
+    /// `alias.table` alongside this connection's own.
+    ///
+    /// Runs `ATTACH`; `path` may be `:memory:` for a fresh in-memory database. The
+    /// attachment is visible to every connection of this database until
+    /// [`detach`](Connection::detach)ed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or `alias` is already in use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// conn.attach(":memory:", "other", false)?;
+    /// conn.execute_batch("CREATE TABLE other.t AS SELECT 42 AS x")?;
+    /// assert_eq!(conn.query_scalar::<i32>("SELECT x FROM other.t")?, 42);
+    /// conn.detach("other")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach(
+        &mut self,
+        path: &str,
+        alias: &str,
+        read_only: bool,
+    ) -> Result<()> {
+        let mut sql = format!("ATTACH {} AS {}", quote_literal(path), quote_identifier(alias));
+        if read_only {
+            sql.push_str(" (READ_ONLY)");
+        }
+        self.execute_batch(sql)
+    }
+
+    /// Detaches the database attached as `alias` by [`attach`](Connection::attach).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no database is attached as `alias`, or if it is the
+    /// connection's default database.
+    pub fn detach(
+        &mut self,
+        alias: &str,
+    ) -> Result<()> {
+        self.execute_batch(format!("DETACH {}", quote_identifier(alias)))
+    }
+
+    /// Closes the connection and the database behind it, reporting whether the final
+    /// checkpoint succeeded.
+    ///
+    /// Dropping the last connection also closes the database, but DuckDB gives no way
+    /// to learn whether its shutdown checkpoint worked; a failure there leaves the
+    /// write-ahead log next to the file.
+    ///
+    /// After a successful shutdown the connection is closed, as after
+    /// [`close`](Connection::close). An [`InterruptHandle`] does not keep the database
+    /// open.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DatabaseInUse`] if other connections, appenders or a
+    /// [`ConnectionPool`](crate::pool::ConnectionPool) still use the same database; nothing
+    /// is closed then and this connection stays usable. Returns an error if the checkpoint
+    /// fails, in which case the connection and the database are closed anyway.
+    pub fn shutdown(&mut self) -> Result<()> {
+        if Arc::strong_count(&self.0.db) > 1 {
+            return Err(Error::DatabaseInUse);
+        }
+        self.0.close()?;
+        match Arc::get_mut(&mut self.0.db) {
+            Some(db) => db.close(),
+            // Only the registry of a named in-memory database still refers to it, and
+            // there is nothing to checkpoint; dropping `self` closes it.
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if the connection is open.
+    #[inline]
+    #[allow(unused)]
+    pub fn is_open(&self) -> bool {
+        !self.0.con.is_null()
+    }
+
+    /// Returns a handle that can interrupt this connection's queries from another thread.
+    ///
+    /// See [`InterruptHandle`].
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle { target: Arc::clone(&self.0.interrupt) }
+    }
+
+    /// Returns a reference to the underlying `RawConnection`.
+    ///
+    /// This provides access to low-level operations such as `prepare`.
+    #[inline]
+    #[allow(unused)]
+    #[allow(private_interfaces)]
+    pub fn db(&self) -> &RawConnection {
+        &self.0
+    }
+}
+
+/// Interrupts the queries of one [`Connection`] from another thread.
+///
+/// Obtained from [`Connection::interrupt_handle`]. The handle is `Send + Sync` and
+/// cheap to clone, so it can be given to a watchdog thread that aborts a runaway query:
+/// the query then fails with a DuckDB error classified as
+/// [`DuckDBErrorType::Interrupt`](crate::error::DuckDBErrorType::Interrupt).
+///
+/// Interrupting is always harmless. If no query is running, or the connection has
+/// been closed or dropped, [`interrupt`](InterruptHandle::interrupt) does nothing, and
+/// the next query on the connection is not affected. The handle does not keep the
+/// connection or its database open.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::{thread, time::Duration};
+/// # use better_duck_core::{connection::Connection, error::DuckDBErrorType};
+/// let mut conn = Connection::open_in_memory().unwrap();
+/// let handle = conn.interrupt_handle();
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_secs(5));
+///     handle.interrupt();
+/// });
+/// let err = conn.execute_batch("SELECT sum(i) FROM range(1000000000000) t(i)").unwrap_err();
+/// assert_eq!(err.duckdb_error_type(), Some(DuckDBErrorType::Interrupt));
+/// ```
+#[derive(Clone)]
+pub struct InterruptHandle {
+    target: Arc<InterruptTarget>,
+}
+
+impl InterruptHandle {
+    /// Interrupts the query currently running on the connection, if any.
+    pub fn interrupt(&self) {
+        self.target.interrupt();
+    }
+}
+
+impl std::fmt::Debug for InterruptHandle {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("InterruptHandle").finish_non_exhaustive()
+    }
+}
+
+/// Adds a pointer to `Config::allow_unsigned_extensions` to DuckDB's signature error.
+fn unsigned_extension_hint(mut err: Error) -> Error {
+    if let Error::DuckDBFailure { message: Some(message), .. } = &mut err {
+        if message.contains("signature") {
+            message.push_str(
+                "\nHint: open the connection with \
+                 `Config::allow_unsigned_extensions()` to load unsigned extensions",
+            );
+        }
+    }
+    err
+}
+
+// SAFETY: DuckDB connections are safe to move between threads (they do not hold
+// thread-local state). Each `Connection` owns its `RawConnection` exclusively.
+unsafe impl Send for Connection {}
+
+/// A [`Connection`] that can be shared between threads.
+///
+/// `SyncConnection` puts a `Connection` behind a [`Mutex`], so it is `Send + Sync` and
+/// can be wrapped in an [`Arc`](std::sync::Arc) and handed to a thread pool. Every call
+/// locks the connection for its duration, so statements from different threads run one
+/// at a time. For real parallelism, give each thread its own `Connection` to the same
+/// database instead.
+///
+/// The returned [`DuckResult`]s are fully materialized, so they stay valid after the
+/// lock is released and other threads use the connection.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::{sync::Arc, thread};
+/// # use better_duck_core::{connection::SyncConnection, types::value::DuckValue};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let conn = Arc::new(SyncConnection::open_in_memory()?);
+/// let worker = {
+///     let conn = Arc::clone(&conn);
+///     thread::spawn(move || {
+///         conn.query_row("SELECT 1 AS one", |row| Ok(row.get("one").cloned())).unwrap()
+///     })
+/// };
+/// assert_eq!(worker.join().unwrap(), Some(DuckValue::Int(1)));
+/// # Ok(())
+/// # }
+/// ```
+pub struct SyncConnection(Mutex<Connection>);
+
+impl SyncConnection {
+    /// Wraps an open connection.
+    pub fn new(conn: Connection) -> SyncConnection {
+        SyncConnection(Mutex::new(conn))
+    }
+
+    /// Opens a shareable connection to the DuckDB database at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or the path contains a nul byte.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<SyncConnection> {
+        Connection::open(path).map(SyncConnection::new)
+    }
+
+    /// Opens a shareable in-memory DuckDB connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be established.
+    pub fn open_in_memory() -> Result<SyncConnection> {
+        Connection::open_in_memory().map(SyncConnection::new)
+    }
+
+    /// Locks the connection and runs [`Connection::execute`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB cannot prepare or execute the statement.
+    #[must_use = "the DuckResult carries both affected-row count (.changes()) and a row iterator — consume it"]
+    pub fn execute(
+        &self,
+        sql: impl AsRef<str>,
+    ) -> Result<DuckResult> {
+        self.lock().execute(sql)
+    }
+
+    /// Locks the connection and runs [`Connection::execute_batch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any statement fails to execute.
+    #[must_use = "execute_batch result should be checked"]
+    pub fn execute_batch(
+        &self,
+        sql: impl AsRef<str>,
+    ) -> Result<()> {
+        self.lock().execute_batch(sql)
+    }
+
+    /// Locks the connection and runs [`Connection::query`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB cannot execute the query or if `sql` contains a nul byte.
+    #[must_use = "query returns a DuckResult; iterate it to read the rows"]
+    pub fn query(
+        &self,
+        sql: impl AsRef<str>,
+    ) -> Result<DuckResult> {
+        self.lock().query(sql)
+    }
+
+    /// Locks the connection and runs [`Connection::query_row`]; `f` runs while the lock
+    /// is held.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::QueryReturnedNoRows`] if the query yields no rows, or any error
+    /// from executing the query or from `f`.
+    pub fn query_row<T>(
+        &self,
+        sql: impl AsRef<str>,
+        f: impl FnOnce(&DuckRow) -> Result<T>,
+    ) -> Result<T> {
+        self.lock().query_row(sql, f)
+    }
+
+    /// Runs `f` with exclusive access to the wrapped connection, e.g. to use a
+    /// transaction or an appender across several calls without other threads
+    /// interleaving.
+    pub fn with_connection<R>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> R,
+    ) -> R {
+        f(&mut self.lock())
+    }
+
+    /// Unwraps the inner connection.
+    pub fn into_inner(self) -> Connection {
+        self.0.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// A panic in another thread while it held the lock leaves the connection itself
+    /// usable (each DuckDB call either completes or fails as a whole), so a poisoned
+    /// mutex is recovered rather than propagated.
+    fn lock(&self) -> MutexGuard<'_, Connection> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<Connection> for SyncConnection {
+    fn from(conn: Connection) -> Self {
+        SyncConnection::new(conn)
+    }
+}
+
+#[cfg(test)]
+mod connection_tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_open_in_memory() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        assert!(conn.is_open());
+        conn.close().unwrap();
+        assert!(!conn.is_open());
+    }
+
+    #[test]
+    fn test_open_with_flags() {
+        let config = Config::default().with("duckdb_api", "rust").unwrap();
+        let mut conn = Connection::open_with_flags(":memory:", config).unwrap();
+        assert!(conn.is_open());
+        conn.close().unwrap();
+        assert!(!conn.is_open());
+    }
+
+    #[test]
+    fn test_batch_execution() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let exec = conn.execute_batch("CREATE TABLE test (id INTEGER, name TEXT)");
+        assert!(exec.is_ok(), "{}", exec.unwrap_err());
+        let exec = conn.execute_batch("INSERT INTO test VALUES (1, 'example')");
+        assert!(exec.is_ok(), "{}", exec.unwrap_err());
+        conn.close().unwrap();
+    }
+
+    #[test]
+    fn test_unsigned_extension_hint() {
+        let err = Error::DuckDBFailure {
+            error: crate::ffi::Error::new(crate::ffi::DuckDBError),
+            message: Some(
+                "Extension \"x\" could not be loaded because its signature is missing".into(),
+            ),
+            error_type: None,
+        };
+        let msg = unsigned_extension_hint(err).to_string();
+        assert!(msg.contains("allow_unsigned_extensions()"), "{msg}");
+
+        let other = Error::DuckDBFailure {
+            error: crate::ffi::Error::new(crate::ffi::DuckDBError),
+            message: Some("IO Error".into()),
+            error_type: None,
+        };
+        assert_eq!(unsigned_extension_hint(other).to_string(), "IO Error");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_load_builtin_json_extension() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.load_extension("json").unwrap();
+        let v =
+            conn.query_row("SELECT '[1,2]'::JSON AS j", |row| Ok(row.get("j").cloned())).unwrap();
+        assert_eq!(v, Some(DuckValue::text("[1,2]")));
+    }
+
+    #[test]
+    fn test_current_setting_reflects_config() {
+        let config = Config::default().threads(2).unwrap();
+        let mut conn = Connection::open_in_memory_with_flags(config).unwrap();
+        assert_eq!(conn.current_setting("threads").unwrap(), "2");
+        assert!(conn.current_setting("no_such_setting").is_err());
+    }
+
+    #[test]
+    fn test_default_block_size_applies_to_new_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("small_blocks.duckdb");
+        let config = Config::default().default_block_size(16 * 1024).unwrap();
+        let mut conn = Connection::open_with_flags(&path, config).unwrap();
+        assert_eq!(conn.current_setting("default_block_size").unwrap(), "16384");
+        let block_size = conn
+            .query_row("SELECT block_size FROM pragma_database_size()", |row| {
+                Ok(row.get("block_size").cloned())
+            })
+            .unwrap();
+        assert_eq!(block_size, Some(DuckValue::BigInt(16 * 1024)));
     }
 }
@@ -1,11 +1,11 @@
 #![allow(dead_code)]
 // Direct copy from DuckDB
 
-use crate::ffi::{duckdb_type, Error as FFIError};
+use crate::ffi::{self, duckdb_type, Error as FFIError};
 use std::{error, fmt, path::PathBuf, result, str};
 
 /// Describes why a value conversion from or to DuckDB failed.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum DuckDBConversionError {
     /// The DuckDB column type did not match the expected Rust type.
     TypeMismatch {
@@ -20,6 +20,163 @@ pub enum DuckDBConversionError {
     NullValue,
     /// The conversion would lose precision (e.g. Decimal scale overflow).
     PrecisionLoss(String),
+    /// Text read from DuckDB, such as a `VARCHAR` value or a column name, was not valid
+    /// UTF-8.
+    Utf8(str::Utf8Error),
+}
+
+impl From<str::Utf8Error> for DuckDBConversionError {
+    #[cold]
+    fn from(err: str::Utf8Error) -> DuckDBConversionError {
+        DuckDBConversionError::Utf8(err)
+    }
+}
+
+/// The category of a DuckDB error, mirroring DuckDB's `duckdb_error_type`.
+///
+/// Obtained from [`Error::duckdb_error_type`]; use it to react to a class of failure,
+/// e.g. skipping rows that hit a [`Constraint`](DuckDBErrorType::Constraint)
+/// violation, without inspecting the message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DuckDBErrorType {
+    /// An invalid operation or argument.
+    Invalid,
+    /// A value was outside the range of its type.
+    OutOfRange,
+    /// A value could not be cast to the target type.
+    Conversion,
+    /// An unknown type name was used.
+    UnknownType,
+    /// A `DECIMAL` value or operation failed.
+    Decimal,
+    /// Operand types did not match.
+    MismatchType,
+    /// Integer division by zero.
+    DivideByZero,
+    /// An object exceeded its size limit.
+    ObjectSize,
+    /// An invalid type was used.
+    InvalidType,
+    /// Serializing or deserializing data failed.
+    Serialization,
+    /// A transaction failed, e.g. because of a write conflict.
+    Transaction,
+    /// The feature is not implemented.
+    NotImplemented,
+    /// An expression could not be evaluated.
+    Expression,
+    /// A table, schema or other catalog entry is missing or already exists.
+    Catalog,
+    /// The SQL could not be parsed.
+    Parser,
+    /// The query could not be planned.
+    Planner,
+    /// Scheduling the query failed.
+    Scheduler,
+    /// Executing the query failed.
+    Executor,
+    /// A constraint was violated, e.g. a duplicate primary key or a `NULL` in a `NOT NULL` column.
+    Constraint,
+    /// An index operation failed.
+    Index,
+    /// Collecting statistics failed.
+    Stat,
+    /// A connection-level failure.
+    Connection,
+    /// A syntax error outside the parser proper.
+    Syntax,
+    /// A setting was invalid or could not be changed.
+    Settings,
+    /// Names or types in the query could not be resolved.
+    Binder,
+    /// A network failure.
+    Network,
+    /// The optimizer failed.
+    Optimizer,
+    /// A null pointer was encountered.
+    NullPointer,
+    /// Reading or writing a file failed.
+    Io,
+    /// The query was interrupted.
+    Interrupt,
+    /// A fatal error; the database is invalidated.
+    Fatal,
+    /// An internal DuckDB error.
+    Internal,
+    /// The input was invalid, e.g. a malformed CSV file.
+    InvalidInput,
+    /// DuckDB ran out of memory.
+    OutOfMemory,
+    /// The operation is not permitted.
+    Permission,
+    /// A prepared-statement parameter type could not be resolved.
+    ParameterNotResolved,
+    /// A parameter was used where none is allowed.
+    ParameterNotAllowed,
+    /// A catalog entry could not be dropped or changed because others depend on it.
+    Dependency,
+    /// An HTTP request failed.
+    Http,
+    /// A required extension is not loaded.
+    MissingExtension,
+    /// Autoloading an extension failed.
+    Autoload,
+    /// A sequence operation failed.
+    Sequence,
+}
+
+impl DuckDBErrorType {
+    /// Maps a raw `duckdb_error_type`, returning `None` for codes this crate does not
+    /// know.
+    pub fn from_raw(raw: ffi::duckdb_error_type) -> Option<DuckDBErrorType> {
+        use DuckDBErrorType::*;
+        Some(match raw {
+            ffi::duckdb_error_type_DUCKDB_ERROR_INVALID => Invalid,
+            ffi::duckdb_error_type_DUCKDB_ERROR_OUT_OF_RANGE => OutOfRange,
+            ffi::duckdb_error_type_DUCKDB_ERROR_CONVERSION => Conversion,
+            ffi::duckdb_error_type_DUCKDB_ERROR_UNKNOWN_TYPE => UnknownType,
+            ffi::duckdb_error_type_DUCKDB_ERROR_DECIMAL => Decimal,
+            ffi::duckdb_error_type_DUCKDB_ERROR_MISMATCH_TYPE => MismatchType,
+            ffi::duckdb_error_type_DUCKDB_ERROR_DIVIDE_BY_ZERO => DivideByZero,
+            ffi::duckdb_error_type_DUCKDB_ERROR_OBJECT_SIZE => ObjectSize,
+            ffi::duckdb_error_type_DUCKDB_ERROR_INVALID_TYPE => InvalidType,
+            ffi::duckdb_error_type_DUCKDB_ERROR_SERIALIZATION => Serialization,
+            ffi::duckdb_error_type_DUCKDB_ERROR_TRANSACTION => Transaction,
+            ffi::duckdb_error_type_DUCKDB_ERROR_NOT_IMPLEMENTED => NotImplemented,
+            ffi::duckdb_error_type_DUCKDB_ERROR_EXPRESSION => Expression,
+            ffi::duckdb_error_type_DUCKDB_ERROR_CATALOG => Catalog,
+            ffi::duckdb_error_type_DUCKDB_ERROR_PARSER => Parser,
+            ffi::duckdb_error_type_DUCKDB_ERROR_PLANNER => Planner,
+            ffi::duckdb_error_type_DUCKDB_ERROR_SCHEDULER => Scheduler,
+            ffi::duckdb_error_type_DUCKDB_ERROR_EXECUTOR => Executor,
+            ffi::duckdb_error_type_DUCKDB_ERROR_CONSTRAINT => Constraint,
+            ffi::duckdb_error_type_DUCKDB_ERROR_INDEX => Index,
+            ffi::duckdb_error_type_DUCKDB_ERROR_STAT => Stat,
+            ffi::duckdb_error_type_DUCKDB_ERROR_CONNECTION => Connection,
+            ffi::duckdb_error_type_DUCKDB_ERROR_SYNTAX => Syntax,
+            ffi::duckdb_error_type_DUCKDB_ERROR_SETTINGS => Settings,
+            ffi::duckdb_error_type_DUCKDB_ERROR_BINDER => Binder,
+            ffi::duckdb_error_type_DUCKDB_ERROR_NETWORK => Network,
+            ffi::duckdb_error_type_DUCKDB_ERROR_OPTIMIZER => Optimizer,
+            ffi::duckdb_error_type_DUCKDB_ERROR_NULL_POINTER => NullPointer,
+            ffi::duckdb_error_type_DUCKDB_ERROR_IO => Io,
+            ffi::duckdb_error_type_DUCKDB_ERROR_INTERRUPT => Interrupt,
+            ffi::duckdb_error_type_DUCKDB_ERROR_FATAL => Fatal,
+            ffi::duckdb_error_type_DUCKDB_ERROR_INTERNAL => Internal,
+            ffi::duckdb_error_type_DUCKDB_ERROR_INVALID_INPUT => InvalidInput,
+            ffi::duckdb_error_type_DUCKDB_ERROR_OUT_OF_MEMORY => OutOfMemory,
+            ffi::duckdb_error_type_DUCKDB_ERROR_PERMISSION => Permission,
+            ffi::duckdb_error_type_DUCKDB_ERROR_PARAMETER_NOT_RESOLVED => ParameterNotResolved,
+            ffi::duckdb_error_type_DUCKDB_ERROR_PARAMETER_NOT_ALLOWED => ParameterNotAllowed,
+            ffi::duckdb_error_type_DUCKDB_ERROR_DEPENDENCY => Dependency,
+            ffi::duckdb_error_type_DUCKDB_ERROR_HTTP => Http,
+            ffi::duckdb_error_type_DUCKDB_ERROR_MISSING_EXTENSION => MissingExtension,
+            ffi::duckdb_error_type_DUCKDB_ERROR_AUTOLOAD => Autoload,
+            ffi::duckdb_error_type_DUCKDB_ERROR_SEQUENCE => Sequence,
+            _ => return None,
+        })
+    }
 }
 
 /// Enum listing possible errors from duckdb.
@@ -28,7 +185,18 @@ pub enum DuckDBConversionError {
 #[non_exhaustive]
 pub enum Error {
     /// An error from an underlying DuckDB call.
-    DuckDBFailure(FFIError, Option<String>),
+    ///
+    /// More fields may be added, so match it with `..`.
+    #[non_exhaustive]
+    DuckDBFailure {
+        /// The FFI status of the failed call.
+        error: FFIError,
+        /// DuckDB's error message, when it gave one.
+        message: Option<String>,
+        /// The error's category when DuckDB reports one; see
+        /// [`Error::duckdb_error_type`].
+        error_type: Option<DuckDBErrorType>,
+    },
 
     /// Error when the value of a particular column is requested, but it cannot
     /// be converted to the requested Rust type.
@@ -70,6 +238,14 @@ pub enum Error {
     /// matches the name for the statement.
     InvalidColumnName(String),
 
+    /// Error when a row is requested by index, but the index is past the end of the
+    /// result or names a row that was already consumed and released.
+    InvalidRowIndex(u64),
+
+    /// Error when a parameter is requested by its 1-based index, but the statement has
+    /// no parameter at that index.
+    InvalidParameterIndex(u64),
+
     /// Error when the value of a particular column is requested, but the type
     /// of the result in that column cannot be converted to the requested
     /// Rust type.
@@ -94,12 +270,47 @@ pub enum Error {
     /// given, the 2nd is how many were expected.
     InvalidParameterCount(usize, usize),
 
+    /// Error when a row given to an appender has a different number of values than
+    /// the table has columns, or a query returns a different number of columns than
+    /// the caller reads. The first `usize` is how many columns were given, the 2nd
+    /// is how many were expected.
+    InvalidColumnCount(usize, usize),
+
     /// An error occurred while appending a value via the DuckDB appender API.
     AppendError,
 
     /// A value conversion error.
     ConversionError(DuckDBConversionError),
 
+    /// Error when a row-limited result produced more rows than allowed. The
+    /// associated `u64` is the limit that was exceeded.
+    ResultTooLarge(u64),
+
+    /// Error when a transaction is started while another one is still open on the
+    /// same connection; DuckDB does not support nested transactions.
+    NestedTransaction,
+
+    /// Error when a user-defined function cannot be created or registered. The first
+    /// `String` is the function's name, the 2nd says what is wrong with it.
+    InvalidFunction(String, String),
+
+    /// Error when a database is shut down while other connections or interrupt handles
+    /// still refer to it.
+    DatabaseInUse,
+
+    /// Error when no pooled connection became available within the pool's timeout.
+    /// The associated `Duration` is the timeout that elapsed.
+    PoolTimeout(std::time::Duration),
+
+    /// Error when a query was interrupted because it ran longer than its timeout. The
+    /// associated `Duration` is the timeout that elapsed.
+    Timeout(std::time::Duration),
+
+    /// Error when one statement of a multi-statement batch fails. The associated
+    /// `usize` is the 0-based index of the failing statement in the batch, the error is
+    /// what that statement reported. Statements before it have already run.
+    BatchStatementFailed(usize, Box<Error>),
+
     /// An unexpected error with no more specific classification.
     #[allow(non_camel_case_types)]
     UNKNOWN(Box<dyn ::std::error::Error>),
@@ -114,7 +325,10 @@ impl PartialEq for Error {
         other: &Error,
     ) -> bool {
         match (self, other) {
-            (Error::DuckDBFailure(e1, s1), Error::DuckDBFailure(e2, s2)) => e1 == e2 && s1 == s2,
+            (
+                Error::DuckDBFailure { error: e1, message: s1, error_type: t1 },
+                Error::DuckDBFailure { error: e2, message: s2, error_type: t2 },
+            ) => e1 == e2 && s1 == s2 && t1 == t2,
             (Error::IntegralValueOutOfRange(i1, n1), Error::IntegralValueOutOfRange(i2, n2)) => {
                 i1 == i2 && n1 == n2
             },
@@ -126,6 +340,8 @@ impl PartialEq for Error {
             (Error::QueryReturnedNoRows, Error::QueryReturnedNoRows) => true,
             (Error::InvalidColumnIndex(i1), Error::InvalidColumnIndex(i2)) => i1 == i2,
             (Error::InvalidColumnName(n1), Error::InvalidColumnName(n2)) => n1 == n2,
+            (Error::InvalidRowIndex(i1), Error::InvalidRowIndex(i2)) => i1 == i2,
+            (Error::InvalidParameterIndex(i1), Error::InvalidParameterIndex(i2)) => i1 == i2,
             // (Error::InvalidColumnType(i1, n1, t1), Error::InvalidColumnType(i2, n2, t2)) => {
             //     i1 == i2 && t1 == t2 && n1 == n2
             // }
@@ -133,11 +349,61 @@ impl PartialEq for Error {
             (Error::InvalidParameterCount(i1, n1), Error::InvalidParameterCount(i2, n2)) => {
                 i1 == i2 && n1 == n2
             },
+            (Error::InvalidColumnCount(i1, n1), Error::InvalidColumnCount(i2, n2)) => {
+                i1 == i2 && n1 == n2
+            },
+            (Error::ResultTooLarge(n1), Error::ResultTooLarge(n2)) => n1 == n2,
+            (Error::NestedTransaction, Error::NestedTransaction) => true,
+            (Error::InvalidFunction(n1, r1), Error::InvalidFunction(n2, r2)) => {
+                n1 == n2 && r1 == r2
+            },
+            (Error::DatabaseInUse, Error::DatabaseInUse) => true,
+            (Error::PoolTimeout(d1), Error::PoolTimeout(d2)) => d1 == d2,
+            (Error::Timeout(d1), Error::Timeout(d2)) => d1 == d2,
+            (Error::BatchStatementFailed(i1, e1), Error::BatchStatementFailed(i2, e2)) => {
+                i1 == i2 && e1 == e2
+            },
             (..) => false,
         }
     }
 }
 
+impl Error {
+    /// Returns the DuckDB category of this error, or `None` if it did not come from
+    /// DuckDB or carries no category.
+    ///
+    /// The category is the `duckdb_error_type` DuckDB reports for a failed query,
+    /// statement execution or appender. DuckDB's C API reports none for failures while
+    /// preparing or splitting a statement, so those return `None`, as does an
+    /// [`Error::DuckDBFailure`] raised by this crate's own validation. An
+    /// [`Error::BatchStatementFailed`] reports the category of the failing statement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::{connection::Connection, error::DuckDBErrorType};
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let err = conn.query("SELEC 1").err().expect("syntax error");
+    /// assert_eq!(err.duckdb_error_type(), Some(DuckDBErrorType::Parser));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn duckdb_error_type(&self) -> Option<DuckDBErrorType> {
+        match self {
+            Error::DuckDBFailure { error_type, .. } => *error_type,
+            Error::BatchStatementFailed(_, err) => err.duckdb_error_type(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this error is a DuckDB constraint violation, such as a
+    /// duplicate primary key or a `NULL` in a `NOT NULL` column.
+    pub fn is_constraint_violation(&self) -> bool {
+        self.duckdb_error_type() == Some(DuckDBErrorType::Constraint)
+    }
+}
+
 impl From<str::Utf8Error> for Error {
     #[cold]
     fn from(err: str::Utf8Error) -> Error {
@@ -152,6 +418,13 @@ impl From<::std::ffi::NulError> for Error {
     }
 }
 
+impl From<DuckDBConversionError> for Error {
+    #[cold]
+    fn from(err: DuckDBConversionError) -> Error {
+        Error::ConversionError(err)
+    }
+}
+
 const UNKNOWN_COLUMN: usize = usize::MAX;
 
 /// The conversion isn't precise, but it's convenient to have it
@@ -183,8 +456,8 @@ impl fmt::Display for Error {
         f: &mut fmt::Formatter<'_>,
     ) -> fmt::Result {
         match self {
-            Error::DuckDBFailure(ref err, None) => err.fmt(f),
-            Error::DuckDBFailure(_, Some(ref s)) => write!(f, "{s}"),
+            Error::DuckDBFailure { error, message: None, .. } => error.fmt(f),
+            Error::DuckDBFailure { message: Some(ref s), .. } => write!(f, "{s}"),
             // Error::FromSqlConversionFailure(i, ref t, ref err) => {
             //     if i != UNKNOWN_COLUMN {
             //         write!(f, "Conversion error from type {t} at index: {i}, {err}")
@@ -209,6 +482,8 @@ impl fmt::Display for Error {
             Error::QueryReturnedNoRows => write!(f, "Query returned no rows"),
             Error::InvalidColumnIndex(i) => write!(f, "Invalid column index: {i}"),
             Error::InvalidColumnName(ref name) => write!(f, "Invalid column name: {name}"),
+            Error::InvalidRowIndex(i) => write!(f, "Invalid row index: {i}"),
+            Error::InvalidParameterIndex(i) => write!(f, "Invalid parameter index: {i}"),
             // Error::InvalidColumnType(i, ref name, ref t) => {
             //     write!(f, "Invalid column type {t} at index: {i}, name: {name}")
             // }
@@ -218,6 +493,9 @@ impl fmt::Display for Error {
             Error::InvalidParameterCount(i1, n1) => {
                 write!(f, "Wrong number of parameters passed to query. Got {i1}, needed {n1}")
             },
+            Error::InvalidColumnCount(i1, n1) => {
+                write!(f, "Wrong number of columns: expected {n1} columns, got {i1}")
+            },
             Error::StatementChangedRows(i) => write!(f, "Query changed {i} rows"),
             Error::ToSqlConversionFailure(ref err) => err.fmt(f),
             Error::InvalidQuery => write!(f, "Query is not read-only"),
@@ -232,6 +510,24 @@ impl fmt::Display for Error {
                 },
                 DuckDBConversionError::NullValue => write!(f, "Null value encountered"),
                 DuckDBConversionError::PrecisionLoss(ref msg) => write!(f, "Precision loss: {msg}"),
+                DuckDBConversionError::Utf8(ref err) => write!(f, "Invalid UTF-8: {err}"),
+            },
+            Error::ResultTooLarge(n) => write!(f, "Query returned more than {n} rows"),
+            Error::NestedTransaction => {
+                write!(f, "A transaction is already open; DuckDB does not support nesting them")
+            },
+            Error::InvalidFunction(ref name, ref reason) => {
+                write!(f, "Invalid function `{name}`: {reason}")
+            },
+            Error::DatabaseInUse => {
+                write!(f, "The database is still used by other connections")
+            },
+            Error::PoolTimeout(d) => {
+                write!(f, "Timed out after {d:?} waiting for a pooled connection")
+            },
+            Error::Timeout(d) => write!(f, "Query interrupted after running for {d:?}"),
+            Error::BatchStatementFailed(i, e) => {
+                write!(f, "Statement {i} of the batch failed: {e}")
             },
             Error::UNKNOWN(e) => write!(f, "Unknown error: {e}"),
         }
@@ -241,9 +537,10 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
-            Error::DuckDBFailure(ref err, _) => Some(err),
+            Error::DuckDBFailure { error, .. } => Some(error),
             Error::Utf8Error(ref err) => Some(err),
             Error::NulError(ref err) => Some(err),
+            Error::ConversionError(DuckDBConversionError::Utf8(ref err)) => Some(err),
 
             Error::IntegralValueOutOfRange(..)
             | Error::InvalidParameterName(_)
@@ -251,18 +548,68 @@ impl error::Error for Error {
             | Error::QueryReturnedNoRows
             | Error::InvalidColumnIndex(_)
             | Error::InvalidColumnName(_)
+            | Error::InvalidRowIndex(_)
+            | Error::InvalidParameterIndex(_)
             // | Error::InvalidColumnType(..)
             | Error::InvalidPath(_)
             | Error::InvalidParameterCount(..)
+            | Error::InvalidColumnCount(..)
             | Error::StatementChangedRows(_)
             | Error::InvalidQuery
             | Error::AppendError
             // | Error::ArrowTypeToDuckdbType(..)
             | Error::MultipleStatement
+            | Error::ResultTooLarge(_)
+            | Error::NestedTransaction
+            | Error::InvalidFunction(..)
+            | Error::DatabaseInUse
+            | Error::PoolTimeout(_)
+            | Error::Timeout(_)
             | Error::ConversionError(_) => None,
             // Error::FromSqlConversionFailure(_, _, ref err)
             Error::ToSqlConversionFailure(ref err) => Some(&**err),
+            Error::BatchStatementFailed(_, ref err) => Some(&**err),
             Error::UNKNOWN(e) => Some(e.as_ref()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure(
+        message: &str,
+        kind: Option<DuckDBErrorType>,
+    ) -> Error {
+        Error::DuckDBFailure {
+            error: FFIError::new(ffi::DuckDBError),
+            message: Some(message.to_owned()),
+            error_type: kind,
+        }
+    }
+
+    #[test]
+    fn test_duckdb_error_type_is_the_stored_category() {
+        let err = failure(
+            "Duplicate key \"id: 1\" violates primary key",
+            Some(DuckDBErrorType::Constraint),
+        );
+        assert_eq!(err.duckdb_error_type(), Some(DuckDBErrorType::Constraint));
+        assert!(err.is_constraint_violation());
+        // The message text is never consulted.
+        assert_eq!(failure("Constraint Error: Duplicate key", None).duckdb_error_type(), None);
+        assert_eq!(Error::QueryReturnedNoRows.duckdb_error_type(), None);
+        let batch = Error::BatchStatementFailed(1, Box::new(err));
+        assert_eq!(batch.duckdb_error_type(), Some(DuckDBErrorType::Constraint));
+    }
+
+    #[test]
+    fn test_duckdb_error_type_from_raw() {
+        assert_eq!(
+            DuckDBErrorType::from_raw(ffi::duckdb_error_type_DUCKDB_ERROR_CONSTRAINT),
+            Some(DuckDBErrorType::Constraint)
+        );
+        assert_eq!(DuckDBErrorType::from_raw(ffi::duckdb_error_type::MAX), None);
+    }
+}
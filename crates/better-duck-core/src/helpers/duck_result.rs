@@ -3,13 +3,60 @@
 use std::ffi::CStr;
 
 use crate::ffi::{
-    duckdb_appender, duckdb_appender_destroy, duckdb_appender_error, duckdb_arrow,
-    duckdb_destroy_arrow, duckdb_destroy_prepare, duckdb_destroy_result, duckdb_prepare_error,
-    duckdb_prepared_statement, duckdb_query_arrow_error, duckdb_result, duckdb_result_error,
-    duckdb_state, DuckDBSuccess, Error as FFIError,
+    duckdb_appender, duckdb_appender_destroy, duckdb_appender_error_data, duckdb_arrow,
+    duckdb_destroy_arrow, duckdb_destroy_error_data, duckdb_destroy_prepare, duckdb_destroy_result,
+    duckdb_error_data_error_type, duckdb_error_data_has_error, duckdb_error_data_message,
+    duckdb_prepare_error, duckdb_prepared_statement, duckdb_query_arrow_error, duckdb_result,
+    duckdb_result_error, duckdb_result_error_type, duckdb_state, DuckDBSuccess, Error as FFIError,
 };
 
-use crate::error::{Error, Result};
+use crate::error::{DuckDBErrorType, Error, Result};
+
+/// Copies the message and category of the error stored in `out`, or returns `None` if
+/// the result holds no error.
+///
+/// # Safety
+///
+/// `out` must point to a valid `duckdb_result`.
+pub(crate) unsafe fn result_error(
+    out: *mut duckdb_result
+) -> Option<(String, Option<DuckDBErrorType>)> {
+    // SAFETY: guaranteed by the caller; the message is owned by the result and copied.
+    unsafe {
+        let c_err = duckdb_result_error(out);
+        if c_err.is_null() {
+            return None;
+        }
+        let message = CStr::from_ptr(c_err).to_string_lossy().into_owned();
+        Some((message, DuckDBErrorType::from_raw(duckdb_result_error_type(out))))
+    }
+}
+
+/// Copies the message and category of the appender's last error, or returns `None` if
+/// it has none.
+///
+/// # Safety
+///
+/// `appender` must be a valid, non-null `duckdb_appender`.
+pub(crate) unsafe fn appender_error(
+    appender: duckdb_appender
+) -> Option<(String, Option<DuckDBErrorType>)> {
+    // SAFETY: guaranteed by the caller. The error data is a copy owned by us; its
+    // message is copied before the data is destroyed.
+    unsafe {
+        let mut data = duckdb_appender_error_data(appender);
+        if data.is_null() {
+            return None;
+        }
+        let error = duckdb_error_data_has_error(data).then(|| {
+            let message = CStr::from_ptr(duckdb_error_data_message(data));
+            let kind = DuckDBErrorType::from_raw(duckdb_error_data_error_type(data));
+            (message.to_string_lossy().into_owned(), kind)
+        });
+        duckdb_destroy_error_data(&mut data);
+        error
+    }
+}
 
 /// Converts a DuckDB error code and optional message into a `Result<()>` with a `DuckDBFailure` error.
 ///
@@ -33,7 +80,18 @@ fn error_from_duckdb_code(
     code: duckdb_state,
     message: Option<String>,
 ) -> Result<()> {
-    Err(Error::DuckDBFailure(FFIError::new(code), message))
+    Err(Error::DuckDBFailure { error: FFIError::new(code), message, error_type: None })
+}
+
+/// Like [`error_from_duckdb_code`], keeping the category DuckDB reported with the
+/// message.
+#[inline]
+fn typed_error_from_duckdb_code(
+    code: duckdb_state,
+    error: Option<(String, Option<DuckDBErrorType>)>,
+) -> Result<()> {
+    let (message, kind) = error.unzip();
+    Err(Error::DuckDBFailure { error: FFIError::new(code), message, error_type: kind.flatten() })
 }
 
 /// Converts the result of a DuckDB appender operation into a `Result<()>`.
@@ -72,15 +130,12 @@ pub fn result_from_duckdb_appender(
     // may be null internally if creation failed, which we check via `(*appender).is_null()`.
     // On failure we extract the error string and destroy the appender.
     unsafe {
-        let message = if (*appender).is_null() {
-            Some("appender is null".to_string())
-        } else {
-            let c_err = duckdb_appender_error(*appender);
-            let message = Some(CStr::from_ptr(c_err).to_string_lossy().to_string());
-            duckdb_appender_destroy(appender);
-            message
-        };
-        error_from_duckdb_code(code, message)
+        if (*appender).is_null() {
+            return error_from_duckdb_code(code, Some("appender is null".to_string()));
+        }
+        let error = appender_error(*appender);
+        duckdb_appender_destroy(appender);
+        typed_error_from_duckdb_code(code, error)
     }
 }
 
@@ -215,14 +270,10 @@ pub fn result_from_duckdb_result(
     // SAFETY: `out` is a `*mut duckdb_result` that was passed to `duckdb_query` or
     // `duckdb_execute_prepared`. On error DuckDB writes error info into `*out`, and
     // `duckdb_result_error` returns a pointer into that memory. We copy the error string
-    // and then destroy the result.
+    // and read its category, and then destroy the result.
     unsafe {
-        let message = {
-            let c_err = duckdb_result_error(out);
-            let message = Some(CStr::from_ptr(c_err).to_string_lossy().to_string());
-            duckdb_destroy_result(out);
-            message
-        };
-        error_from_duckdb_code(code, message)
+        let error = result_error(out);
+        duckdb_destroy_result(out);
+        typed_error_from_duckdb_code(code, error)
     }
 }
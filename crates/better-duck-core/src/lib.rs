@@ -12,23 +12,51 @@ pub extern crate libduckdb_sys;
 /// Re-export of the raw `libduckdb_sys` FFI bindings.
 pub use libduckdb_sys as ffi;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod capabilities;
 mod config;
 /// High-level DuckDB connection type.
 pub mod connection;
 /// Error types returned by this crate.
 pub mod error;
 mod helpers;
+/// A bounded pool of connections for multi-threaded workloads.
+pub mod pool;
 mod raw;
+/// Helpers for building SQL text safely.
+pub mod sql;
+/// Table functions streaming rows produced by Rust code.
+pub mod table_function;
+/// RAII transaction guard returned by [`connection::Connection::transaction`].
+pub mod transaction;
 /// DuckDB type system and value conversion traits.
 pub mod types;
+/// Scalar user-defined functions implemented by Rust closures.
+pub mod udf;
 
+/// Version and feature information about the linked DuckDB library.
+pub use capabilities::{capabilities, library_version, Capabilities};
+/// Database configuration passed to [`connection::Connection::open_with_flags`].
+pub use config::{AccessMode, Config, DefaultNullOrder, DefaultOrder};
+/// The name and type of one result column.
+pub use raw::result::ColumnInfo;
 /// A fully iterable DuckDB query result.
 pub use raw::result::DuckResult;
+/// An iterator decoding the rows of a borrowed result into a [`types::from_row::FromRow`] type.
+pub use raw::result::MappedRows;
+/// A borrowing iterator that walks a result from its first row without consuming it.
+pub use raw::result::Rows;
+/// An iterator decoding result rows into a [`types::from_row::FromRow`] type.
+pub use raw::result::TypedRows;
 /// A single row from a DuckDB query result.
 pub use raw::row::DuckRow;
 /// A prepared statement suitable for caching and re-execution.
 pub use raw::statement::CachedStatement;
-/// Trait for binding values to DuckDB prepared statements and appenders.
+/// The parameter types of a prepared statement.
+pub use raw::statement::PreparedInfo;
+/// Trait for binding values to DuckDB prepared statements and appenders (and, with the
+/// `derive` feature, its derive macro).
 pub use types::appendable::AppendAble;
 /// A calendar date value for use without the `chrono` feature.
 #[cfg(not(feature = "chrono"))]
@@ -0,0 +1,306 @@
+//! A blocking pool of connections to one database.
+//!
+//! [`ConnectionPool::get`] checks out a [`PooledConnection`], which derefs to a
+//! [`Connection`] and goes back to the pool when dropped. The pool opens connections on
+//! demand up to its size and can be shared between threads.
+
+use std::{
+    ops::{Deref, DerefMut},
+    path::Path,
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    connection::Connection,
+    error::{Error, Result},
+    ffi,
+    raw::connection::{RawConnection, RawDatabase},
+};
+
+/// A bounded pool of connections to one DuckDB database.
+///
+/// Opening a DuckDB connection allocates a fresh client context, which is wasteful to do
+/// per request in a server. The pool keeps connections that are checked back in and
+/// hands them out again from [`get`](ConnectionPool::get). At most `max_size`
+/// connections exist at once; when all of them are in use, `get` blocks until one is
+/// returned, or fails with [`Error::PoolTimeout`] once the timeout set with
+/// [`with_timeout`](ConnectionPool::with_timeout) elapses.
+///
+/// The pool is `Send + Sync`, so it can live in an [`Arc`] shared by worker threads.
+/// Each connection is used by one thread at a time, giving real parallelism up to
+/// `max_size` queries.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{pool::ConnectionPool, types::value::DuckValue};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let pool = ConnectionPool::open_in_memory(4)?;
+/// pool.get()?.execute_batch("CREATE TABLE t (id INTEGER)")?;
+///
+/// let mut conn = pool.get()?;
+/// conn.execute_batch("INSERT INTO t VALUES (1)")?;
+/// let n = conn.query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))?;
+/// assert_eq!(n, Some(DuckValue::BigInt(1)));
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConnectionPool {
+    db: Arc<RawDatabase>,
+    state: Mutex<PoolState>,
+    /// Signalled whenever a connection is checked in or a slot frees up.
+    available: Condvar,
+    max_size: usize,
+    timeout: Option<Duration>,
+}
+
+struct PoolState {
+    idle: Vec<Connection>,
+    /// Connections created and not yet dropped, idle or checked out.
+    live: usize,
+}
+
+impl ConnectionPool {
+    /// Creates a pool around `conn`, which becomes its first idle connection. Further
+    /// connections to the same database are opened on demand, up to `max_size` in total.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `max_size` is `0`.
+    pub fn new(
+        conn: Connection,
+        max_size: usize,
+    ) -> Result<ConnectionPool> {
+        if max_size == 0 {
+            return Err(Error::DuckDBFailure {
+                error: ffi::Error::new(ffi::DuckDBError),
+                message: Some("connection pool size must be at least 1".to_owned()),
+                error_type: None,
+            });
+        }
+        Ok(ConnectionPool {
+            db: Arc::clone(&conn.db().db),
+            state: Mutex::new(PoolState { idle: vec![conn], live: 1 }),
+            available: Condvar::new(),
+            max_size,
+            timeout: None,
+        })
+    }
+
+    /// Opens the database at `path` and creates a pool of up to `max_size` connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or `max_size` is `0`.
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        max_size: usize,
+    ) -> Result<ConnectionPool> {
+        ConnectionPool::new(Connection::open(path)?, max_size)
+    }
+
+    /// Creates a pool of up to `max_size` connections to a new in-memory database.
+    ///
+    /// All connections from the pool see the same in-memory database.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or `max_size` is `0`.
+    pub fn open_in_memory(max_size: usize) -> Result<ConnectionPool> {
+        ConnectionPool::new(Connection::open_in_memory()?, max_size)
+    }
+
+    /// Makes [`get`](ConnectionPool::get) give up with [`Error::PoolTimeout`] after
+    /// waiting `timeout` for a connection, instead of blocking indefinitely.
+    #[must_use = "with_timeout returns the configured pool"]
+    pub fn with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> ConnectionPool {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the maximum number of connections the pool will open.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns the number of connections currently waiting in the pool.
+    pub fn idle_count(&self) -> usize {
+        self.lock_state().idle.len()
+    }
+
+    /// Checks a connection out of the pool.
+    ///
+    /// Reuses an idle connection if there is one, opens a new one if fewer than
+    /// `max_size` exist, and otherwise waits for another caller to return one. The
+    /// connection goes back into the pool when the returned guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PoolTimeout`] if a timeout is set and no connection became
+    /// available in time, or an error if opening a new connection fails.
+    pub fn get(&self) -> Result<PooledConnection<'_>> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut state = self.lock_state();
+        loop {
+            if let Some(conn) = state.idle.pop() {
+                return Ok(PooledConnection { pool: self, conn: Some(conn) });
+            }
+            if state.live < self.max_size {
+                state.live += 1;
+                // Connect without holding the lock so other callers are not held up.
+                drop(state);
+                return match RawConnection::new(Arc::clone(&self.db)) {
+                    Ok(raw) => {
+                        Ok(PooledConnection { pool: self, conn: Some(Connection::from_raw(raw)) })
+                    },
+                    Err(e) => {
+                        self.release_slot();
+                        Err(e)
+                    },
+                };
+            }
+            state = match deadline {
+                None => self.available.wait(state).unwrap_or_else(PoisonError::into_inner),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(Error::PoolTimeout(self.timeout.unwrap_or_default()));
+                    }
+                    self.available
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                },
+            };
+        }
+    }
+
+    fn check_in(
+        &self,
+        conn: Connection,
+    ) {
+        self.lock_state().idle.push(conn);
+        self.available.notify_one();
+    }
+
+    fn release_slot(&self) {
+        self.lock_state().live -= 1;
+        self.available.notify_one();
+    }
+
+    /// The state is only ever modified in single steps, so it stays consistent even if
+    /// a thread panicked while holding the lock.
+    fn lock_state(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A connection checked out of a [`ConnectionPool`].
+///
+/// Derefs to [`Connection`]. Dropping the guard returns the connection to the pool, or,
+/// if it was closed while checked out, frees its slot so the pool can open a new one.
+pub struct PooledConnection<'pool> {
+    pool: &'pool ConnectionPool,
+    conn: Option<Connection>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().expect("pooled connection is present until drop")
+    }
+}
+
+impl DerefMut for PooledConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn.as_mut().expect("pooled connection is present until drop")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        match self.conn.take() {
+            Some(conn) if conn.is_open() => self.pool.check_in(conn),
+            _ => self.pool.release_slot(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, thread};
+
+    use super::*;
+    use crate::types::value::DuckValue;
+
+    #[test]
+    fn test_pool_reuses_connections() {
+        let pool = ConnectionPool::open_in_memory(2).unwrap();
+        pool.get().unwrap().execute_batch("CREATE TABLE t (id INTEGER)").unwrap();
+        {
+            let mut a = pool.get().unwrap();
+            let mut b = pool.get().unwrap();
+            a.execute_batch("INSERT INTO t VALUES (1)").unwrap();
+            b.execute_batch("INSERT INTO t VALUES (2)").unwrap();
+            assert_eq!(pool.idle_count(), 0);
+        }
+        assert_eq!(pool.idle_count(), 2);
+        let n = pool
+            .get()
+            .unwrap()
+            .query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))
+            .unwrap();
+        assert_eq!(n, Some(DuckValue::BigInt(2)));
+    }
+
+    #[test]
+    fn test_third_get_waits_for_a_connection() {
+        let pool = ConnectionPool::open_in_memory(2).unwrap();
+        let first = pool.get().unwrap();
+        let second = pool.get().unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        thread::scope(|s| {
+            s.spawn(|| {
+                let mut third = pool.get().unwrap();
+                tx.send(()).unwrap();
+                third.execute_batch("SELECT 1").unwrap();
+            });
+            // Both connections are checked out, so the waiter cannot proceed yet.
+            assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+            drop(first);
+            rx.recv_timeout(Duration::from_secs(10)).expect("waiter got the returned connection");
+        });
+        drop(second);
+        assert_eq!(pool.idle_count(), 2);
+    }
+
+    #[test]
+    fn test_get_times_out_when_exhausted() {
+        let pool =
+            ConnectionPool::open_in_memory(1).unwrap().with_timeout(Duration::from_millis(50));
+        let _held = pool.get().unwrap();
+        let err = pool.get().err().expect("pool is exhausted");
+        assert_eq!(err, Error::PoolTimeout(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn test_closed_connection_frees_its_slot() {
+        let pool =
+            ConnectionPool::open_in_memory(1).unwrap().with_timeout(Duration::from_millis(50));
+        pool.get().unwrap().close().unwrap();
+        assert_eq!(pool.idle_count(), 0);
+        // The slot was released, so a fresh connection can be opened.
+        pool.get().unwrap().execute_batch("SELECT 1").unwrap();
+    }
+
+    #[test]
+    fn test_zero_size_pool_is_rejected() {
+        assert!(ConnectionPool::open_in_memory(0).is_err());
+    }
+}
@@ -1,14 +1,15 @@
 use std::ffi::{c_char, CString};
-use std::ptr;
+use std::ptr::{self, NonNull};
 
-use crate::error::Result;
+use crate::error::{DuckDBErrorType, Error, Result};
 use crate::ffi::{
-    duckdb_appender, duckdb_appender_begin_row, duckdb_appender_close, duckdb_appender_create,
-    duckdb_appender_destroy, duckdb_appender_end_row, duckdb_appender_flush,
+    self, duckdb_appender, duckdb_appender_begin_row, duckdb_appender_close,
+    duckdb_appender_column_count, duckdb_appender_column_type, duckdb_appender_create,
+    duckdb_appender_destroy, duckdb_appender_end_row, duckdb_appender_flush, DUCKDB_TYPE,
 };
-use crate::helpers::duck_result::result_from_duckdb_appender;
-use crate::raw::connection::RawConnection;
-use crate::types::appendable::AppendAble;
+use crate::helpers::duck_result::{appender_error, result_from_duckdb_appender};
+use crate::raw::{connection::RawConnection, row::DuckRow};
+use crate::types::{appendable::AppendAble, value::DuckValue, value_ref::DuckValueRef};
 
 /// A DuckDB appender for bulk-inserting rows into a table without going through
 /// the SQL parser.
@@ -16,9 +17,21 @@ use crate::types::appendable::AppendAble;
 /// Call [`append`](Appender::append) for each row and [`save`](Appender::save)
 /// to flush the data to the database. Rows are also flushed automatically on drop
 /// (errors during the implicit flush are logged to stderr).
+///
+/// # Generated columns
+///
+/// Generated (virtual) columns such as `c INTEGER AS (a + b)` are not part of the
+/// appender's row layout: DuckDB excludes them when the appender is created, so each
+/// appended row only supplies values for the physical columns, in table order. The
+/// generated values are computed on read.
 pub struct Appender {
     _con: RawConnection,
     inn: duckdb_appender,
+    /// Whether [`begin_row`](Appender::begin_row) started a row that was not ended yet.
+    in_row: bool,
+    /// Columns filled so far in the current row. Each value counts as its
+    /// [`AppendAble::appender_column_count`], or as one column if it does not report it.
+    appended: usize,
 }
 
 impl Appender {
@@ -46,75 +59,334 @@ impl Appender {
                 &mut appender,
             )
         };
-        result_from_duckdb_appender(res, &mut appender)
-            .map(|_| Appender { _con: con, inn: appender })
+        result_from_duckdb_appender(res, &mut appender).map(|_| Appender {
+            _con: con,
+            inn: appender,
+            in_row: false,
+            appended: 0,
+        })
+    }
+
+    /// Returns the number of columns each appended row must supply.
+    ///
+    /// Generated columns are not counted, see the type-level docs.
+    pub fn column_count(&self) -> usize {
+        // SAFETY: `self.inn` is a valid duckdb_appender (or null, which yields 0).
+        unsafe { duckdb_appender_column_count(self.inn) as usize }
+    }
+
+    /// Returns the DuckDB type of the appender column at `idx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnIndex`] if `idx` is out of range.
+    pub fn column_type(
+        &self,
+        idx: usize,
+    ) -> Result<DUCKDB_TYPE> {
+        // SAFETY: `self.inn` is a valid duckdb_appender; out-of-range indices yield null.
+        let mut logical_type = unsafe { duckdb_appender_column_type(self.inn, idx as u64) };
+        if logical_type.is_null() {
+            return Err(Error::InvalidColumnIndex(idx));
+        }
+        // SAFETY: `logical_type` is a valid logical type created above.
+        let type_id = unsafe { ffi::duckdb_get_type_id(logical_type) };
+        // SAFETY: `logical_type` was created above and is destroyed exactly once.
+        unsafe { ffi::duckdb_destroy_logical_type(&mut logical_type) };
+        Ok(type_id)
+    }
+
+    /// Fails with [`Error::InvalidColumnCount`] unless `given` matches the column count.
+    fn check_column_count(
+        &self,
+        given: usize,
+    ) -> Result<()> {
+        let expected = self.column_count();
+        if given != expected {
+            return Err(Error::InvalidColumnCount(given, expected));
+        }
+        Ok(())
+    }
+
+    /// Starts a row to be filled column by column with
+    /// [`append_column`](Appender::append_column) and finished with
+    /// [`end_row`](Appender::end_row).
+    ///
+    /// This maps directly to `duckdb_appender_begin_row`, for rows assembled from
+    /// several sources. [`append`](Appender::append) does all three steps for a value
+    /// that fills the whole row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB rejects the call.
+    pub fn begin_row(&mut self) -> Result<()> {
+        // SAFETY: `self.inn` is a valid duckdb_appender created in `new`.
+        let rc = unsafe { duckdb_appender_begin_row(self.inn) };
+        self.in_row = true;
+        self.appended = 0;
+        result_from_duckdb_appender(rc, &mut self.inn)
+    }
+
+    /// Appends the next column(s) of the row started with
+    /// [`begin_row`](Appender::begin_row).
+    ///
+    /// `value` fills as many columns as its [`AppendAble`] impl appends: one for a
+    /// scalar, several for a multi-column row type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be converted or appended, e.g. past the last
+    /// column.
+    pub fn append_column<T: AppendAble>(
+        &mut self,
+        value: &mut T,
+    ) -> Result<()> {
+        debug_assert!(self.in_row, "append_column called outside begin_row/end_row");
+        value.appender_append(self.inn)?;
+        self.appended += value.appender_column_count().unwrap_or(1);
+        Ok(())
+    }
+
+    /// Finishes the row started with [`begin_row`](Appender::begin_row).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnCount`] if the row does not fill every column, or an
+    /// error if DuckDB rejects the row. As with any failed row, the appender cannot be
+    /// used afterwards.
+    pub fn end_row(&mut self) -> Result<()> {
+        debug_assert!(self.in_row, "end_row called without a matching begin_row");
+        self.in_row = false;
+        self.finish_row()
+    }
+
+    /// Ends the current row. If DuckDB rejects it and fewer columns were appended than
+    /// the table has, the failure is reported as [`Error::InvalidColumnCount`] instead of
+    /// DuckDB's generic end-of-row message.
+    fn finish_row(&mut self) -> Result<()> {
+        let expected = self.column_count();
+        // SAFETY: `self.inn` is a valid duckdb_appender inside a begun row.
+        let rc = unsafe { duckdb_appender_end_row(self.inn) };
+        if rc != ffi::DuckDBSuccess && self.appended < expected {
+            // The appender is destroyed on a failed row either way.
+            let _ = result_from_duckdb_appender(rc, &mut self.inn);
+            return Err(Error::InvalidColumnCount(self.appended, expected));
+        }
+        result_from_duckdb_appender(rc, &mut self.inn)
     }
 
     /// Appends a row to the table.
     ///
     /// Calls `duckdb_appender_begin_row`, then the value appender, then
-    /// `duckdb_appender_end_row`.
+    /// `duckdb_appender_end_row`. If `row` reports how many columns it fills (see
+    /// [`AppendAble::appender_column_count`]), that is checked against
+    /// [`column_count`](Appender::column_count) before anything is appended. A row that
+    /// does not report it counts as one column, so a multi-column table that rejects it
+    /// at the end of the row reports a column count of one.
     ///
     /// # Errors
     ///
-    /// Returns an error if the row cannot be appended.
+    /// Returns [`Error::InvalidColumnCount`] if `row` fills too few columns, or reports
+    /// a different count than the table has, or an error if the row cannot be appended
+    /// (including a row with too many values, which DuckDB rejects at the first extra
+    /// value).
     #[must_use = "append result should be checked"]
     #[allow(dead_code)]
     pub fn append<T: AppendAble>(
         &mut self,
         row: &mut T,
     ) -> Result<()> {
+        if let Some(given) = row.appender_column_count() {
+            self.check_column_count(given)?;
+        }
         // SAFETY: `self.inn` is a valid duckdb_appender created in `new`.
         let _ = unsafe { duckdb_appender_begin_row(self.inn) };
         row.appender_append(self.inn)?;
+        self.appended = row.appender_column_count().unwrap_or(1);
+        self.finish_row()
+    }
+
+    /// Appends every row in `rows`, in order.
+    ///
+    /// This is [`append`](Appender::append) in a loop: rows accumulate in the
+    /// appender's buffer and nothing is flushed, neither between rows nor at the end.
+    /// Call [`save`](Appender::save) once the batch (or several batches) is in, or let
+    /// the appender flush on drop.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first row that fails and returns its error; the rows before it
+    /// stay buffered.
+    #[must_use = "append_all result should be checked"]
+    pub fn append_all<T: AppendAble>(
+        &mut self,
+        rows: &mut [T],
+    ) -> Result<()> {
+        rows.iter_mut().try_for_each(|row| self.append(row))
+    }
+
+    /// Appends one row made of `values`, one value per column in table order.
+    ///
+    /// Each value is appended with the `duckdb_append_*` call for its variant; `NULL`s
+    /// and nested values (lists, structs, maps) go through `duckdb_append_value`. An
+    /// empty list carries no element type, so it takes the element type of its target
+    /// column. This makes it possible to copy rows read from one table into another
+    /// without a dedicated [`AppendAble`] type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnCount`] if `values` does not have one value per
+    /// column, or an error if a value cannot be converted or DuckDB rejects the row.
+    #[must_use = "append_values result should be checked"]
+    pub fn append_values(
+        &mut self,
+        values: &[DuckValue],
+    ) -> Result<()> {
+        self.check_column_count(values.len())?;
+        // SAFETY: `self.inn` is a valid duckdb_appender created in `new`.
+        let _ = unsafe { duckdb_appender_begin_row(self.inn) };
+        for (col, value) in values.iter().enumerate() {
+            match value {
+                DuckValue::List(items) if items.is_empty() => self.append_empty_list(col)?,
+                _ => DuckValueRef::from(value).appender_append(self.inn)?,
+            }
+        }
         // SAFETY: `self.inn` is a valid duckdb_appender; `begin_row` was called above.
         let rc = unsafe { duckdb_appender_end_row(self.inn) };
         result_from_duckdb_appender(rc, &mut self.inn)
     }
 
+    /// Appends the values of `row`, e.g. one read from another table with the same
+    /// column layout. See [`append_values`](Appender::append_values).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`append_values`](Appender::append_values).
+    #[must_use = "append_row result should be checked"]
+    pub fn append_row(
+        &mut self,
+        row: &DuckRow,
+    ) -> Result<()> {
+        self.append_values(row.values())
+    }
+
+    /// Appends an empty list typed after the `LIST` column at `col`.
+    fn append_empty_list(
+        &mut self,
+        col: usize,
+    ) -> Result<()> {
+        let failure = |msg: String| Error::DuckDBFailure {
+            error: ffi::Error::new(ffi::DuckDBError),
+            message: Some(msg),
+            error_type: None,
+        };
+        // SAFETY: `self.inn` is a valid duckdb_appender; out-of-range columns yield null.
+        let mut col_type = unsafe { ffi::duckdb_appender_column_type(self.inn, col as u64) };
+        if col_type.is_null() {
+            return Err(failure(format!("appender has no column {col}")));
+        }
+        // SAFETY: `col_type` is a valid logical type; non-LIST types yield null.
+        let mut child = unsafe { ffi::duckdb_list_type_child_type(col_type) };
+        // SAFETY: `col_type` was created above and is destroyed exactly once.
+        unsafe { ffi::duckdb_destroy_logical_type(&mut col_type) };
+        if child.is_null() {
+            return Err(failure(format!("cannot append an empty list to non-LIST column {col}")));
+        }
+        // SAFETY: `child` is a valid logical type; with a count of 0 the (non-null,
+        // dangling) values pointer is never read.
+        let mut list = unsafe {
+            ffi::duckdb_create_list_value(
+                child,
+                NonNull::<ffi::duckdb_value>::dangling().as_ptr(),
+                0,
+            )
+        };
+        // SAFETY: `child` was created above and is destroyed exactly once.
+        unsafe { ffi::duckdb_destroy_logical_type(&mut child) };
+        if list.is_null() {
+            return Err(failure(format!("failed to create an empty list for column {col}")));
+        }
+        // SAFETY: `self.inn` is inside a begin_row/end_row pair; `list` is a valid value.
+        let rc = unsafe { ffi::duckdb_append_value(self.inn, list) };
+        // SAFETY: `list` was created above and is destroyed exactly once.
+        unsafe { ffi::duckdb_destroy_value(&mut list) };
+        if rc != ffi::DuckDBSuccess {
+            return Err(failure(format!("failed to append an empty list to column {col}")));
+        }
+        Ok(())
+    }
+
     /// Flushes all buffered rows to the database.
     ///
+    /// The appender stays open, so `save` can be called repeatedly during a long ingest
+    /// to bound the memory held by buffered rows. Only [`Drop`] destroys the appender.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the flush fails.
+    /// Returns an error if the flush fails, e.g. on a constraint violation. The rows
+    /// buffered since the last successful flush are discarded.
     #[must_use = "save result should be checked"]
     #[allow(dead_code)]
     pub fn save(&mut self) -> Result<()> {
-        // SAFETY: `self.inn` is a valid duckdb_appender.
         self.flush()
     }
 
-    /// Flushes the appender's internal buffer.
-    ///
-    /// # Safety
-    ///
-    /// `self.inn` must be a valid, non-null `duckdb_appender`.
+    /// Flushes the appender's internal buffer without destroying the appender.
     fn flush(&mut self) -> Result<()> {
-        // SAFETY: `self.inn` is a valid duckdb_appender (enforced by the caller).
-        let res = unsafe { duckdb_appender_flush(self.inn) };
-        result_from_duckdb_appender(res, &mut self.inn)
+        if self.inn.is_null() {
+            return Err(Error::DuckDBFailure {
+                error: ffi::Error::new(ffi::DuckDBError),
+                message: Some("appender is closed".to_string()),
+                error_type: None,
+            });
+        }
+        // SAFETY: `self.inn` is a valid, non-null duckdb_appender (checked above).
+        let rc = unsafe { duckdb_appender_flush(self.inn) };
+        if rc == ffi::DuckDBSuccess {
+            return Ok(());
+        }
+        let (message, kind) = self.last_error().unzip();
+        Err(Error::DuckDBFailure {
+            error: ffi::Error::new(rc),
+            message,
+            error_type: kind.flatten(),
+        })
+    }
+
+    /// Copies the message and category of the appender's last error, if any.
+    fn last_error(&self) -> Option<(String, Option<DuckDBErrorType>)> {
+        // SAFETY: `self.inn` is a valid, non-null duckdb_appender (enforced by callers).
+        unsafe { appender_error(self.inn) }
     }
 }
 
+// SAFETY: every `Appender` is created on a connection of its own (a clone made for it in
+// `RawConnection::appender` or `Connection::parallel_appender`) that nothing else uses,
+// so moving it to another thread moves the only user of that connection, as for
+// `Connection`.
+unsafe impl Send for Appender {}
+
 impl Drop for Appender {
     fn drop(&mut self) {
+        // A failed append destroys the appender and nulls `self.inn`; nothing is left to
+        // tear down then.
         if self.inn.is_null() {
             return;
         }
-        // [err-result-over-panic] — log on flush failure; never panic in Drop.
-        // SAFETY: `self.inn` is non-null (checked above); it is a valid duckdb_appender
-        // created in `new`. After close and destroy it is invalidated. The null guard
-        // above ensures this runs at most once.
+        // [err-result-over-panic] — log failures; never panic in Drop.
         if let Err(e) = self.flush() {
             eprintln!("[better-duck] appender flush on drop failed: {e}");
         }
-        // SAFETY: `self.inn` is a valid, non-null duckdb_appender (null guard above).
-        // Close and destroy are safe to call in sequence; after destroy the handle is
-        // invalid and will not be used again.
-        unsafe {
-            duckdb_appender_close(self.inn);
-            duckdb_appender_destroy(&mut self.inn);
+        // SAFETY: `self.inn` is a valid, non-null duckdb_appender: `flush` never destroys
+        // it. Closing before destroying keeps the error message readable.
+        let rc = unsafe { duckdb_appender_close(self.inn) };
+        if rc != ffi::DuckDBSuccess {
+            let message = self.last_error().map(|(message, _)| message).unwrap_or_default();
+            eprintln!("[better-duck] appender close on drop failed: {message}");
         }
+        // SAFETY: `self.inn` is still valid and is destroyed exactly once here; destroy
+        // nulls it, so no later call can reach the freed appender.
+        unsafe { duckdb_appender_destroy(&mut self.inn) };
     }
 }
 
@@ -226,6 +498,275 @@ mod appender_tests {
         }
     }
 
+    #[test]
+    fn test_appender_rows_collect_into_column() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE test_append (id INTEGER, name VARCHAR)").unwrap();
+
+        let mut appender = Appender::new(con.clone(), "test_append", "main").unwrap();
+        appender.append(&mut Row(1, "Alice")).unwrap();
+        appender.append(&mut Row(2, "Sara")).unwrap();
+        appender.append(&mut Row(3, "Charlie")).unwrap();
+        appender.save().unwrap();
+
+        let mut result = con.query("SELECT id FROM test_append ORDER BY id").unwrap();
+        assert_eq!(result.collect_column::<i32>(0).unwrap(), [1, 2, 3]);
+
+        let mut result = con.query("SELECT NULLIF(id, 2) FROM test_append ORDER BY id").unwrap();
+        assert_eq!(result.collect_column::<Option<i32>>(0).unwrap(), [Some(1), None, Some(3)]);
+        let mut result = con.query("SELECT NULLIF(id, 2) FROM test_append ORDER BY id").unwrap();
+        assert!(matches!(
+            result.collect_column::<i32>(0),
+            Err(Error::ConversionError(DuckDBConversionError::NullValue))
+        ));
+        let mut result = con.query("SELECT id FROM test_append").unwrap();
+        assert_eq!(result.collect_column::<i32>(1).unwrap_err(), Error::InvalidColumnIndex(1));
+    }
+
+    #[test]
+    fn test_appender_save_keeps_appender_open() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE batches (id INTEGER, name VARCHAR)").unwrap();
+        let count = |con: &mut RawConnection| {
+            let mut stmt = con.prepare("SELECT count(*) AS n FROM batches").unwrap();
+            let row = stmt.execute().unwrap().next().unwrap().unwrap();
+            row.get("n").cloned()
+        };
+
+        let mut appender = Appender::new(con.clone(), "batches", "main").unwrap();
+        appender.append(&mut Row(1, "first")).unwrap();
+        appender.save().unwrap();
+        assert_eq!(count(&mut con), Some(DuckValue::BigInt(1)));
+
+        appender.append(&mut Row(2, "second")).unwrap();
+        appender.append(&mut Row(3, "third")).unwrap();
+        appender.save().unwrap();
+        assert_eq!(count(&mut con), Some(DuckValue::BigInt(3)));
+
+        appender.append(&mut Row(4, "on drop")).unwrap();
+        drop(appender);
+        assert_eq!(count(&mut con), Some(DuckValue::BigInt(4)));
+    }
+
+    #[test]
+    fn test_appender_drop_after_rows_and_failures() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE dropped (id INTEGER PRIMARY KEY, name VARCHAR)").unwrap();
+
+        let mut appender = Appender::new(con.clone(), "dropped", "main").unwrap();
+        appender.append(&mut Row(1, "kept")).unwrap();
+        drop(appender);
+
+        // A failing flush leaves the appender to be closed and destroyed by drop.
+        let mut appender = Appender::new(con.clone(), "dropped", "main").unwrap();
+        appender.append(&mut Row(1, "duplicate")).unwrap();
+        assert!(appender.save().is_err());
+        drop(appender);
+
+        // A failing append destroys the appender before drop runs.
+        let mut appender = Appender::new(con.clone(), "dropped", "main").unwrap();
+        assert!(appender.append(&mut IdOnly(2)).is_err());
+        drop(appender);
+
+        let mut stmt = con.prepare("SELECT count(*) AS n FROM dropped").unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("n"), Some(&DuckValue::BigInt(1)));
+    }
+
+    #[test]
+    fn test_appender_row_built_column_by_column() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE wide (id INTEGER, name VARCHAR, score DOUBLE)").unwrap();
+
+        let mut appender = Appender::new(con.clone(), "wide", "main").unwrap();
+        appender.begin_row().unwrap();
+        appender.append_column(&mut 7i32).unwrap();
+        appender.append_column(&mut "seven".to_string()).unwrap();
+        appender.append_column(&mut 0.7f64).unwrap();
+        appender.end_row().unwrap();
+        appender.save().unwrap();
+
+        let mut stmt = con.prepare("SELECT id, name, score FROM wide").unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            row.values(),
+            [DuckValue::Int(7), DuckValue::text("seven"), DuckValue::Double(0.7)]
+        );
+    }
+
+    #[test]
+    fn test_end_row_reports_columns_appended_so_far() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE triple (a INTEGER, b INTEGER, c INTEGER)").unwrap();
+        let mut appender = Appender::new(con.clone(), "triple", "main").unwrap();
+        appender.begin_row().unwrap();
+        appender.append_column(&mut (1i32, 2i32)).unwrap();
+        assert_eq!(appender.end_row().unwrap_err(), Error::InvalidColumnCount(2, 3));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "end_row called without a matching begin_row")]
+    fn test_end_row_without_begin_row_panics_in_debug() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE unbegun (id INTEGER)").unwrap();
+        let mut appender = Appender::new(con.clone(), "unbegun", "main").unwrap();
+        let _ = appender.end_row();
+    }
+
+    #[test]
+    fn test_appender_append_all() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE bulk (id INTEGER, name VARCHAR)").unwrap();
+
+        let mut rows: Vec<_> = (0..10_000).map(|i| Row(i, "bulk")).collect();
+        let mut appender = Appender::new(con.clone(), "bulk", "main").unwrap();
+        appender.append_all(&mut rows).unwrap();
+        appender.save().unwrap();
+
+        let mut stmt = con.prepare("SELECT count(*) AS n, sum(id) AS total FROM bulk").unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("n"), Some(&DuckValue::BigInt(10_000)));
+        assert_eq!(row.get("total"), Some(&DuckValue::HugeInt(49_995_000)));
+    }
+
+    #[test]
+    fn test_appender_skips_generated_columns() {
+        let mut con = get_test_connection();
+        let _ = con
+            .query(
+                "CREATE TABLE test_generated \
+                 (id INTEGER, name VARCHAR, doubled INTEGER AS (id * 2), shout VARCHAR AS (name || '!'))",
+            )
+            .unwrap();
+
+        let mut appender = Appender::new(con.clone(), "test_generated", "main").unwrap();
+        appender.append(&mut Row(21, "duck")).unwrap();
+        appender.save().unwrap();
+
+        let mut stmt = con.prepare("SELECT id, name, doubled, shout FROM test_generated").unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("id"), Some(&DuckValue::Int(21)));
+        assert_eq!(row.get("name"), Some(&DuckValue::text("duck")));
+        assert_eq!(row.get("doubled"), Some(&DuckValue::Int(42)));
+        assert_eq!(row.get("shout"), Some(&DuckValue::text("duck!")));
+    }
+
+    #[test]
+    fn test_appender_copies_rows_between_tables() {
+        let mut con = get_test_connection();
+        let _ = con
+            .query(
+                "CREATE TABLE src (id INTEGER, name VARCHAR, tags VARCHAR[], score DOUBLE); \
+                 INSERT INTO src VALUES (1, 'a', ['x', NULL], 1.5), (2, NULL, NULL, NULL), \
+                 (3, 'c', [], -2.0)",
+            )
+            .unwrap();
+        let _ = con.query("CREATE TABLE dst AS SELECT * FROM src LIMIT 0").unwrap();
+
+        let mut appender = Appender::new(con.clone(), "dst", "main").unwrap();
+        let mut stmt = con.prepare("SELECT * FROM src ORDER BY id").unwrap();
+        let source: Vec<_> = stmt.execute().unwrap().collect::<Result<_>>().unwrap();
+        for row in &source {
+            appender.append_row(row).unwrap();
+        }
+        appender.save().unwrap();
+
+        let mut stmt = con.prepare("SELECT * FROM dst ORDER BY id").unwrap();
+        let copied: Vec<_> = stmt.execute().unwrap().collect::<Result<_>>().unwrap();
+        assert_eq!(copied.len(), 3);
+        for (a, b) in source.iter().zip(&copied) {
+            assert_eq!(a.values(), b.values());
+        }
+        assert_eq!(copied[1].get("name"), Some(&DuckValue::Null));
+    }
+
+    #[test]
+    fn test_append_values_rejects_wrong_column_count() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE pair (a INTEGER, b INTEGER)").unwrap();
+        let mut appender = Appender::new(con.clone(), "pair", "main").unwrap();
+        let err = appender.append_values(&[DuckValue::Int(1)]).unwrap_err();
+        assert_eq!(err, Error::InvalidColumnCount(1, 2));
+        assert_eq!(err.to_string(), "Wrong number of columns: expected 2 columns, got 1");
+        let err = appender.append(&mut DuckValue::Int(1)).unwrap_err();
+        assert_eq!(err, Error::InvalidColumnCount(1, 2));
+
+        // The rejected rows left the appender usable.
+        appender.append_values(&[DuckValue::Int(1), DuckValue::Int(2)]).unwrap();
+        appender.save().unwrap();
+        let mut stmt = con.prepare("SELECT count(*) AS n FROM pair").unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("n"), Some(&DuckValue::BigInt(1)));
+    }
+
+    #[test]
+    fn test_appender_appends_tuples_as_rows() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE pair (a INTEGER, b VARCHAR)").unwrap();
+        let mut appender = Appender::new(con.clone(), "pair", "main").unwrap();
+        appender.append(&mut (1i32, "one")).unwrap();
+        let err = appender.append(&mut (2i32, "two", true)).unwrap_err();
+        assert_eq!(err, Error::InvalidColumnCount(3, 2));
+        appender.save().unwrap();
+
+        let mut stmt = con.prepare("SELECT a, b FROM pair").unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("a"), Some(&DuckValue::Int(1)));
+        assert_eq!(row.get("b"), Some(&DuckValue::text("one")));
+    }
+
+    /// A user-defined row that only fills the first of the table's columns.
+    struct IdOnly(i32);
+
+    impl AppendAble for IdOnly {
+        fn appender_append(
+            &mut self,
+            appender: duckdb_appender,
+        ) -> Result<()> {
+            self.0.appender_append(appender)
+        }
+
+        fn stmt_append(
+            &mut self,
+            idx: u64,
+            stmt: crate::ffi::duckdb_prepared_statement,
+        ) -> Result<()> {
+            self.0.stmt_append(idx, stmt)
+        }
+    }
+
+    #[test]
+    fn test_append_reports_too_narrow_row() {
+        let mut con = get_test_connection();
+        let _ = con.query("CREATE TABLE narrow (id INTEGER, name VARCHAR)").unwrap();
+
+        let mut appender = Appender::new(con.clone(), "narrow", "main").unwrap();
+        appender.append(&mut Row(1, "kept")).unwrap();
+        appender.save().unwrap();
+        let err = appender.append(&mut IdOnly(2)).unwrap_err();
+        assert_eq!(err, Error::InvalidColumnCount(1, 2));
+        assert_eq!(err.to_string(), "Wrong number of columns: expected 2 columns, got 1");
+        drop(appender);
+
+        // The saved row is kept; the short row was never inserted.
+        let mut stmt = con.prepare("SELECT count(*) AS n FROM narrow").unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("n"), Some(&DuckValue::BigInt(1)));
+    }
+
+    #[test]
+    fn test_appender_column_count_and_types() {
+        let mut con = get_test_connection();
+        let _ =
+            con.query("CREATE TABLE typed (a INTEGER, b VARCHAR, c INTEGER AS (a + 1))").unwrap();
+        let appender = Appender::new(con.clone(), "typed", "main").unwrap();
+        assert_eq!(appender.column_count(), 2);
+        assert_eq!(appender.column_type(0).unwrap(), ffi::DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+        assert_eq!(appender.column_type(1).unwrap(), ffi::DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR);
+        assert_eq!(appender.column_type(2).unwrap_err(), Error::InvalidColumnIndex(2));
+    }
+
     #[test]
     fn test_appender_error_on_invalid_table() {
         let c_path = path_to_cstring(":memory:".as_ref()).unwrap();
@@ -1,20 +1,24 @@
 use std::{
+    collections::HashMap,
     ffi::{c_void, CStr, CString},
     mem,
     os::raw::c_char,
     ptr, str,
-    sync::Arc,
+    sync::{Arc, LazyLock, Mutex, PoisonError, Weak},
 };
 
 use crate::{
     config::Config,
     error::{Error, Result},
     ffi::{
-        duckdb_close, duckdb_connect, duckdb_connection, duckdb_database, duckdb_disconnect,
-        duckdb_free, duckdb_open_ext, duckdb_query, duckdb_result, DuckDBError, DuckDBSuccess,
+        duckdb_close, duckdb_connect, duckdb_connection, duckdb_database, duckdb_destroy_extracted,
+        duckdb_destroy_result, duckdb_disconnect, duckdb_extract_statements,
+        duckdb_extract_statements_error, duckdb_extracted_statements, duckdb_free,
+        duckdb_interrupt, duckdb_open_ext, duckdb_prepare_extracted_statement,
+        duckdb_prepared_statement, duckdb_query, duckdb_result, DuckDBError, DuckDBSuccess,
         Error as FFIError,
     },
-    helpers::duck_result::result_from_duckdb_result,
+    helpers::duck_result::{result_from_duckdb_prepare, result_from_duckdb_result},
     raw::{
         appender::Appender,
         result::DuckResult,
@@ -62,13 +66,64 @@ impl RawDatabase {
     #[inline]
     pub unsafe fn new(db: duckdb_database) -> Result<RawDatabase> {
         if db.is_null() {
-            return Err(Error::DuckDBFailure(
-                FFIError::new(DuckDBError),
-                Some("database is null".to_owned()),
-            ));
+            return Err(Error::DuckDBFailure {
+                error: FFIError::new(DuckDBError),
+                message: Some("database is null".to_owned()),
+                error_type: None,
+            });
         }
         Ok(RawDatabase(db))
     }
+
+    /// Checkpoints and closes the database.
+    ///
+    /// `duckdb_close` reports nothing, and a checkpoint that fails while it shuts down
+    /// leaves the write-ahead log behind. A `CHECKPOINT` is therefore run first so that
+    /// a failure can be returned; the database is closed either way. Subsequent calls,
+    /// and the eventual drop, are no-ops.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no connection can be opened for the checkpoint or the
+    /// checkpoint fails.
+    pub fn close(&mut self) -> Result<()> {
+        if self.0.is_null() {
+            return Ok(());
+        }
+        let checkpoint = self.checkpoint();
+        // SAFETY: `self.0` is a valid, open duckdb_database; `duckdb_close` sets it to
+        // null, so neither this method nor `drop` closes it again.
+        unsafe { duckdb_close(&mut self.0) };
+        checkpoint
+    }
+
+    /// Runs `CHECKPOINT` on a short-lived connection of its own.
+    fn checkpoint(&mut self) -> Result<()> {
+        let mut con: duckdb_connection = ptr::null_mut();
+        // SAFETY: `self.0` is a valid, open duckdb_database.
+        if unsafe { duckdb_connect(self.0, &mut con) } != DuckDBSuccess {
+            return Err(Error::DuckDBFailure {
+                error: FFIError::new(DuckDBError),
+                message: Some("could not connect to checkpoint the database".to_owned()),
+                error_type: None,
+            });
+        }
+        // SAFETY: an all-zeros `duckdb_result` is the expected initial state of the
+        // output parameter.
+        let mut out = unsafe { mem::zeroed::<duckdb_result>() };
+        // SAFETY: `con` was just connected and the SQL is a static nul-terminated string.
+        // On failure `result_from_duckdb_result` destroys `out`; on success it is
+        // destroyed here. `con` is disconnected exactly once.
+        unsafe {
+            let r = duckdb_query(con, c"CHECKPOINT".as_ptr(), &mut out);
+            let checkpoint = result_from_duckdb_result(r, &mut out);
+            if checkpoint.is_ok() {
+                duckdb_destroy_result(&mut out);
+            }
+            duckdb_disconnect(&mut con);
+            checkpoint
+        }
+    }
 }
 // SAFETY: The DuckDB database handle is internally reference-counted and thread-safe.
 // Multiple connections (each on its own thread) may share the same database handle.
@@ -90,6 +145,13 @@ impl Drop for RawDatabase {
     }
 }
 
+/// Named in-memory databases opened by [`RawConnection::open_in_memory_named`].
+///
+/// Only weak references are kept, so a database is freed once its last connection is
+/// dropped; the next open under the same name starts from an empty database.
+static NAMED_IN_MEMORY: LazyLock<Mutex<HashMap<String, Weak<RawDatabase>>>> =
+    LazyLock::new(Default::default);
+
 /// A low-level connection to a DuckDB database.
 ///
 /// `RawConnection` manages both a connection handle and a reference to the underlying
@@ -101,6 +163,15 @@ impl Drop for RawDatabase {
 /// should not be shared between threads. Instead, create new connections using
 /// [`try_clone`](RawConnection::try_clone) for each thread.
 ///
+/// `RawConnection` is deliberately neither `Send` nor `Sync`: the `duckdb_connection`
+/// pointer carries the connection's client context (its open transaction, prepared
+/// statements and pending query), which DuckDB does not guard against concurrent use.
+/// The raw pointer keeps the compiler from assuming otherwise. The public
+/// [`Connection`](crate::connection::Connection) is `Send` because it owns its
+/// connection exclusively, and
+/// [`SyncConnection`](crate::connection::SyncConnection) adds the mutex needed to share
+/// one between threads.
+///
 /// # Resource Management
 ///
 /// Connections are automatically closed when dropped. The underlying database remains open
@@ -121,8 +192,37 @@ pub struct RawConnection {
     pub db: Arc<RawDatabase>,
     /// The raw DuckDB connection handle.
     pub con: duckdb_connection,
+    /// The same handle, shared with interrupt handles on other threads.
+    pub(crate) interrupt: Arc<InterruptTarget>,
 }
 
+/// A connection handle that other threads may interrupt.
+///
+/// [`RawConnection::close`] clears the handle under the lock before disconnecting, so
+/// an interrupt either reaches the live connection or finds it gone, never a freed one.
+pub(crate) struct InterruptTarget(Mutex<duckdb_connection>);
+
+impl InterruptTarget {
+    /// Interrupts the query running on the connection, if it is still open.
+    pub(crate) fn interrupt(&self) {
+        let con = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        // SAFETY: a non-null `con` is open — `close` nulls it under this lock before
+        // disconnecting. `duckdb_interrupt` only sets the client context's atomic
+        // interrupted flag, which is safe while another thread runs a query.
+        unsafe { duckdb_interrupt(*con) };
+    }
+
+    fn clear(&self) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = ptr::null_mut();
+    }
+}
+
+// SAFETY: the handle is only used for `duckdb_interrupt`, which is thread-safe, and every
+// access goes through the mutex.
+unsafe impl Send for InterruptTarget {}
+// SAFETY: see the `Send` impl above.
+unsafe impl Sync for InterruptTarget {}
+
 impl RawConnection {
     /// Returns the underlying raw DuckDB connection handle.
     ///
@@ -146,7 +246,7 @@ impl RawConnection {
     ///
     /// Returns an error if the connection cannot be established.
     #[inline]
-    fn new(db: Arc<RawDatabase>) -> Result<RawConnection> {
+    pub(crate) fn new(db: Arc<RawDatabase>) -> Result<RawConnection> {
         let mut con: duckdb_connection = ptr::null_mut();
         // SAFETY: `db.0` is a valid open duckdb_database; `con` is a valid output pointer.
         let r = unsafe { duckdb_connect(db.0, &mut con) };
@@ -154,9 +254,14 @@ impl RawConnection {
             // SAFETY: `con` may be partially initialized on failure; `duckdb_disconnect`
             // handles null/invalid handles gracefully.
             unsafe { duckdb_disconnect(&mut con) };
-            return Err(Error::DuckDBFailure(FFIError::new(r), Some("connect error".to_owned())));
+            return Err(Error::DuckDBFailure {
+                error: FFIError::new(r),
+                message: Some("connect error".to_owned()),
+                error_type: None,
+            });
         }
-        Ok(RawConnection { db, con })
+        let interrupt = Arc::new(InterruptTarget(Mutex::new(con)));
+        Ok(RawConnection { db, con, interrupt })
     }
 
     /// Opens a new connection to the database at the given path with the specified config.
@@ -180,12 +285,43 @@ impl RawConnection {
             if r != DuckDBSuccess {
                 let msg = Some(CStr::from_ptr(c_err).to_string_lossy().to_string());
                 duckdb_free(c_err as *mut c_void);
-                return Err(Error::DuckDBFailure(FFIError::new(r), msg));
+                return Err(Error::DuckDBFailure {
+                    error: FFIError::new(r),
+                    message: msg,
+                    error_type: None,
+                });
             }
             RawConnection::new(Arc::new(RawDatabase::new(db)?))
         }
     }
 
+    /// Opens a connection to the in-memory database called `name`, creating it with
+    /// `config` if no connection to it is open yet.
+    ///
+    /// Each `duckdb_open` of `:memory:` creates a separate database, and DuckDB treats a
+    /// `:memory:name` path as a file name, so connections to a named database share one
+    /// [`RawDatabase`] through a process-wide registry instead. `config` is ignored when
+    /// the database already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or the connection cannot be
+    /// established.
+    pub(crate) fn open_in_memory_named(
+        name: &str,
+        config: Config,
+    ) -> Result<RawConnection> {
+        // Held while opening, so concurrent callers cannot create the database twice.
+        let mut registry = NAMED_IN_MEMORY.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(db) = registry.get(name).and_then(Weak::upgrade) {
+            return RawConnection::new(db);
+        }
+        let conn = RawConnection::open_with_flags(c":memory:", config)?;
+        registry.retain(|_, db| db.strong_count() > 0);
+        registry.insert(name.to_owned(), Arc::downgrade(&conn.db));
+        Ok(conn)
+    }
+
     /// Closes the connection, releasing the underlying DuckDB handle.
     ///
     /// Subsequent calls are no-ops.
@@ -197,6 +333,7 @@ impl RawConnection {
         if self.con.is_null() {
             return Ok(());
         }
+        self.interrupt.clear();
         // SAFETY: `self.con` is a valid open duckdb_connection. After disconnect it is
         // set to null so this code path cannot run twice.
         unsafe {
@@ -245,7 +382,92 @@ impl RawConnection {
             duckdb_query(self.con, c_str.as_ptr() as *const c_char, &mut *out as *mut duckdb_result)
         };
         result_from_duckdb_result(r, &mut *out as *mut duckdb_result)?;
-        Ok(DuckResult::new(*out))
+        DuckResult::new(*out)
+    }
+
+    /// Executes a SQL statement and returns the fully materialized result in Arrow
+    /// format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL cannot be executed or if `sql` contains a nul byte.
+    #[cfg(feature = "arrow")]
+    pub fn query_arrow(
+        &mut self,
+        sql: impl AsRef<str>,
+    ) -> Result<crate::arrow::ArrowResult> {
+        let c_str = CString::new(sql.as_ref())?;
+        let mut out: crate::ffi::duckdb_arrow = ptr::null_mut();
+        // SAFETY: `self.con` is a valid open duckdb_connection and `c_str` a valid
+        // null-terminated CString that outlives this call. On failure `out` is destroyed
+        // by `result_from_duckdb_arrow`; on success `ArrowResult` takes ownership.
+        let r = unsafe { crate::ffi::duckdb_query_arrow(self.con, c_str.as_ptr(), &mut out) };
+        crate::helpers::duck_result::result_from_duckdb_arrow(r, out)?;
+        crate::arrow::ArrowResult::new(out)
+    }
+
+    /// Runs every `;`-separated statement in `sql`, one after another.
+    ///
+    /// The batch is split with DuckDB's own parser, then each statement is prepared and
+    /// executed in order, so a statement may depend on tables created earlier in the
+    /// batch. Execution stops at the first failing statement.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BatchStatementFailed`] with the 0-based index of the statement
+    /// that failed to prepare or execute; the statements before it have run. A batch of
+    /// a single statement returns that statement's error as is. A syntax error anywhere
+    /// in the batch is reported as a plain [`Error::DuckDBFailure`] before any statement
+    /// runs, because DuckDB parses the whole batch up front.
+    pub fn execute_batch(
+        &mut self,
+        sql: impl AsRef<str>,
+    ) -> Result<()> {
+        let c_str = CString::new(sql.as_ref())?;
+        let mut extracted = ExtractedStatements(ptr::null_mut());
+        // SAFETY: `self.con` is a valid open duckdb_connection and `c_str` a valid
+        // null-terminated CString. `extracted` owns the output and destroys it on drop.
+        let count =
+            unsafe { duckdb_extract_statements(self.con, c_str.as_ptr(), &mut extracted.0) };
+        if extracted.0.is_null() {
+            // DuckDB only leaves the output unset for a closed connection.
+            return Err(Error::DuckDBFailure {
+                error: FFIError::new(DuckDBError),
+                message: Some("connection is closed".to_owned()),
+                error_type: None,
+            });
+        }
+        if count == 0 {
+            // SAFETY: `extracted.0` is the non-null handle written above; a non-null error
+            // string is owned by it and copied before it is destroyed.
+            let c_err = unsafe { duckdb_extract_statements_error(extracted.0) };
+            if c_err.is_null() {
+                return Ok(());
+            }
+            // SAFETY: `c_err` is a non-null, null-terminated C string.
+            let message = unsafe { CStr::from_ptr(c_err) }.to_string_lossy().into_owned();
+            return Err(Error::DuckDBFailure {
+                error: FFIError::new(DuckDBError),
+                message: Some(message),
+                error_type: None,
+            });
+        }
+        for index in 0..count {
+            let failed = |e| match count {
+                1 => e,
+                _ => Error::BatchStatementFailed(index as usize, Box::new(e)),
+            };
+            let mut stmt: duckdb_prepared_statement = ptr::null_mut();
+            // SAFETY: `extracted.0` holds `count` statements and `index < count`; `stmt`
+            // is a valid output pointer, destroyed by `result_from_duckdb_prepare` on
+            // failure and by `Statement`'s `Drop` otherwise.
+            let r = unsafe {
+                duckdb_prepare_extracted_statement(self.con, extracted.0, index, &mut stmt)
+            };
+            result_from_duckdb_prepare(r, stmt).map_err(failed)?;
+            Statement::from_prepared(self, stmt).execute().map_err(failed)?;
+        }
+        Ok(())
     }
 
     /// Prepares a SQL statement for execution.
@@ -303,10 +525,11 @@ impl RawConnection {
         if res.changes() > 0 {
             Ok(())
         } else {
-            Err(Error::DuckDBFailure(
-                FFIError::new(DuckDBError),
-                Some("Failed to insert values".to_owned()),
-            ))
+            Err(Error::DuckDBFailure {
+                error: FFIError::new(DuckDBError),
+                message: Some("Failed to insert values".to_owned()),
+                error_type: None,
+            })
         }
     }
 
@@ -326,13 +549,28 @@ impl RawConnection {
         binds: &mut [&mut dyn AppendAble],
     ) -> Result<DuckResult> {
         let mut stmt = CachedStatement::prepare(self, sql)?;
-        for (i, bind) in binds.iter_mut().enumerate() {
-            stmt.bind((i + 1) as u64, *bind)?;
+        let mut idx = 1;
+        for bind in binds.iter_mut() {
+            stmt.bind(idx, *bind)?;
+            idx += bind.appender_column_count().unwrap_or(1) as u64;
         }
         stmt.execute()
     }
 }
 
+/// Owns a `duckdb_extracted_statements` handle and destroys it on drop.
+struct ExtractedStatements(duckdb_extracted_statements);
+
+impl Drop for ExtractedStatements {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: `self.0` was returned by `duckdb_extract_statements` and is destroyed
+            // exactly once here.
+            unsafe { duckdb_destroy_extracted(&mut self.0) };
+        }
+    }
+}
+
 impl Clone for RawConnection {
     /// Creates a new connection to the same database.
     ///
@@ -365,6 +603,7 @@ impl Drop for RawConnection {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::value::DuckValue;
 
     #[test]
     fn test_raw_connection_open() {
@@ -383,6 +622,18 @@ mod tests {
         assert!(result.is_ok(), "{}", result.err().unwrap());
     }
 
+    #[test]
+    fn test_raw_connection_query_keeps_returning_rows() {
+        let path = CString::new(":memory:").unwrap();
+        let mut conn = RawConnection::open_with_flags(&path, Config::default()).unwrap();
+        conn.query("CREATE TABLE t (id INTEGER DEFAULT 7)").unwrap();
+
+        let mut result = conn.query("INSERT INTO t DEFAULT VALUES RETURNING id").unwrap();
+        let row = result.next().unwrap().unwrap();
+        assert_eq!(row.get("id"), Some(&DuckValue::Int(7)));
+        assert!(result.next().is_none());
+    }
+
     #[test]
     fn test_raw_connection_prepare() {
         let path = CString::new(":memory:").unwrap();
@@ -1,12 +1,12 @@
-use std::{
-    ops::{Deref, DerefMut},
-    ptr,
-};
+use std::ops::{Deref, DerefMut};
 
-use crate::ffi::{duckdb_data_chunk, duckdb_destroy_data_chunk};
+use crate::ffi::{duckdb_data_chunk, duckdb_destroy_data_chunk, DUCKDB_TYPE};
 
-use super::result::DuckResult;
-use crate::{error::Result, ffi};
+use super::{result::DuckResult, row::DuckRow};
+use crate::{
+    error::{Error, Result},
+    ffi,
+};
 
 pub struct DataChunk(
     pub(crate) duckdb_data_chunk,
@@ -17,10 +17,11 @@ impl DataChunk {
     #[inline]
     pub fn new(data_chunk: ffi::duckdb_data_chunk) -> Result<DataChunk> {
         if data_chunk.is_null() {
-            return Err(crate::error::Error::DuckDBFailure(
-                ffi::Error::new(ffi::DuckDBError),
-                Some("data chunk is null".to_owned()),
-            ));
+            return Err(crate::error::Error::DuckDBFailure {
+                error: ffi::Error::new(ffi::DuckDBError),
+                message: Some("data chunk is null".to_owned()),
+                error_type: None,
+            });
         }
         Ok(DataChunk(data_chunk, 0))
     }
@@ -48,25 +49,65 @@ impl DataChunk {
         unsafe { ffi::duckdb_data_chunk_get_size(self.0) }
     }
 
+    /// Moves the cursor to the next row and returns its index, or `None` once every row
+    /// has been visited. The chunk itself stays alive until it is dropped.
     #[inline]
     pub fn next_row(&mut self) -> Option<u64> {
-        // SAFETY: `self.0` is a valid duckdb_data_chunk (enforced by the caller).
-        if self.row_count() < 1 {
-            return None;
-        }
-        // SAFETY: same as above.
         if self.1 >= self.row_count() {
-            // Reset the row index and fetch the next chunk
-            self.1 = 0;
-            // SAFETY: `self.0` is a valid, non-null duckdb_data_chunk; after destroy
-            // we null it so this path is never re-entered.
-            unsafe { duckdb_destroy_data_chunk(&mut (self.0)) };
-            self.0 = ptr::null_mut();
             return None;
         }
         self.1 += 1;
         Some(self.1 - 1)
     }
+
+    /// Returns a view of row `index`, which should be less than
+    /// [`row_count`](Self::row_count); filling a view past the last row fails.
+    #[inline]
+    pub fn row_at(
+        &self,
+        index: u64,
+    ) -> RowView<'_> {
+        RowView { chunk: self, index }
+    }
+
+    /// Iterates over the chunk's rows without touching the cursor, so it can be called
+    /// any number of times.
+    pub fn rows(&self) -> impl Iterator<Item = RowView<'_>> {
+        (0..self.row_count()).map(|index| self.row_at(index))
+    }
+}
+
+/// One row of a [`DataChunk`], borrowed from it.
+#[derive(Clone, Copy)]
+pub struct RowView<'a> {
+    chunk: &'a DataChunk,
+    index: u64,
+}
+
+impl RowView<'_> {
+    /// Returns the row's index within its chunk.
+    #[inline]
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Replaces `row`'s values with this row's, decoding each column as `col_types`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRowIndex`] if the row is past the end of the chunk, or an
+    /// error if a column vector is null or a value fails to convert.
+    #[inline]
+    pub fn fill(
+        &self,
+        row: &mut DuckRow,
+        col_types: &[DUCKDB_TYPE],
+    ) -> Result<()> {
+        if self.index >= self.chunk.row_count() {
+            return Err(Error::InvalidRowIndex(self.index));
+        }
+        row.fill_at(self.chunk, self.index, col_types)
+    }
 }
 
 impl Deref for DataChunk {
@@ -94,3 +135,66 @@ impl Drop for DataChunk {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::Config, helpers::path::path_to_cstring, raw::connection::RawConnection,
+        types::value::DuckValue,
+    };
+
+    fn get_test_connection() -> RawConnection {
+        let c_path = path_to_cstring(":memory:".as_ref()).unwrap();
+        let config = Config::default().with("duckdb_api", "rust").unwrap();
+        RawConnection::open_with_flags(&c_path, config).unwrap()
+    }
+
+    #[test]
+    fn test_rows_can_be_iterated_twice() {
+        let con = get_test_connection();
+        let mut stmt = con.prepare("SELECT i FROM range(3) t(i)").unwrap();
+        let result = stmt.execute().unwrap();
+        let chunk = DataChunk::from_result(&result).expect("expected a chunk").unwrap();
+        let col_types = [result.column_type(0).unwrap()];
+
+        let read = |chunk: &DataChunk| {
+            chunk
+                .rows()
+                .map(|view| {
+                    let mut row = DuckRow::new(Vec::new(), vec!["i".into()].into_boxed_slice());
+                    view.fill(&mut row, &col_types).unwrap();
+                    (view.index(), row.get_idx(0).cloned().unwrap())
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected: Vec<_> = (0..3).map(|i| (i, DuckValue::BigInt(i as i64))).collect();
+        assert_eq!(read(&chunk), expected);
+        assert_eq!(read(&chunk), expected);
+        assert_eq!(chunk.current_row(), 0);
+    }
+
+    #[test]
+    fn test_next_row_keeps_chunk_alive() {
+        let con = get_test_connection();
+        let mut stmt = con.prepare("SELECT 1").unwrap();
+        let result = stmt.execute().unwrap();
+        let mut chunk = DataChunk::from_result(&result).expect("expected a chunk").unwrap();
+        assert_eq!(chunk.next_row(), Some(0));
+        assert_eq!(chunk.next_row(), None);
+        assert_eq!(chunk.next_row(), None);
+        assert!(!chunk.is_null());
+        assert_eq!(chunk.rows().count(), 1);
+    }
+
+    #[test]
+    fn test_row_past_the_end_is_not_filled() {
+        let con = get_test_connection();
+        let mut stmt = con.prepare("SELECT 1 AS i").unwrap();
+        let result = stmt.execute().unwrap();
+        let chunk = DataChunk::from_result(&result).expect("expected a chunk").unwrap();
+        let mut row = DuckRow::new(Vec::new(), vec!["i".into()].into_boxed_slice());
+        let err = chunk.row_at(1).fill(&mut row, &[result.column_type(0).unwrap()]).unwrap_err();
+        assert_eq!(err, Error::InvalidRowIndex(1));
+    }
+}
@@ -1,7 +1,9 @@
 use std::{
     cell::OnceCell,
     ffi::CStr,
+    marker::PhantomData,
     ops::{Deref, DerefMut},
+    ptr,
 };
 
 use crate::ffi::{duckdb_column_count, duckdb_column_name, duckdb_destroy_result, DUCKDB_TYPE};
@@ -9,19 +11,21 @@ use crate::ffi::{duckdb_column_count, duckdb_column_name, duckdb_destroy_result,
 use crate::{
     error::{DuckDBConversionError, Error, Result},
     ffi,
+    helpers::duck_result::result_error,
     raw::row::DuckRow,
+    types::{from_row::FromRow, value::DuckValue, DuckDialect, LogicalType, VectorPrimitive},
 };
 
-use super::data_chunk::DataChunk;
+use super::data_chunk::{DataChunk, RowView};
 
-// TODO: Implement rows cache by using Box<[DuckValue]> or Vec<DuckValue> to store rows
 // TODO: Implement exists method
 
 /// Represents the result of a DuckDB query, providing row-by-row iteration over
 /// the returned data.
 ///
 /// `DuckResult` owns the underlying `duckdb_result` and destroys it in [`Drop`].
-/// It implements [`Iterator`] yielding `Result<DuckRow>`.
+/// It implements [`Iterator`] yielding `Result<DuckRow>`, and rows can also be read
+/// out of order with [`row`](DuckResult::row).
 ///
 /// # Safety
 ///
@@ -35,14 +39,36 @@ pub struct DuckResult {
     column_types: Box<[DUCKDB_TYPE]>,
     /// Number of columns in the result.
     pub col_count: u64,
+    /// Maximum number of rows the iterator may yield, if limited.
+    row_limit: Option<u64>,
+    /// Number of rows the cursor has advanced over so far.
+    rows_read: u64,
+    /// Set once `duckdb_fetch_chunk` has returned no chunk (end of data or error).
+    exhausted: bool,
+    /// Whether fetched chunks are kept in `cache` for random access. Switched on by the
+    /// first [`row`](DuckResult::row) or [`row_count`](DuckResult::row_count) call; from
+    /// then on the iterator reads through the cache as well.
+    caching: bool,
+    /// Chunks kept for random access, each with the result-wide index of its first row.
+    cache: Vec<(u64, DataChunk)>,
+    /// Result-wide index one past the last cached row.
+    cached_end: u64,
+    /// Position (cache slot, row within chunk) of the iterator's current row while
+    /// `caching` is set.
+    cursor: Option<(usize, u64)>,
 }
 
 impl DuckResult {
     /// Creates a new `DuckResult` from an owned `duckdb_result`.
     ///
-    /// Immediately resolves column names and types. Panics if the result is in
-    /// an invalid state (should not happen for a result from a successful query).
-    pub fn new(mut result: ffi::duckdb_result) -> DuckResult {
+    /// Immediately resolves column names and types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column name is not valid UTF-8. DuckDB's own readers reject
+    /// or transcode such names, but a table function from an extension can still pass
+    /// raw bytes through. The result is destroyed either way.
+    pub fn new(mut result: ffi::duckdb_result) -> Result<DuckResult> {
         let mut res = DuckResult {
             // SAFETY: `result` is a valid, fully initialized `duckdb_result` that was
             // returned by `duckdb_query` or `duckdb_execute_prepared` and is now moved
@@ -52,10 +78,17 @@ impl DuckResult {
             chunk: None,
             column_names: OnceCell::new(),
             column_types: Box::new([]),
+            row_limit: None,
+            rows_read: 0,
+            exhausted: false,
+            caching: false,
+            cache: Vec::new(),
+            cached_end: 0,
+            cursor: None,
         };
-        res.resolve_columns_name().expect("failed to resolve column names");
-        res.resolve_columns_types().expect("failed to resolve column types");
-        res
+        res.resolve_columns_name()?;
+        res.resolve_columns_types()?;
+        Ok(res)
     }
 
     #[inline]
@@ -99,15 +132,12 @@ impl DuckResult {
                 if raw.is_null() {
                     return Err(Error::InvalidColumnIndex(i as usize));
                 }
-                // SAFETY: DuckDB guarantees null-terminated valid UTF-8 for column names.
+                // SAFETY: `raw` is a non-null, null-terminated C string. Its bytes are
+                // checked for UTF-8 below: names can come from external data.
                 unsafe { CStr::from_ptr(raw) }
                     .to_str()
                     .map(|s| s.to_string().into_boxed_str())
-                    .map_err(|e| {
-                        Error::ConversionError(DuckDBConversionError::ConversionError(
-                            e.to_string(),
-                        ))
-                    })
+                    .map_err(|e| Error::ConversionError(e.into()))
             })
             .collect::<Result<Vec<Box<str>>>>()?;
 
@@ -118,19 +148,25 @@ impl DuckResult {
 
     /// Advances the internal cursor to the next row.
     ///
-    /// Returns `Some(())` if a row is available, or `None` if all rows have been
-    /// consumed.
-    fn advance(&mut self) -> Option<()> {
+    /// Returns `Some(Ok(()))` if a row is available, or `None` if all rows have been
+    /// consumed. When a row limit is set, the first row past the limit yields
+    /// `Some(Err(Error::ResultTooLarge))` and the cursor stops there.
+    fn advance(&mut self) -> Option<Result<()>> {
+        if let Some(limit) = self.row_limit {
+            if self.rows_read > limit {
+                return None;
+            }
+        }
+        if self.caching {
+            return self.advance_cached();
+        }
         loop {
             if self.chunk.is_none() {
-                // SAFETY: `self.res` is a valid duckdb_result. `DataChunk::from_result`
-                // calls `duckdb_fetch_chunk` which returns null when exhausted.
-                let next_chunk = DataChunk::from_result(self);
-                match next_chunk {
+                match self.fetch_chunk() {
                     None => return None,
-                    Some(Err(_)) => {
+                    Some(Err(e)) => {
                         self.chunk = None;
-                        return None;
+                        return Some(Err(e));
                     },
                     Some(Ok(chunk)) => {
                         self.chunk = Some(chunk);
@@ -143,19 +179,190 @@ impl DuckResult {
                 self.chunk = None;
                 return None;
             }
-            // SAFETY: `the_chunk` wraps a valid duckdb_data_chunk whose row count > 0.
             if the_chunk.next_row().is_some() {
-                let row_chunk = **the_chunk;
-                if row_chunk.is_null() {
-                    panic!("Data chunk is null");
+                self.rows_read += 1;
+                if let Some(limit) = self.row_limit {
+                    if self.rows_read > limit {
+                        self.chunk = None;
+                        return Some(Err(Error::ResultTooLarge(limit)));
+                    }
                 }
-                return Some(());
+                return Some(Ok(()));
             } else {
+                // Dropping the used-up chunk releases it; loop to fetch the next one.
                 self.chunk = None;
-                // Loop to fetch the next chunk.
             }
         }
     }
+
+    /// [`advance`](DuckResult::advance) for a result whose chunks are cached: moves the
+    /// cursor to row `rows_read`, fetching chunks into the cache as needed.
+    fn advance_cached(&mut self) -> Option<Result<()>> {
+        match self.cache_through(self.rows_read) {
+            Err(e) => {
+                if let Error::ResultTooLarge(limit) = e {
+                    // Move past the limit so iteration ends after this error.
+                    self.rows_read = limit + 1;
+                    self.cursor = None;
+                }
+                Some(Err(e))
+            },
+            Ok(None) => {
+                self.cursor = None;
+                None
+            },
+            Ok(Some(pos)) => {
+                self.cursor = Some(pos);
+                self.rows_read += 1;
+                Some(Ok(()))
+            },
+        }
+    }
+
+    /// Switches the result to keeping its chunks, so rows can be revisited.
+    ///
+    /// A chunk the iterator is part-way through is moved into the cache; rows from
+    /// chunks the iterator already finished were released and stay unreachable.
+    fn start_caching(&mut self) {
+        if self.caching {
+            return;
+        }
+        self.caching = true;
+        self.cached_end = self.rows_read;
+        if let Some(chunk) = self.chunk.take() {
+            if !chunk.is_null() && chunk.current_row() > 0 {
+                let start = self.rows_read - chunk.current_row();
+                self.cursor = Some((0, chunk.current_row() - 1));
+                self.cached_end = start + chunk.row_count();
+                self.cache.push((start, chunk));
+            }
+        }
+    }
+
+    /// Returns the cache slot and in-chunk offset of the row at result-wide index `idx`,
+    /// fetching chunks into the cache until it is covered, or `None` if the result has
+    /// fewer rows. `idx` must not precede the first cached row.
+    ///
+    /// With a row limit set, no chunk is fetched once more than `row_limit` rows are
+    /// cached, and a row at or past the limit is never returned: both fail with
+    /// [`Error::ResultTooLarge`]. The cache therefore holds at most the limit plus one
+    /// chunk.
+    fn cache_through(
+        &mut self,
+        idx: u64,
+    ) -> Result<Option<(usize, u64)>> {
+        let limit = self.row_limit.unwrap_or(u64::MAX);
+        while idx >= self.cached_end {
+            if self.cached_end > limit {
+                return Err(Error::ResultTooLarge(limit));
+            }
+            match self.fetch_chunk() {
+                None => return Ok(None),
+                Some(Err(e)) => return Err(e),
+                Some(Ok(chunk)) => {
+                    if chunk.row_count() == 0 {
+                        self.exhausted = true;
+                        return Ok(None);
+                    }
+                    let start = self.cached_end;
+                    self.cached_end += chunk.row_count();
+                    self.cache.push((start, chunk));
+                },
+            }
+        }
+        if idx >= limit {
+            return Err(Error::ResultTooLarge(limit));
+        }
+        let slot = self.cache.partition_point(|(start, _)| *start <= idx) - 1;
+        Ok(Some((slot, idx - self.cache[slot].0)))
+    }
+
+    /// Returns a view of the iterator's current row.
+    fn current_position(&self) -> Option<RowView<'_>> {
+        if self.caching {
+            let (slot, offset) = self.cursor?;
+            return Some(self.cache[slot].1.row_at(offset));
+        }
+        let chunk = self.chunk.as_ref().filter(|chunk| !chunk.is_null())?;
+        chunk.current_row().checked_sub(1).map(|offset| chunk.row_at(offset))
+    }
+
+    /// Fetches the next data chunk.
+    ///
+    /// `duckdb_fetch_chunk` returns null both when the result is exhausted and when
+    /// producing the next chunk failed; the two are told apart by checking
+    /// `duckdb_result_error`, so a failure surfaces as `Some(Err(..))` instead of
+    /// looking like the end of the data. Either way the result is marked exhausted and
+    /// later calls return `None`.
+    fn fetch_chunk(&mut self) -> Option<Result<DataChunk>> {
+        if self.exhausted {
+            return None;
+        }
+        let next = DataChunk::from_result(self);
+        if next.is_none() {
+            self.exhausted = true;
+            // SAFETY: `self.res` is a valid duckdb_result. The error string (if any) is
+            // owned by the result; `result_error` copies it.
+            if let Some((message, kind)) = unsafe { result_error(&mut self.res) } {
+                return Some(Err(Error::DuckDBFailure {
+                    error: ffi::Error::new(ffi::DuckDBError),
+                    message: Some(message),
+                    error_type: kind,
+                }));
+            }
+        }
+        next
+    }
+
+    /// Calls `f` with every remaining data chunk and the row offset to start reading
+    /// from, consuming the rest of the result.
+    ///
+    /// A chunk that the row iterator has already started is passed first with the
+    /// offset of its first unread row; chunks fetched afterwards start at `0`.
+    ///
+    /// With a row limit set, a chunk that would take the rows read past the limit is not
+    /// passed to `f`; [`Error::ResultTooLarge`] is returned instead.
+    fn for_each_remaining_chunk(
+        &mut self,
+        mut f: impl FnMut(&DataChunk, u64) -> Result<()>,
+    ) -> Result<()> {
+        if self.caching {
+            self.cache_through(u64::MAX)?;
+            for (start, chunk) in &self.cache {
+                if start + chunk.row_count() > self.rows_read {
+                    f(chunk, self.rows_read.saturating_sub(*start))?;
+                }
+            }
+            self.rows_read = self.rows_read.max(self.cached_end);
+            self.cursor = None;
+            return Ok(());
+        }
+        if let Some(chunk) = self.chunk.take() {
+            if !chunk.is_null() && chunk.current_row() < chunk.row_count() {
+                self.rows_read += chunk.row_count() - chunk.current_row();
+                self.check_row_limit()?;
+                f(&chunk, chunk.current_row())?;
+            }
+        }
+        while let Some(chunk) = self.fetch_chunk() {
+            let chunk = chunk?;
+            if chunk.row_count() == 0 {
+                break;
+            }
+            self.rows_read += chunk.row_count();
+            self.check_row_limit()?;
+            f(&chunk, 0)?;
+        }
+        Ok(())
+    }
+
+    /// Fails with [`Error::ResultTooLarge`] once more rows than the row limit were read.
+    fn check_row_limit(&self) -> Result<()> {
+        match self.row_limit {
+            Some(limit) if self.rows_read > limit => Err(Error::ResultTooLarge(limit)),
+            _ => Ok(()),
+        }
+    }
 }
 
 // Exposed API
@@ -167,8 +374,386 @@ impl DuckResult {
     /// Returns an error if the chunk is not available or value conversion fails.
     pub fn current(&mut self) -> Result<DuckRow> {
         let col_names = self.column_names().to_vec().into_boxed_slice();
-        let chunk = self.chunk.as_mut().unwrap();
-        DuckRow::from_chunk(chunk, col_names, &self.column_types)
+        let Some(view) = self.current_position() else {
+            return Err(Error::DuckDBFailure {
+                error: ffi::Error::new(ffi::DuckDBError),
+                message: Some("no current row".to_owned()),
+                error_type: None,
+            });
+        };
+        let mut row = DuckRow::new(Vec::with_capacity(col_names.len()), col_names);
+        view.fill(&mut row, &self.column_types)?;
+        Ok(row)
+    }
+
+    /// Returns the row at zero-based index `idx` within the whole result.
+    ///
+    /// Rows can be read in any order and as often as needed: the first call makes the
+    /// result keep every chunk it fetches from then on, so earlier rows stay available.
+    /// The iterator keeps its own position and is not moved by this call.
+    ///
+    /// Rows that the iterator had already moved past before the first `row` or
+    /// [`row_count`](DuckResult::row_count) call were released and cannot be read
+    /// back.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRowIndex`] if `idx` is past the last row or names a row
+    /// that was already released, [`Error::ResultTooLarge`] if a row limit is set and
+    /// `idx` is at or past it, or an error if fetching a chunk or converting a value
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::{connection::Connection, types::value::DuckValue};
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let mut result = conn.query("SELECT * FROM range(3) t(v) ORDER BY v")?;
+    /// assert_eq!(result.row(2)?.get("v"), Some(&DuckValue::BigInt(2)));
+    /// assert_eq!(result.row(0)?.get("v"), Some(&DuckValue::BigInt(0)));
+    /// assert_eq!(result.row_count()?, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn row(
+        &mut self,
+        idx: u64,
+    ) -> Result<DuckRow> {
+        self.start_caching();
+        let first_cached = self.cache.first().map_or(self.cached_end, |(start, _)| *start);
+        if idx < first_cached {
+            return Err(Error::InvalidRowIndex(idx));
+        }
+        let Some((slot, offset)) = self.cache_through(idx)? else {
+            return Err(Error::InvalidRowIndex(idx));
+        };
+        let col_names = self.column_names().to_vec().into_boxed_slice();
+        let mut row = DuckRow::new(Vec::with_capacity(col_names.len()), col_names);
+        self.cache[slot].1.row_at(offset).fill(&mut row, &self.column_types)?;
+        Ok(row)
+    }
+
+    /// Returns the total number of rows in the result, summed over all of its chunks.
+    ///
+    /// This fetches and caches every remaining chunk, as [`row`](DuckResult::row)
+    /// does, and counts rows the iterator has already consumed too. It does not move
+    /// the iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ResultTooLarge`] if a row limit is set and the result has more
+    /// rows, without caching past the first chunk that crosses it, or an error if
+    /// fetching a chunk fails.
+    pub fn row_count(&mut self) -> Result<u64> {
+        self.start_caching();
+        self.cache_through(u64::MAX)?;
+        Ok(self.cached_end)
+    }
+
+    /// Consumes the result, decoding each remaining row straight into `T`.
+    ///
+    /// The returned iterator refills a single [`DuckRow`] buffer in place for every row,
+    /// so no row or column-name allocation is made per row.
+    pub fn into_typed<T: FromRow>(self) -> TypedRows<T> {
+        let names = self.column_names().to_vec().into_boxed_slice();
+        TypedRows {
+            row: DuckRow::new(Vec::with_capacity(names.len()), names),
+            result: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decodes the remaining rows as `T` without consuming the result.
+    ///
+    /// Like [`into_typed`](DuckResult::into_typed), but borrowed: once the returned
+    /// iterator is dropped, the result can still be read, e.g. with
+    /// [`row`](DuckResult::row) or a second `map_rows` for rows not yet decoded.
+    pub fn map_rows<T: FromRow>(&mut self) -> MappedRows<'_, T> {
+        let names = self.column_names().to_vec().into_boxed_slice();
+        MappedRows {
+            row: DuckRow::new(Vec::with_capacity(names.len()), names),
+            result: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Iterates the rows of the result without consuming it.
+    ///
+    /// Every call starts again from the first row, so the same result can be walked
+    /// any number of times. Like [`row`](DuckResult::row), this makes the result keep
+    /// its chunks; rows the owning iterator had already moved past before caching
+    /// started were released and are skipped. Neither the owning iterator's position
+    /// nor [`current`](DuckResult::current) is affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::{connection::Connection, error::Result};
+    /// # fn main() -> Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let mut result = conn.query("SELECT * FROM range(3) t(v)")?;
+    /// let first = result.rows().map(|row| Ok(row?.values().to_vec())).collect::<Result<Vec<_>>>()?;
+    /// let second = result.rows().map(|row| Ok(row?.values().to_vec())).collect::<Result<Vec<_>>>()?;
+    /// assert_eq!(first, second);
+    /// assert_eq!(first.len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rows(&mut self) -> Rows<'_> {
+        self.start_caching();
+        let next = self.cache.first().map_or(self.cached_end, |(start, _)| *start);
+        Rows { result: self, next: Some(next) }
+    }
+
+    /// Advances to the next row, refills `row` with it and decodes it as `T`.
+    fn next_typed<T: FromRow>(
+        &mut self,
+        row: &mut DuckRow,
+    ) -> Option<Result<T>> {
+        if let Err(e) = self.advance()? {
+            return Some(Err(e));
+        }
+        let view = self.current_position()?;
+        Some(view.fill(row, &self.column_types).and_then(|()| T::from_row(row)))
+    }
+
+    /// Caps the number of rows this result will yield.
+    ///
+    /// Iteration yields at most `max_rows` rows; if the query produced more, the next
+    /// item is an [`Error::ResultTooLarge`] and iteration ends. Rows are still fetched
+    /// chunk by chunk, so the guard trips before the excess rows are converted.
+    #[must_use = "with_row_limit returns the limited result"]
+    pub fn with_row_limit(
+        mut self,
+        max_rows: u64,
+    ) -> DuckResult {
+        self.row_limit = Some(max_rows);
+        self
+    }
+
+    /// Consumes the result, yielding each row together with its zero-based ordinal.
+    ///
+    /// The index counts rows across chunk boundaries, so it is the row number within
+    /// the whole result rather than within the current chunk.
+    pub fn enumerate_rows(self) -> impl Iterator<Item = (u64, Result<DuckRow>)> {
+        self.enumerate().map(|(idx, row)| (idx as u64, row))
+    }
+
+    /// Clears `out` and fills it with the remaining rows of this result.
+    ///
+    /// `out` keeps its capacity, so passing the same `Vec` to repeated executions of a
+    /// query avoids growing a fresh buffer every time.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced while reading a row. Rows read before the error
+    /// are left in `out`.
+    pub fn drain_into(
+        &mut self,
+        out: &mut Vec<DuckRow>,
+    ) -> Result<()> {
+        out.clear();
+        for row in self {
+            out.push(row?);
+        }
+        Ok(())
+    }
+
+    /// Renders the remaining rows as text, returning the column names and one `Vec` of
+    /// cells per row.
+    ///
+    /// Each cell is formatted with [`DuckValue`]'s `Display` impl; a `NULL` cell becomes
+    /// the string `NULL`. Use
+    /// [`to_string_matrix_with_null`](DuckResult::to_string_matrix_with_null) to choose a
+    /// different placeholder, e.g. an empty string for CSV export. Meant for small
+    /// results such as table previews: every row is held in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced while reading a row.
+    #[allow(clippy::type_complexity)]
+    pub fn to_string_matrix(&mut self) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+        self.to_string_matrix_with_null("NULL")
+    }
+
+    /// Like [`to_string_matrix`](DuckResult::to_string_matrix), but renders top-level
+    /// `NULL` cells as `null_text`. `NULL`s nested inside lists, structs or maps are still
+    /// written as `NULL`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced while reading a row.
+    #[allow(clippy::type_complexity)]
+    pub fn to_string_matrix_with_null(
+        &mut self,
+        null_text: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+        let headers: Vec<String> =
+            self.column_names().iter().map(|name| name.to_string()).collect();
+        let mut rows = Vec::new();
+        for row in self {
+            let row = row?;
+            let cells = (0..headers.len())
+                .map(|idx| match row.get_idx(idx) {
+                    None | Some(DuckValue::Null) => null_text.to_owned(),
+                    Some(value) => value.to_string(),
+                })
+                .collect();
+            rows.push(cells);
+        }
+        Ok((headers, rows))
+    }
+
+    /// Reads the remaining values of a `DATE` column into a `Vec`, with `None` for NULLs.
+    ///
+    /// Values are decoded straight from the chunk vectors without materialising a
+    /// [`DuckRow`] per row. This consumes the rest of the result: rows already yielded
+    /// by the iterator are not included, and iterating afterwards yields nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnIndex`] if `col` is out of range, a
+    /// [`DuckDBConversionError::TypeMismatch`] if the column is not `DATE`,
+    /// [`Error::ResultTooLarge`] if a row limit is set and the result has more rows, or
+    /// an error if a value cannot be represented as a [`NaiveDate`](chrono::NaiveDate).
+    #[cfg(feature = "chrono")]
+    pub fn column_dates(
+        &mut self,
+        col: usize,
+    ) -> Result<Vec<Option<chrono::NaiveDate>>> {
+        use crate::types::DuckDialect;
+
+        let found = self.column_type(col)?;
+        if found != ffi::DUCKDB_TYPE_DUCKDB_TYPE_DATE {
+            return Err(Error::ConversionError(DuckDBConversionError::TypeMismatch {
+                expected: ffi::DUCKDB_TYPE_DUCKDB_TYPE_DATE,
+                found,
+            }));
+        }
+        let mut out = Vec::new();
+        self.for_each_remaining_chunk(|chunk, start| {
+            // SAFETY: `chunk` wraps a valid duckdb_data_chunk and `col` < col_count.
+            let vector = unsafe { ffi::duckdb_data_chunk_get_vector(**chunk, col as u64) };
+            // SAFETY: `vector` is a valid DATE vector, so its data is a packed
+            // `duckdb_date` array of `row_count` elements.
+            let data = unsafe { ffi::duckdb_vector_get_data(vector) } as *const ffi::duckdb_date;
+            // SAFETY: the validity mask (possibly null) belongs to `vector`.
+            let validity = unsafe { ffi::duckdb_vector_get_validity(vector) };
+            for row in start..chunk.row_count() {
+                // SAFETY: `row` < row_count, in bounds for both the validity mask and `data`.
+                if !unsafe { ffi::duckdb_validity_row_is_valid(validity, row) } {
+                    out.push(None);
+                    continue;
+                }
+                // SAFETY: same bounds as above; the slot holds an initialised `duckdb_date`.
+                let raw = unsafe { *data.add(row as usize) };
+                out.push(Some(chrono::NaiveDate::from_duck(raw).map_err(Error::ConversionError)?));
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    /// Reads the remaining values of a primitive column into a `Vec`, with `None` for
+    /// NULLs.
+    ///
+    /// Each chunk's vector is read in one pass straight from its data buffer and validity
+    /// mask, without materialising a [`DuckRow`] or a per-cell value. Like
+    /// `column_dates`, this consumes the rest of the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnIndex`] if `col` is out of range, a
+    /// [`DuckDBConversionError::TypeMismatch`] if the column type is not `T::DUCK_TYPE`,
+    /// or [`Error::ResultTooLarge`] if a row limit is set and the result has more rows.
+    pub fn column_chunk<T: VectorPrimitive>(
+        &mut self,
+        col: usize,
+    ) -> Result<Vec<Option<T>>> {
+        let found = self.column_type(col)?;
+        if found != T::DUCK_TYPE {
+            return Err(Error::ConversionError(DuckDBConversionError::TypeMismatch {
+                expected: T::DUCK_TYPE,
+                found,
+            }));
+        }
+        let mut out = Vec::new();
+        self.for_each_remaining_chunk(|chunk, start| {
+            // SAFETY: `chunk` wraps a valid duckdb_data_chunk and `col` < col_count.
+            let vector = unsafe { ffi::duckdb_data_chunk_get_vector(**chunk, col as u64) };
+            // SAFETY: the column type is `T::DUCK_TYPE`, so by the `VectorPrimitive`
+            // contract the vector data is a packed array of `row_count` `T` slots. NULL
+            // slots may hold garbage, so they are never read.
+            let data = unsafe { ffi::duckdb_vector_get_data(vector) } as *const T;
+            // SAFETY: the validity mask (possibly null) belongs to `vector`.
+            let validity = unsafe { ffi::duckdb_vector_get_validity(vector) };
+            out.reserve((chunk.row_count() - start) as usize);
+            for row in start..chunk.row_count() {
+                // SAFETY: `row` < row_count, in bounds for the validity mask.
+                if !unsafe { ffi::duckdb_validity_row_is_valid(validity, row) } {
+                    out.push(None);
+                    continue;
+                }
+                // SAFETY: same bounds as above; the slot of a valid row holds a `T`.
+                out.push(Some(unsafe { *data.add(row as usize) }));
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    /// Reads the remaining values of column `col`, of any type, into a `Vec<T>`.
+    ///
+    /// The high-level counterpart of [`column_chunk`](DuckResult::column_chunk): each
+    /// cell is decoded as a [`DuckValue`] and converted with [`DuckDialect::from_duck`],
+    /// so `T` need not match the column type exactly. A `NULL` cell goes through
+    /// [`DuckDialect::from_null`], which fails unless `T` is an `Option`. Like
+    /// `column_chunk`, this consumes the rest of the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnIndex`] if `col` is out of range,
+    /// [`DuckDBConversionError::NullValue`] for a `NULL` cell when `T` cannot hold it,
+    /// [`Error::ResultTooLarge`] if a row limit is set and the result has more rows, or
+    /// an error if a value cannot be converted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::connection::Connection;
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let mut result = conn.query("SELECT * FROM (VALUES (1), (NULL), (3)) t(v)")?;
+    /// assert_eq!(result.collect_column::<Option<i32>>(0)?, [Some(1), None, Some(3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collect_column<T: DuckDialect>(
+        &mut self,
+        col: usize,
+    ) -> Result<Vec<T>> {
+        let col_type = self.column_type(col)?;
+        let mut out = Vec::new();
+        self.for_each_remaining_chunk(|chunk, start| {
+            // SAFETY: `chunk` wraps a valid duckdb_data_chunk and `col` < col_count.
+            let vector = unsafe { ffi::duckdb_data_chunk_get_vector(**chunk, col as u64) };
+            out.reserve((chunk.row_count() - start) as usize);
+            for row in start..chunk.row_count() {
+                let value = DuckValue::from_duckdb_vec(vector, col_type, row)?;
+                if value.is_null() {
+                    out.push(T::from_null()?);
+                    continue;
+                }
+                let mut raw = value.to_duck()?;
+                let converted = T::from_duck(raw);
+                // SAFETY: `raw` was created by `to_duck` above and is destroyed once.
+                unsafe { ffi::duckdb_destroy_value(&mut raw) };
+                out.push(converted?);
+            }
+            Ok(())
+        })?;
+        Ok(out)
     }
 
     /// Returns the number of rows changed by the last INSERT/UPDATE/DELETE.
@@ -181,6 +766,13 @@ impl DuckResult {
         unsafe { ffi::duckdb_rows_changed(&mut self.res) }
     }
 
+    /// Returns `true` if the result is streamed chunk by chunk rather than
+    /// materialized, i.e. it came from `Statement::stream`.
+    pub fn is_streaming(&self) -> bool {
+        // SAFETY: `self.res` is a valid duckdb_result; the struct is passed by value.
+        unsafe { ffi::duckdb_result_is_streaming(self.res) }
+    }
+
     /// Returns the number of columns in this result.
     #[allow(unused)]
     #[inline]
@@ -205,6 +797,70 @@ impl DuckResult {
         Ok(self.column_types[col_index])
     }
 
+    /// Returns the full logical type of the column at `col_index`.
+    ///
+    /// Unlike [`column_type`](DuckResult::column_type) this carries the type's parameters,
+    /// such as the scale of a `DECIMAL` or the element type of a `LIST`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnIndex`] if `col_index` is out of range.
+    pub fn column_logical_type(
+        &self,
+        col_index: usize,
+    ) -> Result<LogicalType> {
+        if col_index >= self.col_count as usize {
+            return Err(Error::InvalidColumnIndex(col_index));
+        }
+        // SAFETY: `self.res` is valid and `col_index` is in range. DuckDB only reads the
+        // result here, so the `*mut` it asks for is never written through. The returned
+        // logical type is owned by the caller.
+        let lt = unsafe {
+            ffi::duckdb_column_logical_type(ptr::addr_of!(self.res).cast_mut(), col_index as u64)
+        };
+        // SAFETY: `lt` is null or a fresh logical type owned by us.
+        unsafe { LogicalType::from_raw(lt) }.ok_or(Error::InvalidColumnIndex(col_index))
+    }
+
+    /// Describes every column of the result: its name, type and full logical type.
+    ///
+    /// One call gathers what [`column_name`](DuckResult::column_name),
+    /// [`column_type`](DuckResult::column_type) and
+    /// [`column_logical_type`](DuckResult::column_logical_type) return per column. The
+    /// names and types come from the values resolved when the result was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB does not return a logical type for a column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use better_duck_core::{connection::Connection, ffi::DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR};
+    /// # fn main() -> better_duck_core::error::Result<()> {
+    /// let mut conn = Connection::open_in_memory()?;
+    /// let result = conn.query("SELECT 1 AS id, 'duck' AS name")?;
+    /// let schema = result.schema()?;
+    /// assert_eq!(schema[1].name, "name");
+    /// assert_eq!(schema[1].type_id, DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn schema(&self) -> Result<Vec<ColumnInfo>> {
+        self.column_names()
+            .iter()
+            .zip(self.column_types.iter())
+            .enumerate()
+            .map(|(idx, (name, &type_id))| {
+                Ok(ColumnInfo {
+                    name: name.to_string(),
+                    type_id,
+                    logical: self.column_logical_type(idx)?,
+                })
+            })
+            .collect()
+    }
+
     /// Returns the name of the column at `col_index`.
     ///
     /// # Errors
@@ -241,14 +897,93 @@ impl DuckResult {
     }
 }
 
+/// The name and type of one result column, as returned by [`DuckResult::schema`].
+#[derive(Debug)]
+pub struct ColumnInfo {
+    /// The column name.
+    pub name: String,
+    /// The kind of the column's type, e.g. `DUCKDB_TYPE_DUCKDB_TYPE_INTEGER`.
+    pub type_id: DUCKDB_TYPE,
+    /// The full logical type, with parameters such as a `DECIMAL`'s scale.
+    pub logical: LogicalType,
+}
+
 impl Iterator for DuckResult {
     type Item = Result<DuckRow>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.advance().is_some() {
-            Some(self.current())
-        } else {
-            None
+        match self.advance()? {
+            Ok(()) => Some(self.current()),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// An iterator over a query result that yields each row decoded as `T`.
+///
+/// Created by [`DuckResult::into_typed`]. Rows are decoded into one reusable
+/// [`DuckRow`] buffer, which is handed to [`FromRow::from_row`] and then overwritten by
+/// the next row.
+pub struct TypedRows<T> {
+    result: DuckResult,
+    row: DuckRow,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromRow> Iterator for TypedRows<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.result.next_typed(&mut self.row)
+    }
+}
+
+/// An iterator that decodes the remaining rows of a borrowed result as `T`.
+///
+/// Created by [`DuckResult::map_rows`]. Like [`TypedRows`], it reuses one [`DuckRow`]
+/// buffer for every row.
+pub struct MappedRows<'a, T> {
+    result: &'a mut DuckResult,
+    row: DuckRow,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FromRow> Iterator for MappedRows<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.result.next_typed(&mut self.row)
+    }
+}
+
+/// A borrowing iterator over the rows of a result, starting from its first row.
+///
+/// Created by [`DuckResult::rows`]. Iteration ends after the last row or the first
+/// error.
+pub struct Rows<'a> {
+    result: &'a mut DuckResult,
+    /// Index of the next row to yield, or `None` once iteration has ended.
+    next: Option<u64>,
+}
+
+impl Iterator for Rows<'_> {
+    type Item = Result<DuckRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.next?;
+        match self.result.row(idx) {
+            Ok(row) => {
+                self.next = Some(idx + 1);
+                Some(Ok(row))
+            },
+            Err(Error::InvalidRowIndex(_)) => {
+                self.next = None;
+                None
+            },
+            Err(e) => {
+                self.next = None;
+                Some(Err(e))
+            },
         }
     }
 }
@@ -274,3 +1009,61 @@ impl Drop for DuckResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::Config,
+        ffi::{duckdb_bind_info, duckdb_data_chunk, duckdb_function_info, duckdb_init_info},
+        helpers::path::path_to_cstring,
+        raw::connection::RawConnection,
+    };
+
+    fn get_test_connection() -> RawConnection {
+        let c_path = path_to_cstring(":memory:".as_ref()).unwrap();
+        let config = Config::default().with("duckdb_api", "rust").unwrap();
+        RawConnection::open_with_flags(&c_path, config).unwrap()
+    }
+
+    /// Declares one `INTEGER` column whose name is not valid UTF-8.
+    unsafe extern "C" fn bind_bad_name(info: duckdb_bind_info) {
+        // SAFETY: `info` is the bind info DuckDB passed in; the name and type are copied.
+        unsafe {
+            let mut lt = ffi::duckdb_create_logical_type(ffi::DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+            ffi::duckdb_bind_add_result_column(info, c"bad\xff".as_ptr(), lt);
+            ffi::duckdb_destroy_logical_type(&mut lt);
+        }
+    }
+
+    unsafe extern "C" fn init_nothing(_: duckdb_init_info) {}
+
+    /// Emits no rows.
+    unsafe extern "C" fn produce_nothing(
+        _: duckdb_function_info,
+        output: duckdb_data_chunk,
+    ) {
+        // SAFETY: `output` is the chunk DuckDB passed in.
+        unsafe { ffi::duckdb_data_chunk_set_size(output, 0) };
+    }
+
+    #[test]
+    fn test_non_utf8_column_name_is_an_error() {
+        let con = get_test_connection();
+        // SAFETY: the function is fully set up before it is registered on the open
+        // connection, which copies it; it is destroyed afterwards.
+        unsafe {
+            let mut function = ffi::duckdb_create_table_function();
+            ffi::duckdb_table_function_set_name(function, c"bad_name".as_ptr());
+            ffi::duckdb_table_function_set_bind(function, Some(bind_bad_name));
+            ffi::duckdb_table_function_set_init(function, Some(init_nothing));
+            ffi::duckdb_table_function_set_function(function, Some(produce_nothing));
+            assert_eq!(ffi::duckdb_register_table_function(con.con, function), ffi::DuckDBSuccess);
+            ffi::duckdb_destroy_table_function(&mut function);
+        }
+
+        let mut stmt = con.prepare("SELECT * FROM bad_name()").unwrap();
+        let err = stmt.execute().map(|_| ()).unwrap_err();
+        assert!(matches!(err, Error::ConversionError(DuckDBConversionError::Utf8(_))), "{err}");
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
-    error::{Error, Result},
+    error::{DuckDBConversionError, Error, Result},
     ffi::{self, DUCKDB_TYPE},
     raw::data_chunk::DataChunk,
-    types::value::DuckValue,
+    types::{
+        from_struct::{struct_list_from_value, FromStruct},
+        time_micros::TimeMicros,
+        value::DuckValue,
+    },
 };
-use std::ptr;
 
 /// A single row of data returned by a DuckDB query, consisting of typed values
 /// and their associated column names.
@@ -67,11 +70,162 @@ impl DuckRow {
         }
     }
 
+    /// Returns whether the named column is `NULL`, or `None` if no column has that
+    /// name.
+    pub fn is_null(
+        &self,
+        name: &str,
+    ) -> Option<bool> {
+        self.get(name).map(DuckValue::is_null)
+    }
+
+    /// Returns the named column as a `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnName`] if no column has that name, or a conversion
+    /// error if the value is `NULL` or not text.
+    pub fn get_string(
+        &self,
+        name: &str,
+    ) -> Result<String> {
+        self.get_typed(name, "VARCHAR", |value| String::try_from(value).ok())
+    }
+
+    /// Returns the named column as an `i64`.
+    ///
+    /// Any integer column whose value fits is accepted, so an `INTEGER` or `UBIGINT`
+    /// column can be read without matching its exact variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnName`] if no column has that name, or a conversion
+    /// error if the value is `NULL`, not an integer, or out of range for `i64`.
+    pub fn get_i64(
+        &self,
+        name: &str,
+    ) -> Result<i64> {
+        self.get_typed(name, "BIGINT", |value| i64::try_from(value).ok())
+    }
+
+    /// Returns the named column as an `f64`, widening a `FLOAT`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnName`] if no column has that name, or a conversion
+    /// error if the value is `NULL` or not a `FLOAT`/`DOUBLE`.
+    pub fn get_f64(
+        &self,
+        name: &str,
+    ) -> Result<f64> {
+        self.get_typed(name, "DOUBLE", |value| f64::try_from(value).ok())
+    }
+
+    /// Returns the named column as a `bool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnName`] if no column has that name, or a conversion
+    /// error if the value is `NULL` or not a `BOOLEAN`.
+    pub fn get_bool(
+        &self,
+        name: &str,
+    ) -> Result<bool> {
+        self.get_typed(name, "BOOLEAN", |value| bool::try_from(value).ok())
+    }
+
+    /// Returns the named column's bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnName`] if no column has that name, or a conversion
+    /// error if the value is `NULL` or not a `BLOB`.
+    pub fn get_bytes(
+        &self,
+        name: &str,
+    ) -> Result<Vec<u8>> {
+        self.get_typed(name, "BLOB", |value| Vec::<u8>::try_from(value).ok())
+    }
+
+    /// Looks up `name` and converts it with `convert`, reporting a `NULL` as
+    /// [`DuckDBConversionError::NullValue`] and a `None` from `convert` as a mismatch
+    /// with `expected`.
+    fn get_typed<T>(
+        &self,
+        name: &str,
+        expected: &str,
+        convert: impl FnOnce(&DuckValue) -> Option<T>,
+    ) -> Result<T> {
+        let value = self.get(name).ok_or_else(|| Error::InvalidColumnName(name.to_owned()))?;
+        if let DuckValue::Null = value {
+            return Err(Error::ConversionError(DuckDBConversionError::NullValue));
+        }
+        convert(value).ok_or_else(|| {
+            Error::ConversionError(DuckDBConversionError::ConversionError(format!(
+                "column {name} is not a {expected}: {value:?}"
+            )))
+        })
+    }
+
+    /// Returns the `TIME` value of the named column as microseconds since midnight.
+    ///
+    /// Works with and without the `chrono` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnName`] if no column has that name, or a conversion
+    /// error if the value is `NULL` or not a `TIME`.
+    pub fn get_time_micros(
+        &self,
+        name: &str,
+    ) -> Result<i64> {
+        let value = self.get(name).ok_or_else(|| Error::InvalidColumnName(name.to_owned()))?;
+        match value {
+            DuckValue::Null => Err(Error::ConversionError(DuckDBConversionError::NullValue)),
+            other => TimeMicros::from_value(other).map(|t| t.0).ok_or_else(|| {
+                Error::ConversionError(DuckDBConversionError::ConversionError(format!(
+                    "column {name} is not a TIME: {other:?}"
+                )))
+            }),
+        }
+    }
+
+    /// Decodes a `LIST(STRUCT(..))` column into a `Vec<T>`, one element per struct.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidColumnName`] if no column has that name, or a conversion
+    /// error if the value is `NULL`, is not a list of structs, or `T::from_struct` fails
+    /// for an element.
+    pub fn get_struct_list<T: FromStruct>(
+        &self,
+        name: &str,
+    ) -> Result<Vec<T>> {
+        let value = self.get(name).ok_or_else(|| Error::InvalidColumnName(name.to_owned()))?;
+        struct_list_from_value(value)
+    }
+
     /// Returns the number of columns in this row.
     pub fn column_count(&self) -> u64 {
         self.1.len() as u64
     }
 
+    /// Returns the row's values in column order.
+    pub fn values(&self) -> &[DuckValue] {
+        &self.0
+    }
+
+    /// Returns the row's column names in column order, matching [`values`](DuckRow::values).
+    pub fn columns(&self) -> &[Box<str>] {
+        &self.1
+    }
+
+    /// Iterates over the row's `(column name, value)` pairs in column order, e.g. to
+    /// serialize a row without knowing its schema up front.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &DuckValue)> {
+        self.1.iter().map(|name| name.as_ref()).zip(self.0.iter())
+    }
+
     /// Constructs a [`DuckRow`] from the current position of a `DataChunk`.
     ///
     /// # Errors
@@ -82,17 +236,35 @@ impl DuckRow {
         col_names: Box<[Box<str>]>,
         col_types: &[DUCKDB_TYPE],
     ) -> Result<Self> {
+        let mut row = DuckRow(Vec::with_capacity(col_names.len()), col_names);
         let row_idx = chunk.current_row() - 1; // Adjust for 0-based index
-        let column_count = col_names.len() as u64;
+        row.fill_at(chunk, row_idx, col_types)?;
+        Ok(row)
+    }
+
+    /// Replaces this row's values with those of row `row_idx` within `chunk`, keeping
+    /// the column names and the value buffer's allocation. `row_idx` must be less than
+    /// the chunk's row count.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row has no columns or if a column vector is null. On
+    /// error the row's values are left partially filled.
+    pub(crate) fn fill_at(
+        &mut self,
+        chunk: &DataChunk,
+        row_idx: u64,
+        col_types: &[DUCKDB_TYPE],
+    ) -> Result<()> {
+        let column_count = self.1.len() as u64;
         if column_count == 0 {
-            return Err(Error::DuckDBFailure(
-                ffi::Error::new(ffi::DuckDBError),
-                Some("No columns in result".to_owned()),
-            ));
+            return Err(Error::DuckDBFailure {
+                error: ffi::Error::new(ffi::DuckDBError),
+                message: Some("No columns in result".to_owned()),
+                error_type: None,
+            });
         }
-        let mut values: Vec<DuckValue> = Vec::with_capacity(column_count as usize);
-        let values_ptr: *mut DuckValue = values.as_mut_ptr();
-
+        self.0.clear();
         for col_idx in 0..column_count {
             // SAFETY: `**chunk` is a valid duckdb_data_chunk; `col_idx` is within
             // [0, column_count). `duckdb_data_chunk_get_vector` returns null on failure,
@@ -100,24 +272,17 @@ impl DuckRow {
             let col_vec = unsafe { ffi::duckdb_data_chunk_get_vector(**chunk, col_idx) };
 
             if col_vec.is_null() {
-                return Err(Error::DuckDBFailure(
-                    ffi::Error::new(ffi::DuckDBError),
-                    Some("Column returned invalid null ptr".to_owned()),
-                ));
+                return Err(Error::DuckDBFailure {
+                    error: ffi::Error::new(ffi::DuckDBError),
+                    message: Some("Column returned invalid null ptr".to_owned()),
+                    error_type: None,
+                });
             }
             let val = DuckValue::from_duckdb_vec(col_vec, col_types[col_idx as usize], row_idx)
                 .map_err(Error::ConversionError)?;
-
-            // SAFETY: `values_ptr` points to the allocation backing `values` with capacity
-            // `column_count`. `col_idx` is within that capacity, so `add(col_idx)` is in
-            // bounds. We set the length after all writes succeed.
-            unsafe { ptr::write(values_ptr.add(col_idx as usize), val) };
+            self.0.push(val);
         }
-        // SAFETY: We have written exactly `column_count` initialized elements starting at
-        // `values_ptr`. Setting the length to `column_count` is therefore sound.
-        unsafe { values.set_len(column_count as usize) };
-
-        Ok(DuckRow(values, col_names))
+        Ok(())
     }
 }
 
@@ -147,4 +312,77 @@ mod tests {
         assert_eq!(row.get("name"), Some(&DuckValue::Text("hello".to_string())));
         assert_eq!(row.get("nonexistent"), None);
     }
+
+    #[test]
+    fn test_iter_pairs_columns_with_values() {
+        let con = get_test_connection();
+        let mut stmt = con.prepare("SELECT 1 AS id, 'one' AS name").unwrap();
+        let row = stmt.execute().unwrap().next().expect("expected a row").unwrap();
+
+        let pairs: Vec<_> = row.iter().collect();
+        assert_eq!(pairs, [("id", &DuckValue::Int(1)), ("name", &DuckValue::text("one"))]);
+        let names: Vec<&str> = row.columns().iter().map(AsRef::as_ref).collect();
+        assert_eq!(names, ["id", "name"]);
+        assert_eq!(row.iter().len(), row.values().len());
+    }
+
+    fn typed_row() -> DuckRow {
+        let names = ["s", "i", "big", "f", "b", "bytes", "null"];
+        DuckRow::new(
+            vec![
+                DuckValue::text("hi"),
+                DuckValue::Int(-7),
+                DuckValue::UBigInt(u64::MAX),
+                DuckValue::Float(1.5),
+                DuckValue::Boolean(true),
+                DuckValue::Blob(vec![0, 1, 2].into()),
+                DuckValue::Null,
+            ],
+            names.iter().map(|n| Box::from(*n)).collect(),
+        )
+    }
+
+    #[test]
+    fn test_typed_getters() {
+        let row = typed_row();
+        assert_eq!(row.get_string("s").unwrap(), "hi");
+        assert_eq!(row.get_i64("i").unwrap(), -7);
+        assert_eq!(row.get_f64("f").unwrap(), 1.5);
+        assert!(row.get_bool("b").unwrap());
+        assert_eq!(row.get_bytes("bytes").unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_is_null_by_name() {
+        let row = typed_row();
+        assert_eq!(row.is_null("null"), Some(true));
+        assert_eq!(row.is_null("i"), Some(false));
+        assert_eq!(row.is_null("missing"), None);
+    }
+
+    #[test]
+    fn test_typed_getters_report_mismatch_missing_and_null() {
+        let row = typed_row();
+        let err = row.get_i64("s").unwrap_err();
+        assert!(
+            matches!(err, Error::ConversionError(DuckDBConversionError::ConversionError(ref m))
+                if m == "column s is not a BIGINT: Text(\"hi\")"),
+            "{err:?}"
+        );
+        assert!(row.get_string("i").is_err());
+        assert!(row.get_f64("b").is_err());
+        assert!(row.get_bool("f").is_err());
+        assert!(row.get_bytes("s").is_err());
+        // Out of range for i64 is a mismatch, not a wrapped value.
+        assert!(row.get_i64("big").is_err());
+
+        assert_eq!(
+            row.get_bool("missing").unwrap_err(),
+            Error::InvalidColumnName("missing".into())
+        );
+        assert!(matches!(
+            row.get_string("null"),
+            Err(Error::ConversionError(DuckDBConversionError::NullValue))
+        ));
+    }
 }
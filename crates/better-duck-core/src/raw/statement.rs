@@ -1,8 +1,9 @@
 use std::{ffi::CString, mem, ptr};
 
 use crate::ffi::{
-    duckdb_clear_bindings, duckdb_destroy_prepare, duckdb_execute_prepared, duckdb_nparams,
-    duckdb_prepare, duckdb_result, DuckDBSuccess,
+    duckdb_clear_bindings, duckdb_destroy_prepare, duckdb_execute_prepared,
+    duckdb_execute_prepared_streaming, duckdb_nparams, duckdb_param_type, duckdb_prepare,
+    duckdb_result, DuckDBSuccess, DUCKDB_TYPE,
 };
 
 use crate::{
@@ -10,8 +11,11 @@ use crate::{
     ffi,
     ffi::duckdb_prepared_statement,
     helpers::duck_result::{result_from_duckdb_prepare, result_from_duckdb_result},
-    raw::{connection::RawConnection, result::DuckResult},
-    types::appendable::AppendAble,
+    raw::{
+        connection::RawConnection,
+        result::{DuckResult, TypedRows},
+    },
+    types::{appendable::AppendAble, from_row::FromRow, value::DuckValue, value_ref::DuckValueRef},
 };
 
 /// A prepared DuckDB statement that can be executed one or more times.
@@ -46,6 +50,14 @@ impl Statement<'_> {
         Ok(Statement { con, stmt, bind_idx: 0 })
     }
 
+    /// Wraps a statement that was already prepared on `con`, taking ownership of it.
+    pub(super) fn from_prepared(
+        con: &RawConnection,
+        stmt: duckdb_prepared_statement,
+    ) -> Statement<'_> {
+        Statement { con, stmt, bind_idx: 0 }
+    }
+
     /// Returns a reference to the raw prepared-statement pointer.
     #[allow(unused)]
     #[inline]
@@ -65,8 +77,10 @@ impl Statement<'_> {
 impl Statement<'_> {
     /// Binds a value to the next positional parameter (1-based).
     ///
-    /// The first call binds parameter 1, the second call parameter 2, and so on.
-    /// Call [`clear_bindings`](Statement::clear_bindings) to reset the counter.
+    /// The first call binds parameter 1, the second call parameter 2, and so on. A value
+    /// that fills several parameters, such as a tuple `(1i32, "name", true)`, binds them
+    /// all in order and moves the counter past the last one. Call
+    /// [`clear_bindings`](Statement::clear_bindings) to reset the counter.
     ///
     /// # Errors
     ///
@@ -78,9 +92,30 @@ impl Statement<'_> {
         &mut self,
         binder: &mut T,
     ) -> Result<()> {
-        self.bind_idx += 1;
         // Pass the 1-based index directly to stmt_append.
-        self.bind_at(binder, self.bind_idx)
+        self.bind_at(binder, self.bind_idx + 1)?;
+        self.bind_idx += binder.appender_column_count().unwrap_or(1) as u64;
+        Ok(())
+    }
+
+    /// Binds a [`DuckValue`] to the next positional parameter, like
+    /// [`bind`](Statement::bind).
+    ///
+    /// Lets query layers that carry their parameters as `DuckValue`s bind them without
+    /// knowing the concrete type: scalars use the matching `duckdb_bind_*` call,
+    /// [`DuckValue::Null`] binds SQL `NULL`, and nested values are converted with
+    /// `to_duck` and bound through `duckdb_bind_value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be converted or DuckDB rejects the binding.
+    #[must_use = "bind_value result should be checked"]
+    pub fn bind_value(
+        &mut self,
+        value: &DuckValue,
+    ) -> Result<()> {
+        self.bind_idx += 1;
+        DuckValueRef::from(value).stmt_append(self.bind_idx, self.stmt)
     }
 
     /// Binds a value to the parameter at the given 1-based index.
@@ -106,7 +141,8 @@ impl Statement<'_> {
     /// Executes the prepared statement and returns the result.
     ///
     /// The statement can be re-executed after calling [`clear_bindings`](Statement::clear_bindings)
-    /// and re-binding parameters.
+    /// and re-binding parameters. Every call returns a fresh [`DuckResult`] that owns its
+    /// own `duckdb_result`, so earlier results stay readable and are freed when dropped.
     ///
     /// # Errors
     ///
@@ -122,7 +158,64 @@ impl Statement<'_> {
         // transfers to `DuckResult::new`, whose `Drop` calls `duckdb_destroy_result` once.
         let resp = unsafe { duckdb_execute_prepared(self.stmt, &mut *out as *mut duckdb_result) };
         result_from_duckdb_result(resp, &mut *out as *mut duckdb_result)?;
-        Ok(DuckResult::new(*out))
+        DuckResult::new(*out)
+    }
+
+    /// Executes the prepared statement and returns the number of rows it changed.
+    ///
+    /// The natural call for a prepared `INSERT`, `UPDATE` or `DELETE`: shorthand for
+    /// `execute()?.changes()`, so the caller never handles the result. Statements that
+    /// do not touch any rows return `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if execution fails.
+    #[must_use = "the affected-row count should be checked"]
+    pub fn execute_returning_changes(&mut self) -> Result<u64> {
+        self.execute().map(|mut res| res.changes())
+    }
+
+    /// Executes the prepared statement as a streaming query.
+    ///
+    /// Unlike [`execute`](Statement::execute), DuckDB does not materialize the whole
+    /// result up front: the returned [`DuckResult`] pulls one chunk of up to 2048 rows
+    /// at a time as it is iterated, so memory stays bounded for large scans.
+    ///
+    /// A streaming result keeps the connection's query open. Read it to the end (or
+    /// drop it) before running another statement on the same connection; doing so
+    /// earlier invalidates the stream, and its next fetch yields an error.
+    /// [`DuckResult::row`] and [`DuckResult::row_count`] still work, but they keep the
+    /// chunks they read, giving up the memory bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if execution fails.
+    #[must_use = "stream returns the query result; dropping it without reading discards rows"]
+    pub fn stream(&mut self) -> Result<DuckResult> {
+        // SAFETY: `mem::zeroed::<duckdb_result>()` is the correct initial state for a
+        // `duckdb_result` output parameter.
+        let mut out = Box::new(unsafe { mem::zeroed::<duckdb_result>() });
+        // SAFETY: `self.stmt` is a valid prepared statement and `&mut *out` points to the
+        // zeroed output buffer. Ownership of `*out` transfers to `DuckResult::new`, whose
+        // `Drop` calls `duckdb_destroy_result` once.
+        let resp = unsafe {
+            duckdb_execute_prepared_streaming(self.stmt, &mut *out as *mut duckdb_result)
+        };
+        result_from_duckdb_result(resp, &mut *out as *mut duckdb_result)?;
+        DuckResult::new(*out)
+    }
+
+    /// Executes the prepared statement and decodes each result row into `T`.
+    ///
+    /// Equivalent to calling [`FromRow::from_row`] on every row of
+    /// [`execute`](Statement::execute), but decodes into one reused row buffer instead of
+    /// allocating a [`DuckRow`](crate::DuckRow) per row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if execution fails. Decoding errors are yielded by the iterator.
+    pub fn query_typed<T: FromRow>(&mut self) -> Result<TypedRows<T>> {
+        Ok(self.execute()?.into_typed())
     }
 
     /// Returns the number of parameters in the prepared statement.
@@ -133,6 +226,36 @@ impl Statement<'_> {
         unsafe { duckdb_nparams(self.stmt) as usize }
     }
 
+    /// Returns the DuckDB type the parameter at the 1-based `idx` expects, so a generic
+    /// binder can pick the matching conversion before binding.
+    ///
+    /// A parameter whose type DuckDB could not infer, e.g. the one in `SELECT ?`,
+    /// reports `DUCKDB_TYPE_INVALID`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameterIndex`] if `idx` is 0 or greater than
+    /// [`bind_parameter_count`](Statement::bind_parameter_count).
+    pub fn parameter_type(
+        &self,
+        idx: u64,
+    ) -> Result<DUCKDB_TYPE> {
+        if idx == 0 || idx > self.bind_parameter_count() as u64 {
+            return Err(Error::InvalidParameterIndex(idx));
+        }
+        // SAFETY: `self.stmt` is a valid prepared statement and `idx` is in range.
+        Ok(unsafe { duckdb_param_type(self.stmt, idx) })
+    }
+
+    /// Describes the statement's parameters, in order.
+    pub fn info(&self) -> PreparedInfo {
+        let param_types = (1..=self.bind_parameter_count() as u64)
+            // SAFETY: `self.stmt` is a valid prepared statement and `idx` is in range.
+            .map(|idx| unsafe { duckdb_param_type(self.stmt, idx) })
+            .collect();
+        PreparedInfo { param_types }
+    }
+
     /// Clears all parameter bindings and resets the bind index to zero.
     ///
     /// After calling this method, subsequent [`bind`](Statement::bind) calls start
@@ -148,10 +271,11 @@ impl Statement<'_> {
         // SAFETY: `self.stmt` is a valid prepared statement.
         let res = unsafe { duckdb_clear_bindings(self.stmt) };
         if res != DuckDBSuccess {
-            Err(Error::DuckDBFailure(
-                crate::ffi::Error::new(crate::ffi::DuckDBError),
-                Some("Failed to clear bindings".to_owned()),
-            ))
+            Err(Error::DuckDBFailure {
+                error: crate::ffi::Error::new(crate::ffi::DuckDBError),
+                message: Some("Failed to clear bindings".to_owned()),
+                error_type: None,
+            })
         } else {
             self.bind_idx = 0;
             Ok(())
@@ -166,6 +290,22 @@ impl Statement<'_> {
     }
 }
 
+/// The parameters of a prepared statement, as returned by [`Statement::info`] and
+/// [`Connection::validate`](crate::connection::Connection::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedInfo {
+    /// The type each parameter expects, in order; `DUCKDB_TYPE_INVALID` for one whose
+    /// type DuckDB could not infer.
+    pub param_types: Vec<DUCKDB_TYPE>,
+}
+
+impl PreparedInfo {
+    /// Returns the number of parameters the statement takes.
+    pub fn param_count(&self) -> usize {
+        self.param_types.len()
+    }
+}
+
 /// Destroys the prepared statement when the `Statement` is dropped.
 impl Drop for Statement<'_> {
     fn drop(&mut self) {
@@ -231,7 +371,7 @@ impl CachedStatement {
         if r == ffi::DuckDBSuccess {
             Ok(())
         } else {
-            Err(Error::DuckDBFailure(ffi::Error::new(r), None))
+            Err(Error::DuckDBFailure { error: ffi::Error::new(r), message: None, error_type: None })
         }
     }
 
@@ -272,7 +412,7 @@ impl CachedStatement {
             ffi::duckdb_execute_prepared(self.stmt, &mut *out as *mut ffi::duckdb_result)
         };
         result_from_duckdb_result(r, &mut *out as *mut ffi::duckdb_result)?;
-        Ok(DuckResult::new(*out))
+        DuckResult::new(*out)
     }
 }
 
@@ -340,6 +480,69 @@ mod tests {
         assert!(stmt.bind_at(&mut dummy, 1).is_ok());
     }
 
+    #[test]
+    fn test_bind_uses_one_based_indices() {
+        use crate::types::value::DuckValue;
+
+        let con = get_test_connection();
+        let mut stmt = Statement::new(&con, "SELECT $1 AS a, $2 AS b").unwrap();
+        stmt.bind(&mut 7i32).unwrap();
+        stmt.bind(&mut String::from("second")).unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("a"), Some(&DuckValue::Int(7)));
+        assert_eq!(row.get("b"), Some(&DuckValue::text("second")));
+    }
+
+    #[test]
+    fn test_bind_tuple_fills_consecutive_parameters() {
+        let con = get_test_connection();
+        let mut stmt = Statement::new(&con, "SELECT ? + ? AS sum").unwrap();
+        stmt.bind(&mut (2i32, 3i32)).unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("sum"), Some(&DuckValue::Int(5)));
+
+        let mut stmt = Statement::new(&con, "SELECT $1 AS a, $2 AS b, $3 AS c, $4 AS d").unwrap();
+        stmt.bind(&mut (1i32, "name", true)).unwrap();
+        stmt.bind(&mut 4i64).unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("a"), Some(&DuckValue::Int(1)));
+        assert_eq!(row.get("b"), Some(&DuckValue::text("name")));
+        assert_eq!(row.get("c"), Some(&DuckValue::Boolean(true)));
+        assert_eq!(row.get("d"), Some(&DuckValue::BigInt(4)));
+    }
+
+    #[test]
+    fn test_bind_value_text_and_null() {
+        let con = get_test_connection();
+        let mut stmt =
+            Statement::new(&con, "SELECT $1 AS a, $2 AS b, $2 IS NULL AS b_is_null").unwrap();
+        stmt.bind_value(&DuckValue::text("dynamic")).unwrap();
+        stmt.bind_value(&DuckValue::Null).unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("a"), Some(&DuckValue::text("dynamic")));
+        assert_eq!(row.get("b"), Some(&DuckValue::Null));
+        assert_eq!(row.get("b_is_null"), Some(&DuckValue::Boolean(true)));
+    }
+
+    #[test]
+    fn test_bind_value_nested_and_mixed_with_bind() {
+        let con = get_test_connection();
+        let mut stmt = Statement::new(&con, "SELECT $1::INTEGER AS n, len($2) AS l").unwrap();
+        stmt.bind(&mut 3i32).unwrap();
+        stmt.bind_value(&DuckValue::List(vec![DuckValue::Int(1), DuckValue::Int(2)])).unwrap();
+        let row = stmt.execute().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.get("n"), Some(&DuckValue::Int(3)));
+        assert_eq!(row.get("l"), Some(&DuckValue::BigInt(2)));
+    }
+
+    #[test]
+    fn test_bind_value_past_last_parameter_errors() {
+        let con = get_test_connection();
+        let mut stmt = Statement::new(&con, "SELECT $1 AS a").unwrap();
+        stmt.bind_value(&DuckValue::Null).unwrap();
+        assert!(stmt.bind_value(&DuckValue::Null).is_err());
+    }
+
     #[test]
     fn test_raw_and_connection() {
         let con = get_test_connection();
@@ -367,6 +570,23 @@ mod tests {
         assert!(stmt.execute().is_ok());
     }
 
+    #[test]
+    fn test_reexecute_with_new_bindings_keeps_results_independent() {
+        use crate::types::value::DuckValue;
+
+        let con = get_test_connection();
+        let mut stmt = Statement::new(&con, "SELECT $1 AS v").unwrap();
+        stmt.bind(&mut 1i32).unwrap();
+        let mut first = stmt.execute().unwrap();
+
+        stmt.clear_bindings().unwrap();
+        stmt.bind(&mut 2i32).unwrap();
+        let mut second = stmt.execute().unwrap();
+
+        assert_eq!(second.next().unwrap().unwrap().get("v"), Some(&DuckValue::Int(2)));
+        assert_eq!(first.next().unwrap().unwrap().get("v"), Some(&DuckValue::Int(1)));
+    }
+
     #[test]
     fn test_clear_bindings_resets_idx() {
         let con = get_test_connection();
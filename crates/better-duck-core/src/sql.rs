@@ -0,0 +1,153 @@
+//! Helpers for building SQL text safely.
+//!
+//! These are pure string functions; they never talk to DuckDB. Prefer binding
+//! values as parameters and use these only for the parts of a query that a
+//! parameter cannot express on its own.
+
+/// Escapes the `LIKE` wildcards `%` and `_` (and `escape_char` itself) in `s`, so the
+/// result matches `s` literally when used as a `LIKE` pattern with
+/// `ESCAPE '<escape_char>'`.
+///
+/// Bind the returned string as a parameter; only the wildcard meaning is neutralised
+/// here, not SQL quoting. Add your own `%` around the escaped value for substring
+/// matches.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, params, sql::escape_like};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// conn.execute_batch("CREATE TABLE promo (code TEXT)")?;
+/// conn.execute_batch("INSERT INTO promo VALUES ('50%_off'), ('50 cents off')")?;
+///
+/// let pattern = format!("{}%", escape_like("50%_", '\\'));
+/// let matches = conn
+///     .execute_with("SELECT code FROM promo WHERE code LIKE ? ESCAPE '\\'", params![pattern])?
+///     .count();
+/// assert_eq!(matches, 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn escape_like(
+    s: &str,
+    escape_char: char,
+) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c == '%' || c == '_' || c == escape_char {
+            out.push(escape_char);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Quotes `s` as a single-quoted SQL string literal, doubling embedded quotes.
+///
+/// For statements such as `INSTALL` or `current_setting` that take a constant and
+/// cannot bind a parameter. Prefer a bound parameter wherever one is accepted.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, sql::quote_literal};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// assert_eq!(quote_literal("it's"), "'it''s'");
+///
+/// let mut conn = Connection::open_in_memory()?;
+/// let sql = format!("SELECT {} AS s", quote_literal("'; DROP TABLE t; --"));
+/// let s = conn.query_scalar::<String>(&sql)?;
+/// assert_eq!(s, "'; DROP TABLE t; --");
+/// # Ok(())
+/// # }
+/// ```
+pub fn quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Quotes `s` as a double-quoted SQL identifier, doubling embedded quotes.
+///
+/// For table, column or setting names that cannot be bound as a parameter. The quoted
+/// name is matched exactly, so DuckDB's case folding of bare identifiers does not
+/// apply to it.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, sql::quote_identifier};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// assert_eq!(quote_identifier("my \"table\""), "\"my \"\"table\"\"\"");
+///
+/// let mut conn = Connection::open_in_memory()?;
+/// conn.execute_batch(format!("CREATE TABLE {} (id INTEGER)", quote_identifier("odd\" name")))?;
+/// assert!(conn.table_exists("main", "odd\" name")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn quote_identifier(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{connection::Connection, params, types::value::DuckValue};
+
+    #[test]
+    fn test_escape_like_wildcards() {
+        assert_eq!(escape_like("50%_off", '\\'), "50\\%\\_off");
+        assert_eq!(escape_like("a\\b", '\\'), "a\\\\b");
+        assert_eq!(escape_like("100%", '!'), "100!%");
+        assert_eq!(escape_like("plain", '\\'), "plain");
+    }
+
+    #[test]
+    fn test_quote_literal() {
+        assert_eq!(quote_literal("json"), "'json'");
+        assert_eq!(quote_literal("it's"), "'it''s'");
+        assert_eq!(quote_literal("''"), "''''''");
+        assert_eq!(quote_literal(""), "''");
+    }
+
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!(quote_identifier("threads"), "\"threads\"");
+        assert_eq!(quote_identifier("a\"b"), "\"a\"\"b\"");
+        assert_eq!(quote_identifier("it's"), "\"it's\"");
+    }
+
+    #[test]
+    fn test_quoted_names_round_trip_through_duckdb() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let table = "we\"ird'; DROP TABLE x; --";
+        conn.execute_batch(format!("CREATE TABLE {} (v TEXT)", quote_identifier(table))).unwrap();
+        let text = "it's \"quoted\"";
+        conn.execute_batch(format!(
+            "INSERT INTO {} VALUES ({})",
+            quote_identifier(table),
+            quote_literal(text)
+        ))
+        .unwrap();
+        let v: String =
+            conn.query_scalar(format!("SELECT v FROM {}", quote_identifier(table))).unwrap();
+        assert_eq!(v, text);
+    }
+
+    #[test]
+    fn test_escape_like_matches_literal() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE promo (code TEXT)").unwrap();
+        conn.execute_batch("INSERT INTO promo VALUES ('50%_off'), ('50X-off'), ('500 off')")
+            .unwrap();
+
+        let pattern = escape_like("50%_off", '\\');
+        let rows = conn
+            .execute_with("SELECT code FROM promo WHERE code LIKE ? ESCAPE '\\'", params![pattern])
+            .unwrap()
+            .collect::<crate::error::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get("code"), Some(&DuckValue::text("50%_off")));
+    }
+}
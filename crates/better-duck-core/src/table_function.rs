@@ -0,0 +1,355 @@
+//! Table functions that stream rows produced by Rust code.
+//!
+//! A [`TableFunction`] declares its output columns up front and a closure that starts
+//! a new row iterator for every scan. Once registered with
+//! [`Connection::register_table_function`](crate::connection::Connection::register_table_function),
+//! SQL reads it like a table: `SELECT * FROM my_func()`. Rows are copied into DuckDB
+//! one chunk at a time on a single thread.
+//!
+//! Output columns may be `BOOLEAN`, any integer or floating-point type, `VARCHAR` or
+//! `BLOB`; every cell of a row is a [`DuckValue`] of the matching variant, or
+//! [`DuckValue::Null`].
+
+use std::{
+    ffi::{c_void, CString},
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+use crate::{
+    error::{DuckDBConversionError, Error, Result},
+    ffi::{
+        self, duckdb_bind_add_result_column, duckdb_bind_get_extra_info, duckdb_bind_info,
+        duckdb_create_logical_type, duckdb_create_table_function, duckdb_data_chunk,
+        duckdb_data_chunk_get_vector, duckdb_data_chunk_set_size, duckdb_destroy_logical_type,
+        duckdb_destroy_table_function, duckdb_function_get_extra_info,
+        duckdb_function_get_init_data, duckdb_function_info, duckdb_function_set_error,
+        duckdb_init_get_extra_info, duckdb_init_info, duckdb_init_set_error,
+        duckdb_init_set_init_data, duckdb_init_set_max_threads, duckdb_register_table_function,
+        duckdb_table_function, duckdb_table_function_set_bind,
+        duckdb_table_function_set_extra_info, duckdb_table_function_set_function,
+        duckdb_table_function_set_init, duckdb_table_function_set_name,
+        duckdb_validity_set_row_invalid, duckdb_vector, duckdb_vector_assign_string_element_len,
+        duckdb_vector_ensure_validity_writable, duckdb_vector_get_data, duckdb_vector_get_validity,
+        duckdb_vector_size, DuckDBSuccess, DUCKDB_TYPE,
+    },
+    raw::connection::RawConnection,
+    types::value::DuckValue,
+};
+
+/// The iterator a scan reads its rows from.
+type RowIter = Box<dyn Iterator<Item = Vec<DuckValue>> + Send>;
+
+/// What the table function keeps for its lifetime: the schema and the row source.
+struct FunctionInfo {
+    columns: Vec<(CString, DUCKDB_TYPE)>,
+    scan: Box<dyn Fn() -> RowIter + Send + Sync>,
+}
+
+/// A table function whose rows come from a Rust iterator, ready to be registered on a
+/// connection.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{
+/// #     connection::Connection, ffi::DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR,
+/// #     table_function::TableFunction, types::value::DuckValue,
+/// # };
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let names = vec!["ada", "grace"];
+/// conn.register_table_function(TableFunction::new(
+///     "names",
+///     &[("name", DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR)],
+///     move || names.clone().into_iter().map(|n| vec![DuckValue::text(n)]),
+/// )?)?;
+/// assert_eq!(conn.query_scalar::<i64>("SELECT count(*) FROM names()")?, 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct TableFunction {
+    name: String,
+    raw: duckdb_table_function,
+}
+
+impl TableFunction {
+    /// Creates a function called `name` returning `columns`, given as name and type.
+    ///
+    /// `scan` is called once per query that reads the function and returns the rows
+    /// to emit, each with one value per column.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NulError`] if `name` or a column name contains a nul byte, or
+    /// [`Error::InvalidFunction`] if a column type is not supported.
+    pub fn new<F, I>(
+        name: &str,
+        columns: &[(&str, DUCKDB_TYPE)],
+        scan: F,
+    ) -> Result<TableFunction>
+    where
+        F: Fn() -> I + Send + Sync + 'static,
+        I: IntoIterator<Item = Vec<DuckValue>>,
+        I::IntoIter: Send + 'static,
+    {
+        let c_name = CString::new(name)?;
+        let columns = columns
+            .iter()
+            .map(|&(col, ty)| {
+                if !is_supported(ty) {
+                    return Err(Error::InvalidFunction(
+                        name.to_owned(),
+                        format!("column `{col}` has an unsupported type ({ty})"),
+                    ));
+                }
+                Ok((CString::new(col)?, ty))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let info = FunctionInfo {
+            columns,
+            scan: Box::new(move || Box::new(scan().into_iter()) as RowIter),
+        };
+        // SAFETY: creating a table function has no preconditions.
+        let raw = unsafe { duckdb_create_table_function() };
+        // SAFETY: `raw` is a valid table function and DuckDB copies the name. The boxed
+        // info is owned by the function from here on and freed by `drop_box`.
+        unsafe {
+            duckdb_table_function_set_name(raw, c_name.as_ptr());
+            duckdb_table_function_set_extra_info(
+                raw,
+                Box::into_raw(Box::new(info)) as *mut c_void,
+                Some(drop_box::<FunctionInfo>),
+            );
+            duckdb_table_function_set_bind(raw, Some(bind));
+            duckdb_table_function_set_init(raw, Some(init));
+            duckdb_table_function_set_function(raw, Some(produce));
+        }
+        Ok(TableFunction { name: name.to_owned(), raw })
+    }
+
+    /// Returns the function's SQL name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Registers the function on `con`'s database.
+    pub(crate) fn register(
+        self,
+        con: &RawConnection,
+    ) -> Result<()> {
+        // SAFETY: `con.con` is an open connection and `self.raw` a valid function;
+        // DuckDB copies the function, so `self` is destroyed afterwards as usual.
+        let r = unsafe { duckdb_register_table_function(con.con, self.raw) };
+        if r == DuckDBSuccess {
+            Ok(())
+        } else {
+            Err(Error::InvalidFunction(
+                self.name.clone(),
+                "DuckDB rejected its registration".into(),
+            ))
+        }
+    }
+}
+
+impl Drop for TableFunction {
+    fn drop(&mut self) {
+        // SAFETY: `self.raw` is a valid table function destroyed only here; a registered
+        // copy keeps its own reference to the extra info.
+        unsafe { duckdb_destroy_table_function(&mut self.raw) };
+        self.raw = ptr::null_mut();
+    }
+}
+
+/// Returns `true` if [`write_value`] can fill a column of type `ty`.
+fn is_supported(ty: DUCKDB_TYPE) -> bool {
+    matches!(
+        ty,
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_BOOLEAN
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_TINYINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_SMALLINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_INTEGER
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_BIGINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_UTINYINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_USMALLINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_UINTEGER
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_UBIGINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_UHUGEINT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_FLOAT
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR
+            | ffi::DUCKDB_TYPE_DUCKDB_TYPE_BLOB
+    )
+}
+
+/// Frees a box handed to DuckDB as extra info or init data.
+unsafe extern "C" fn drop_box<T>(data: *mut c_void) {
+    // SAFETY: `data` is a leaked `Box<T>`, freed only here.
+    drop(unsafe { Box::from_raw(data as *mut T) });
+}
+
+/// Declares the output columns.
+unsafe extern "C" fn bind(info: duckdb_bind_info) {
+    // SAFETY: the extra info is the `FunctionInfo` set in `TableFunction::new`. Column
+    // names and types are copied by DuckDB.
+    unsafe {
+        let function = &*(duckdb_bind_get_extra_info(info) as *const FunctionInfo);
+        for (name, ty) in &function.columns {
+            let mut lt = duckdb_create_logical_type(*ty);
+            duckdb_bind_add_result_column(info, name.as_ptr(), lt);
+            duckdb_destroy_logical_type(&mut lt);
+        }
+    }
+}
+
+/// Starts a scan: a fresh row iterator becomes the init data.
+unsafe extern "C" fn init(info: duckdb_init_info) {
+    // SAFETY: the extra info is the `FunctionInfo` set in `TableFunction::new`; the
+    // boxed iterator is owned by the scan and freed by `drop_box`. DuckDB copies the
+    // error message.
+    unsafe {
+        let function = &*(duckdb_init_get_extra_info(info) as *const FunctionInfo);
+        let Ok(rows) = catch_unwind(AssertUnwindSafe(|| (function.scan)())) else {
+            duckdb_init_set_error(info, c"table function panicked".as_ptr());
+            return;
+        };
+        duckdb_init_set_init_data(
+            info,
+            Box::into_raw(Box::new(rows)) as *mut c_void,
+            Some(drop_box::<RowIter>),
+        );
+        duckdb_init_set_max_threads(info, 1);
+    }
+}
+
+/// Fills `output` with the next chunk of rows; an empty chunk ends the scan.
+unsafe extern "C" fn produce(
+    info: duckdb_function_info,
+    output: duckdb_data_chunk,
+) {
+    // SAFETY: the extra info is the `FunctionInfo` and the init data the `RowIter` set
+    // above; with one thread, nothing else touches the iterator during this call.
+    // `output` has one vector per declared column, each with room for a full chunk.
+    let outcome = catch_unwind(AssertUnwindSafe(|| unsafe {
+        let function = &*(duckdb_function_get_extra_info(info) as *const FunctionInfo);
+        let rows = &mut *(duckdb_function_get_init_data(info) as *mut RowIter);
+        let vectors: Vec<_> = (0..function.columns.len())
+            .map(|col| duckdb_data_chunk_get_vector(output, col as u64))
+            .collect();
+        let mut size = 0;
+        while size < duckdb_vector_size() {
+            let Some(row) = rows.next() else { break };
+            if row.len() != vectors.len() {
+                duckdb_data_chunk_set_size(output, size);
+                return Err(format!(
+                    "row has {} values but the function returns {} columns",
+                    row.len(),
+                    vectors.len()
+                ));
+            }
+            for ((vector, (_, ty)), value) in vectors.iter().zip(&function.columns).zip(&row) {
+                if let Err(err) = write_value(*vector, *ty, size, value) {
+                    duckdb_data_chunk_set_size(output, size);
+                    return Err(Error::from(err).to_string());
+                }
+            }
+            size += 1;
+        }
+        duckdb_data_chunk_set_size(output, size);
+        Ok(())
+    }))
+    .unwrap_or_else(|_| Err("table function panicked".to_owned()));
+    if let Err(msg) = outcome {
+        let msg = CString::new(msg.replace('\0', " ")).unwrap_or_default();
+        // SAFETY: `info` is valid for this call; DuckDB copies the message.
+        unsafe { duckdb_function_set_error(info, msg.as_ptr()) };
+    }
+}
+
+/// Writes `value` into slot `row` of `vector`, a flat vector of type `ty`.
+///
+/// # Safety
+///
+/// `vector` must be a writable flat vector of type `ty` with more than `row` slots.
+unsafe fn write_value(
+    vector: duckdb_vector,
+    ty: DUCKDB_TYPE,
+    row: u64,
+    value: &DuckValue,
+) -> std::result::Result<(), DuckDBConversionError> {
+    /// Stores `v` in the vector's packed data array.
+    unsafe fn put<T>(
+        vector: duckdb_vector,
+        row: u64,
+        v: T,
+    ) {
+        // SAFETY: the caller checked that the vector holds `T`s and `row` is in bounds.
+        unsafe { *(duckdb_vector_get_data(vector) as *mut T).add(row as usize) = v };
+    }
+
+    // SAFETY: guaranteed by the caller; each arm writes the type the vector holds.
+    unsafe {
+        match (ty, value) {
+            (_, DuckValue::Null) => {
+                duckdb_vector_ensure_validity_writable(vector);
+                duckdb_validity_set_row_invalid(duckdb_vector_get_validity(vector), row);
+            },
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_BOOLEAN, DuckValue::Boolean(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_TINYINT, DuckValue::TinyInt(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_SMALLINT, DuckValue::SmallInt(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_INTEGER, DuckValue::Int(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_BIGINT, DuckValue::BigInt(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT, DuckValue::HugeInt(v)) => {
+                put(vector, row, ffi::duckdb_hugeint { lower: *v as u64, upper: (*v >> 64) as i64 })
+            },
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_UTINYINT, DuckValue::UTinyInt(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_USMALLINT, DuckValue::USmallInt(v)) => {
+                put(vector, row, *v)
+            },
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_UINTEGER, DuckValue::UInt(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_UBIGINT, DuckValue::UBigInt(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_UHUGEINT, DuckValue::UHugeInt(v)) => put(
+                vector,
+                row,
+                ffi::duckdb_uhugeint { lower: *v as u64, upper: (*v >> 64) as u64 },
+            ),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_FLOAT, DuckValue::Float(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE, DuckValue::Double(v)) => put(vector, row, *v),
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR, DuckValue::Text(v)) => {
+                assign_bytes(vector, row, v.as_bytes())
+            },
+            (ffi::DUCKDB_TYPE_DUCKDB_TYPE_BLOB, DuckValue::Blob(v)) => {
+                assign_bytes(vector, row, v.as_bytes())
+            },
+            (expected, other) => {
+                return Err(DuckDBConversionError::ConversionError(format!(
+                    "cannot write {other:?} into a column of type {expected}"
+                )))
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Copies `bytes` into string slot `row` of `vector`.
+///
+/// # Safety
+///
+/// `vector` must be a writable `VARCHAR` or `BLOB` vector with more than `row` slots.
+unsafe fn assign_bytes(
+    vector: duckdb_vector,
+    row: u64,
+    bytes: &[u8],
+) {
+    // SAFETY: guaranteed by the caller; DuckDB copies the bytes.
+    unsafe {
+        duckdb_vector_assign_string_element_len(
+            vector,
+            row,
+            bytes.as_ptr() as *const c_char,
+            bytes.len() as u64,
+        )
+    };
+}
@@ -0,0 +1,183 @@
+//! Transactions scoped to a guard value.
+//!
+//! [`Connection::transaction`] begins a transaction and returns a [`Transaction`] that
+//! derefs to the connection. The transaction ends with an explicit
+//! [`commit`](Transaction::commit) or [`rollback`](Transaction::rollback), and rolls back
+//! when the guard is dropped without either.
+
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    connection::Connection,
+    error::{Error, Result},
+};
+
+/// An open DuckDB transaction on a [`Connection`].
+///
+/// Created by [`Connection::transaction`], which runs `BEGIN TRANSACTION`. Call
+/// [`commit`](Transaction::commit) to keep the changes; if the guard is dropped without
+/// committing — including while unwinding from a panic — the transaction is rolled back.
+///
+/// The guard derefs to the [`Connection`], so statements run through it take part in the
+/// transaction. DuckDB does not support nested transactions, so
+/// [`transaction`](Transaction::transaction) on the guard returns
+/// [`Error::NestedTransaction`] instead of issuing a second `BEGIN` (which DuckDB would
+/// reject *and* use to abort the open transaction).
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::connection::Connection;
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
+///
+/// let mut tx = conn.transaction()?;
+/// tx.execute_batch("INSERT INTO t VALUES (1)")?;
+/// tx.commit()?;
+///
+/// assert_eq!(conn.query("SELECT id FROM t")?.count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Transaction<'conn> {
+    conn: &'conn mut Connection,
+    finished: bool,
+}
+
+impl<'conn> Transaction<'conn> {
+    /// Begins a transaction on `conn`.
+    pub(crate) fn new(conn: &'conn mut Connection) -> Result<Transaction<'conn>> {
+        conn.execute_batch("BEGIN TRANSACTION")?;
+        Ok(Transaction { conn, finished: false })
+    }
+
+    /// Commits the transaction, making its changes visible to other connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB fails to commit, e.g. because of a write conflict.
+    /// DuckDB aborts the transaction in that case, so nothing is left to roll back.
+    pub fn commit(mut self) -> Result<()> {
+        self.finished = true;
+        self.conn.execute_batch("COMMIT")
+    }
+
+    /// Always fails with [`Error::NestedTransaction`]: DuckDB cannot nest transactions.
+    ///
+    /// Shadows [`Connection::transaction`] so a nested call through the guard is rejected
+    /// before reaching DuckDB and the open transaction stays usable.
+    ///
+    /// # Errors
+    ///
+    /// Always returns [`Error::NestedTransaction`].
+    pub fn transaction(&mut self) -> Result<Transaction<'_>> {
+        Err(Error::NestedTransaction)
+    }
+
+    /// Rolls back the transaction, discarding every change made through it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DuckDB fails to roll back.
+    pub fn rollback(mut self) -> Result<()> {
+        self.finished = true;
+        self.conn.execute_batch("ROLLBACK")
+    }
+}
+
+impl Deref for Transaction<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn
+    }
+}
+
+impl DerefMut for Transaction<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        // Never panic in Drop: this also runs while unwinding.
+        if let Err(e) = self.conn.execute_batch("ROLLBACK") {
+            eprintln!("[better-duck] transaction rollback on drop failed: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use crate::{connection::Connection, error::Error};
+
+    fn setup() -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE t (id INTEGER)").unwrap();
+        conn
+    }
+
+    fn count(conn: &mut Connection) -> usize {
+        conn.query("SELECT id FROM t").unwrap().count()
+    }
+
+    #[test]
+    fn test_commit_keeps_changes() {
+        let mut conn = setup();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute_batch("INSERT INTO t VALUES (1), (2)").unwrap();
+        tx.commit().unwrap();
+        assert_eq!(count(&mut conn), 2);
+    }
+
+    #[test]
+    fn test_explicit_rollback_discards_changes() {
+        let mut conn = setup();
+        let mut tx = conn.transaction().unwrap();
+        tx.execute_batch("INSERT INTO t VALUES (1)").unwrap();
+        tx.rollback().unwrap();
+        assert_eq!(count(&mut conn), 0);
+    }
+
+    #[test]
+    fn test_drop_without_commit_rolls_back() {
+        let mut conn = setup();
+        {
+            let mut tx = conn.transaction().unwrap();
+            tx.execute_batch("INSERT INTO t VALUES (1)").unwrap();
+        }
+        assert_eq!(count(&mut conn), 0);
+        // The connection is usable for a new transaction afterwards.
+        conn.transaction().unwrap().commit().unwrap();
+    }
+
+    #[test]
+    fn test_panic_mid_transaction_rolls_back() {
+        let mut conn = setup();
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            let mut tx = conn.transaction().unwrap();
+            tx.execute_batch("INSERT INTO t VALUES (1)").unwrap();
+            panic!("boom");
+        }));
+        assert!(outcome.is_err());
+        assert_eq!(count(&mut conn), 0);
+    }
+
+    #[test]
+    fn test_nested_transaction_errors() {
+        let mut conn = setup();
+        let mut tx = conn.transaction().unwrap();
+        assert!(matches!(tx.transaction(), Err(Error::NestedTransaction)));
+        // The outer transaction is still usable.
+        tx.execute_batch("INSERT INTO t VALUES (1)").unwrap();
+        tx.commit().unwrap();
+        assert_eq!(count(&mut conn), 1);
+    }
+}
@@ -7,12 +7,26 @@
 //! The `idx` argument to [`AppendAble::stmt_append`] is **1-based**, matching
 //! the DuckDB C API. The first parameter is `idx = 1`.
 
-use crate::ffi::{duckdb_appender, duckdb_prepared_statement};
+use std::fmt;
 
-use crate::error::Result;
+use crate::ffi::{
+    duckdb_append_value, duckdb_appender, duckdb_bind_value, duckdb_destroy_value,
+    duckdb_prepared_statement, duckdb_state, duckdb_value, DuckDBSuccess, Error as FFIError,
+};
+
+use crate::error::{Error, Result};
+use crate::types::DuckDialect;
+
+/// Derives [`AppendAble`] for a struct, appending or binding its fields in declaration
+/// order.
+#[cfg(feature = "derive")]
+pub use better_duck_derive::AppendAble;
 
 /// Trait implemented by types that can be bound to a DuckDB prepared statement
 /// or appended to a DuckDB appender row.
+///
+/// With the `derive` feature, `#[derive(AppendAble)]` implements it for a struct whose
+/// fields are all `AppendAble`, one column per field.
 pub trait AppendAble {
     /// Binds this value to a prepared statement parameter.
     ///
@@ -53,41 +67,230 @@ pub trait AppendAble {
         &mut self,
         _appender: duckdb_appender,
     ) -> Result<()>;
+
+    /// Returns how many columns [`appender_append`](AppendAble::appender_append) fills
+    /// when this value is appended as a whole row, if known.
+    ///
+    /// The appender compares it with the table's column count before starting the row,
+    /// so a mismatch is reported as [`Error::InvalidColumnCount`] rather than DuckDB's
+    /// generic end-of-row error. The default returns `None`, which skips the check.
+    fn appender_column_count(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Checks the return code of a `duckdb_bind_*` or `duckdb_append_*` call, failing with
+/// [`Error::DuckDBFailure`] and the message "failed to {what}".
+///
+/// `what` is only formatted on failure, so callers can pass `format_args!`.
+pub(crate) fn check_rc(
+    rc: duckdb_state,
+    what: impl fmt::Display,
+) -> Result<()> {
+    if rc == DuckDBSuccess {
+        return Ok(());
+    }
+    Err(Error::DuckDBFailure {
+        error: FFIError::new(rc),
+        message: Some(format!("failed to {what}")),
+        error_type: None,
+    })
+}
+
+/// Binds any [`DuckDialect`] value to a prepared-statement parameter through the generic
+/// `duckdb_value` path: `to_duck()` → `duckdb_bind_value` → `duckdb_destroy_value`.
+///
+/// This is the building block for [`AppendAble::stmt_append`] on types that have no
+/// dedicated `duckdb_bind_*` function. `idx` is 1-based.
+///
+/// # Errors
+///
+/// Returns the conversion error from `to_duck()`, or [`Error::DuckDBFailure`] if DuckDB
+/// rejects the bind (e.g. `idx` is out of range).
+pub fn bind_via_duck_value<T: DuckDialect<R>, R>(
+    value: &T,
+    idx: u64,
+    stmt: duckdb_prepared_statement,
+) -> Result<()> {
+    bind_duck_value(value.to_duck().map_err(Error::ConversionError)?, idx, stmt)
+}
+
+/// Binds a `duckdb_value` built by the caller, e.g. a `STRUCT` from a map, then destroys
+/// it; the second half of [`bind_via_duck_value`].
+pub(crate) fn bind_duck_value(
+    mut dv: duckdb_value,
+    idx: u64,
+    stmt: duckdb_prepared_statement,
+) -> Result<()> {
+    // SAFETY: `stmt` is a valid prepared statement; `dv` is a valid value owned by this
+    // call. `duckdb_bind_value` copies the value, so it does not take ownership.
+    let rc = unsafe { duckdb_bind_value(stmt, idx, dv) };
+    // SAFETY: `dv` is owned here; destroy exactly once.
+    unsafe { duckdb_destroy_value(&mut dv) };
+    check_rc(rc, format_args!("bind value at parameter {idx}"))
+}
+
+/// Appends any [`DuckDialect`] value to the current appender row through the generic
+/// `duckdb_value` path: `to_duck()` → `duckdb_append_value` → `duckdb_destroy_value`.
+///
+/// This is the building block for [`AppendAble::appender_append`] on types that have no
+/// dedicated `duckdb_append_*` function.
+///
+/// # Errors
+///
+/// Returns the conversion error from `to_duck()`, or [`Error::DuckDBFailure`] if DuckDB
+/// rejects the value for the current column.
+pub fn append_via_duck_value<T: DuckDialect<R>, R>(
+    value: &T,
+    appender: duckdb_appender,
+) -> Result<()> {
+    append_duck_value(value.to_duck().map_err(Error::ConversionError)?, appender)
+}
+
+/// Appends a `duckdb_value` built by the caller, then destroys it; the second half of
+/// [`append_via_duck_value`].
+pub(crate) fn append_duck_value(
+    mut dv: duckdb_value,
+    appender: duckdb_appender,
+) -> Result<()> {
+    // SAFETY: `appender` is a valid duckdb_appender inside a begin_row/end_row pair;
+    // `dv` is a valid value owned by this call and is copied by `duckdb_append_value`.
+    let rc = unsafe { duckdb_append_value(appender, dv) };
+    // SAFETY: `dv` is owned here; destroy exactly once.
+    unsafe { duckdb_destroy_value(&mut dv) };
+    check_rc(rc, "append value")
 }
 
 /// Implements [`AppendAble`] for a type that already implements [`crate::types::DuckDialect`]
-/// by going through the `duckdb_value` path: `to_duck()` → `duckdb_bind_value` /
-/// `duckdb_append_value` → `duckdb_destroy_value`.
+/// by going through the `duckdb_value` path, see [`bind_via_duck_value`] and
+/// [`append_via_duck_value`].
 ///
 /// Use this for types that have no dedicated `duckdb_bind_*` / `duckdb_append_*` FFI
-/// function (e.g. `TimestampS`, `TimeTz`, `Decimal`).
+/// function (e.g. `TimestampS`, `TimeTz`, `Decimal`), including your own types:
+///
+/// ```rust
+/// use better_duck_core::{
+///     error::DuckDBConversionError,
+///     ffi::{duckdb_create_double, duckdb_get_double, duckdb_value},
+///     impl_appendable_via_to_duck_native,
+///     types::DuckDialect,
+///     AppendAble,
+/// };
+///
+/// struct Celsius(f64);
+///
+/// impl DuckDialect for Celsius {
+///     fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
+///         // SAFETY: `value` is a valid DOUBLE duckdb_value.
+///         Ok(Celsius(unsafe { duckdb_get_double(value) }))
+///     }
+///     fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+///         // SAFETY: creating a DOUBLE value cannot fail.
+///         Ok(unsafe { duckdb_create_double(self.0) })
+///     }
+/// }
+///
+/// impl_appendable_via_to_duck_native!(Celsius);
+/// ```
 #[macro_export]
 macro_rules! impl_appendable_via_to_duck_native {
     ($t:ty) => {
-        impl AppendAble for $t {
+        impl $crate::AppendAble for $t {
             fn appender_append(
                 &mut self,
                 appender: $crate::ffi::duckdb_appender,
             ) -> $crate::error::Result<()> {
-                let mut dv = self.to_duck().map_err($crate::error::Error::ConversionError)?;
-                // SAFETY: `appender` is a valid duckdb_appender; `dv` was created by `to_duck()`.
-                unsafe { $crate::ffi::duckdb_append_value(appender, dv) };
-                // SAFETY: `dv` was created above; destroy exactly once.
-                unsafe { $crate::ffi::duckdb_destroy_value(&mut dv) };
-                Ok(())
+                $crate::types::appendable::append_via_duck_value(self, appender)
             }
             fn stmt_append(
                 &mut self,
                 idx: u64,
                 stmt: $crate::ffi::duckdb_prepared_statement,
             ) -> $crate::error::Result<()> {
-                let mut dv = self.to_duck().map_err($crate::error::Error::ConversionError)?;
-                // SAFETY: `stmt`/`idx` are valid; `dv` was created by `to_duck()`.
-                unsafe { $crate::ffi::duckdb_bind_value(stmt, idx, dv) };
-                // SAFETY: `dv` was created above; destroy exactly once.
-                unsafe { $crate::ffi::duckdb_destroy_value(&mut dv) };
+                $crate::types::appendable::bind_via_duck_value(self, idx, stmt)
+            }
+        }
+    };
+}
+
+/// Builds a parameter list for
+/// [`Connection::execute_with`](crate::connection::Connection::execute_with) from plain
+/// values, so no hand-written [`AppendAble`] row struct is needed.
+///
+/// Each expression must implement [`AppendAble`]; values are bound in order, the first
+/// one to parameter `1`. The expressions are evaluated into temporaries that live until
+/// the end of the enclosing statement, so the macro is meant to be used inline as the
+/// call argument.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, params};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// conn.execute_batch("CREATE TABLE t (id INTEGER, name TEXT)")?;
+/// let n = conn.execute_with("INSERT INTO t VALUES (?, ?)", params![1i32, String::from("duck")])?
+///     .changes();
+/// assert_eq!(n, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! params {
+    () => {
+        &mut []
+    };
+    ($($param:expr),+ $(,)?) => {
+        &mut [$(&mut ::core::convert::identity($param) as &mut dyn $crate::AppendAble),+]
+    };
+}
+
+/// Implements [`AppendAble`] for a tuple, binding or appending its elements in order.
+///
+/// Each element takes as many parameters (or columns) as its
+/// [`appender_column_count`](AppendAble::appender_column_count) reports, one if
+/// unknown, so the element after it starts at the following index.
+macro_rules! impl_appendable_for_tuple {
+    ($($name:ident . $field:tt),+) => {
+        impl<$($name: AppendAble),+> AppendAble for ($($name,)+) {
+            fn stmt_append(
+                &mut self,
+                idx: u64,
+                stmt: duckdb_prepared_statement,
+            ) -> Result<()> {
+                let mut next = idx;
+                $(
+                    self.$field.stmt_append(next, stmt)?;
+                    next += self.$field.appender_column_count().unwrap_or(1) as u64;
+                )+
+                let _ = next;
                 Ok(())
             }
+
+            fn appender_append(
+                &mut self,
+                appender: duckdb_appender,
+            ) -> Result<()> {
+                $(self.$field.appender_append(appender)?;)+
+                Ok(())
+            }
+
+            fn appender_column_count(&self) -> Option<usize> {
+                Some(0 $(+ self.$field.appender_column_count().unwrap_or(1))+)
+            }
         }
     };
 }
+
+impl_appendable_for_tuple!(A.0);
+impl_appendable_for_tuple!(A.0, B.1);
+impl_appendable_for_tuple!(A.0, B.1, C.2);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10);
+impl_appendable_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10, L.11);
@@ -12,14 +12,16 @@ use crate::{
     ffi::{
         duckdb_append_value, duckdb_array_type_array_size, duckdb_array_vector_get_child,
         duckdb_bind_value, duckdb_create_array_type, duckdb_create_array_value,
-        duckdb_create_list_type, duckdb_create_list_value, duckdb_destroy_logical_type,
-        duckdb_destroy_value, duckdb_get_type_id, duckdb_list_entry, duckdb_list_vector_get_child,
-        duckdb_logical_type, duckdb_type, duckdb_validity_row_is_valid, duckdb_value,
-        duckdb_vector, duckdb_vector_get_column_type, duckdb_vector_get_data,
-        duckdb_vector_get_validity, idx_t, DUCKDB_TYPE_DUCKDB_TYPE_ARRAY,
-        DUCKDB_TYPE_DUCKDB_TYPE_LIST,
+        duckdb_create_double, duckdb_create_float, duckdb_create_list_type,
+        duckdb_create_list_value, duckdb_create_logical_type, duckdb_destroy_logical_type,
+        duckdb_destroy_value, duckdb_get_type_id, duckdb_list_entry, duckdb_list_type_child_type,
+        duckdb_list_vector_get_child, duckdb_logical_type, duckdb_param_logical_type, duckdb_type,
+        duckdb_validity_row_is_valid, duckdb_value, duckdb_vector, duckdb_vector_get_column_type,
+        duckdb_vector_get_data, duckdb_vector_get_validity, idx_t, DUCKDB_TYPE_DUCKDB_TYPE_ARRAY,
+        DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE, DUCKDB_TYPE_DUCKDB_TYPE_FLOAT,
+        DUCKDB_TYPE_DUCKDB_TYPE_LIST, DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL,
     },
-    types::appendable::AppendAble,
+    types::appendable::{check_rc, AppendAble},
 };
 
 use super::value::DuckValue;
@@ -204,16 +206,13 @@ pub(crate) fn array_to_duck(items: &[DuckValue]) -> Result<duckdb_value, DuckDBC
         unsafe { duckdb_destroy_logical_type(&mut child_lt) };
         return Err(e);
     }
-    // SAFETY: `child_lt` is valid; array_size matches item count.
-    let mut arr_lt = unsafe { duckdb_create_array_type(child_lt, child_dvs.len() as idx_t) };
-    // SAFETY: `child_lt` was allocated by `logical_type_of`; destroy once.
-    unsafe { duckdb_destroy_logical_type(&mut child_lt) };
-    // SAFETY: `arr_lt` is valid; `child_dvs` has `len()` elements.
+    // SAFETY: `child_lt` is valid; `duckdb_create_array_value` takes the element type,
+    // not the array type, and sizes the array from `child_dvs.len()`.
     let result = unsafe {
-        duckdb_create_array_value(arr_lt, child_dvs.as_mut_ptr(), child_dvs.len() as idx_t)
+        duckdb_create_array_value(child_lt, child_dvs.as_mut_ptr(), child_dvs.len() as idx_t)
     };
-    // SAFETY: `arr_lt` was allocated above; destroy once.
-    unsafe { duckdb_destroy_logical_type(&mut arr_lt) };
+    // SAFETY: `child_lt` was allocated by `logical_type_of`; destroy once.
+    unsafe { duckdb_destroy_logical_type(&mut child_lt) };
     for mut v in child_dvs {
         // SAFETY: each `v` was created by `to_duck()` above.
         unsafe { duckdb_destroy_value(&mut v) };
@@ -221,6 +220,111 @@ pub(crate) fn array_to_duck(items: &[DuckValue]) -> Result<duckdb_value, DuckDBC
     Ok(result)
 }
 
+/// Build a `FLOAT[n]`/`DOUBLE[n]` `ARRAY` value straight from a slice of primitives.
+///
+/// Each element goes through `create` (`duckdb_create_float`/`duckdb_create_double`)
+/// instead of a `DuckValue` round-trip, and the element type is created once up front.
+/// An empty slice becomes an empty `LIST`, since DuckDB has no zero-sized `ARRAY` type.
+/// The caller is responsible for destroying the returned value.
+fn float_slice_to_duck<T: Copy>(
+    items: &[T],
+    type_id: duckdb_type,
+    create: unsafe extern "C" fn(T) -> duckdb_value,
+) -> duckdb_value {
+    // SAFETY: `type_id` is a scalar FLOAT/DOUBLE type constant.
+    let mut child_lt = unsafe { duckdb_create_logical_type(type_id) };
+    let mut child_dvs: Vec<duckdb_value> = Vec::with_capacity(items.len());
+    for &item in items {
+        // SAFETY: `create` is a DuckDB scalar constructor; it copies the primitive.
+        child_dvs.push(unsafe { create(item) });
+    }
+    let result = if items.is_empty() {
+        // SAFETY: `child_lt` is valid; zero values are read from the (dangling) pointer.
+        unsafe { duckdb_create_list_value(child_lt, child_dvs.as_mut_ptr(), 0) }
+    } else {
+        // SAFETY: `child_lt` is the element type `duckdb_create_array_value` expects;
+        // `child_dvs` has exactly `items.len()` elements.
+        unsafe {
+            duckdb_create_array_value(child_lt, child_dvs.as_mut_ptr(), child_dvs.len() as idx_t)
+        }
+    };
+    // SAFETY: `child_lt` was allocated above; destroy once.
+    unsafe { duckdb_destroy_logical_type(&mut child_lt) };
+    for mut v in child_dvs {
+        // SAFETY: each `v` was created by `create` above; the array value holds copies.
+        unsafe { duckdb_destroy_value(&mut v) };
+    }
+    result
+}
+
+/// Binds a `LIST` or `ARRAY` value built by the caller and destroys it.
+fn bind_nested_value(
+    mut dv: duckdb_value,
+    idx: u64,
+    stmt: crate::ffi::duckdb_prepared_statement,
+) -> Result<()> {
+    // SAFETY: `stmt`/`idx` are valid; `dv` was created by the caller.
+    let rc = unsafe { duckdb_bind_value(stmt, idx, dv) };
+    // SAFETY: `dv` was created by the caller; destroy exactly once.
+    unsafe { duckdb_destroy_value(&mut dv) };
+    check_rc(rc, format_args!("bind LIST or ARRAY to parameter {idx}"))
+}
+
+/// Appends a `LIST` or `ARRAY` value built by the caller and destroys it.
+fn append_nested_value(
+    mut dv: duckdb_value,
+    appender: crate::ffi::duckdb_appender,
+) -> Result<()> {
+    // SAFETY: `appender` is valid; `dv` was created by the caller.
+    let rc = unsafe { duckdb_append_value(appender, dv) };
+    // SAFETY: `dv` was created by the caller; destroy exactly once.
+    unsafe { duckdb_destroy_value(&mut dv) };
+    check_rc(rc, "append LIST or ARRAY value")
+}
+
+/// Builds an empty `LIST` value typed after `list_type`.
+///
+/// An empty Rust collection carries no element type, so it is taken from `list_type`
+/// when that is a `LIST`. Otherwise (null, or a parameter DuckDB could not type) the
+/// list is `NULL[]`, which DuckDB casts to any list type. The caller is responsible for
+/// destroying the returned value.
+fn empty_list_value(list_type: duckdb_logical_type) -> duckdb_value {
+    let mut child = if list_type.is_null() {
+        ptr::null_mut()
+    } else {
+        // SAFETY: `list_type` is a valid logical type; non-LIST types yield null.
+        unsafe { duckdb_list_type_child_type(list_type) }
+    };
+    if child.is_null() {
+        // SAFETY: `SQLNULL` is a scalar type constant.
+        child = unsafe { duckdb_create_logical_type(DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL) };
+    }
+    // SAFETY: `child` is valid; with a count of 0 the (non-null, dangling) values
+    // pointer is never read.
+    let list = unsafe {
+        duckdb_create_list_value(child, ptr::NonNull::<duckdb_value>::dangling().as_ptr(), 0)
+    };
+    // SAFETY: `child` was created above and is destroyed exactly once.
+    unsafe { duckdb_destroy_logical_type(&mut child) };
+    list
+}
+
+/// Builds an empty `LIST` value for parameter `idx`, typed after what the statement
+/// expects there.
+fn empty_list_for_param(
+    stmt: crate::ffi::duckdb_prepared_statement,
+    idx: u64,
+) -> duckdb_value {
+    // SAFETY: `stmt` is a valid prepared statement; an out-of-range `idx` yields null.
+    let mut param_type = unsafe { duckdb_param_logical_type(stmt, idx) };
+    let list = empty_list_value(param_type);
+    if !param_type.is_null() {
+        // SAFETY: `param_type` was returned above and is destroyed exactly once.
+        unsafe { duckdb_destroy_logical_type(&mut param_type) };
+    }
+    list
+}
+
 // Logical-type path
 
 /// Return a `duckdb_logical_type` for a LIST of the element type of `items[0]`.
@@ -262,33 +366,35 @@ pub(crate) fn array_logical_type(
 /// Bind/append a `Vec<T>` as a DuckDB `LIST`.
 ///
 /// Each element is converted via `T: Into<DuckValue>`, then the entire `DuckValue::List`
-/// is serialized to a `duckdb_value` and bound/appended via the value path.
+/// is serialized to a `duckdb_value` and bound/appended via the value path. An empty
+/// `Vec` takes its element type from the parameter it is bound to, or is appended as
+/// `NULL[]`, which the appender casts to the column's list type.
 impl<T: Into<DuckValue> + Clone> AppendAble for Vec<T> {
     fn stmt_append(
         &mut self,
         idx: u64,
         stmt: crate::ffi::duckdb_prepared_statement,
     ) -> Result<()> {
-        let duck_list: Vec<DuckValue> = self.iter().cloned().map(Into::into).collect();
-        let mut dv = DuckValue::List(duck_list).to_duck().map_err(Error::ConversionError)?;
-        // SAFETY: `stmt`/`idx` are valid; `dv` was created by `to_duck()`.
-        unsafe { duckdb_bind_value(stmt, idx, dv) };
-        // SAFETY: `dv` was created above; destroy exactly once.
-        unsafe { duckdb_destroy_value(&mut dv) };
-        Ok(())
+        let dv = if self.is_empty() {
+            empty_list_for_param(stmt, idx)
+        } else {
+            let duck_list: Vec<DuckValue> = self.iter().cloned().map(Into::into).collect();
+            DuckValue::List(duck_list).to_duck().map_err(Error::ConversionError)?
+        };
+        bind_nested_value(dv, idx, stmt)
     }
 
     fn appender_append(
         &mut self,
         appender: crate::ffi::duckdb_appender,
     ) -> Result<()> {
-        let duck_list: Vec<DuckValue> = self.iter().cloned().map(Into::into).collect();
-        let mut dv = DuckValue::List(duck_list).to_duck().map_err(Error::ConversionError)?;
-        // SAFETY: `appender` is valid; `dv` was created by `to_duck()`.
-        unsafe { duckdb_append_value(appender, dv) };
-        // SAFETY: `dv` was created above; destroy exactly once.
-        unsafe { duckdb_destroy_value(&mut dv) };
-        Ok(())
+        let dv = if self.is_empty() {
+            empty_list_value(ptr::null_mut())
+        } else {
+            let duck_list: Vec<DuckValue> = self.iter().cloned().map(Into::into).collect();
+            DuckValue::List(duck_list).to_duck().map_err(Error::ConversionError)?
+        };
+        append_nested_value(dv, appender)
     }
 }
 
@@ -304,12 +410,8 @@ impl<T: Into<DuckValue> + Clone> AppendAble for Box<[T]> {
     ) -> Result<()> {
         let duck_arr: Box<[DuckValue]> =
             self.iter().cloned().map(Into::into).collect::<Vec<_>>().into_boxed_slice();
-        let mut dv = DuckValue::Array(duck_arr).to_duck().map_err(Error::ConversionError)?;
-        // SAFETY: `stmt`/`idx` are valid; `dv` was created by `to_duck()`.
-        unsafe { duckdb_bind_value(stmt, idx, dv) };
-        // SAFETY: `dv` was created above; destroy exactly once.
-        unsafe { duckdb_destroy_value(&mut dv) };
-        Ok(())
+        let dv = DuckValue::Array(duck_arr).to_duck().map_err(Error::ConversionError)?;
+        bind_nested_value(dv, idx, stmt)
     }
 
     fn appender_append(
@@ -318,11 +420,60 @@ impl<T: Into<DuckValue> + Clone> AppendAble for Box<[T]> {
     ) -> Result<()> {
         let duck_arr: Box<[DuckValue]> =
             self.iter().cloned().map(Into::into).collect::<Vec<_>>().into_boxed_slice();
-        let mut dv = DuckValue::Array(duck_arr).to_duck().map_err(Error::ConversionError)?;
-        // SAFETY: `appender` is valid; `dv` was created by `to_duck()`.
-        unsafe { duckdb_append_value(appender, dv) };
-        // SAFETY: `dv` was created above; destroy exactly once.
-        unsafe { duckdb_destroy_value(&mut dv) };
-        Ok(())
+        let dv = DuckValue::Array(duck_arr).to_duck().map_err(Error::ConversionError)?;
+        append_nested_value(dv, appender)
+    }
+}
+
+/// Bind/append a `&[f32]` as a DuckDB `FLOAT[n]` `ARRAY` (e.g. an embedding).
+///
+/// Faster than `Vec<f32>`, which converts element-wise through `DuckValue` and binds a
+/// `LIST`. DuckDB casts the array to a `FLOAT[]` list column when needed.
+impl AppendAble for &[f32] {
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        bind_nested_value(
+            float_slice_to_duck(self, DUCKDB_TYPE_DUCKDB_TYPE_FLOAT, duckdb_create_float),
+            idx,
+            stmt,
+        )
+    }
+
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        append_nested_value(
+            float_slice_to_duck(self, DUCKDB_TYPE_DUCKDB_TYPE_FLOAT, duckdb_create_float),
+            appender,
+        )
+    }
+}
+
+/// Bind/append a `&[f64]` as a DuckDB `DOUBLE[n]` `ARRAY`; see the `&[f32]` impl.
+impl AppendAble for &[f64] {
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        bind_nested_value(
+            float_slice_to_duck(self, DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE, duckdb_create_double),
+            idx,
+            stmt,
+        )
+    }
+
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        append_nested_value(
+            float_slice_to_duck(self, DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE, duckdb_create_double),
+            appender,
+        )
     }
 }
@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
     ffi::{duckdb_create_blob, duckdb_get_blob},
-    types::appendable::AppendAble,
+    types::appendable::{check_rc, AppendAble},
 };
 
 /// A DuckDB `BLOB` value — a raw byte sequence of arbitrary length.
@@ -64,20 +64,86 @@ impl DuckDialect for Blob {
     }
 }
 
+/// Appends `bytes` to the current appender row as a `BLOB`, pointer and length explicit.
+fn append_bytes(
+    bytes: &[u8],
+    appender: crate::ffi::duckdb_appender,
+) -> crate::error::Result<()> {
+    // SAFETY: `bytes.as_ptr()` is valid for `bytes.len()` bytes; append copies the data.
+    let rc = unsafe {
+        crate::ffi::duckdb_append_blob(
+            appender,
+            bytes.as_ptr() as *const std::ffi::c_void,
+            bytes.len() as u64,
+        )
+    };
+    check_rc(rc, "append BLOB value")
+}
+
+/// Binds `bytes` to the 1-based parameter `idx` as a `BLOB`.
+fn bind_bytes(
+    bytes: &[u8],
+    idx: u64,
+    stmt: crate::ffi::duckdb_prepared_statement,
+) -> crate::error::Result<()> {
+    // SAFETY: `bytes.as_ptr()` is valid for `bytes.len()` bytes; bind copies the data.
+    let rc = unsafe {
+        crate::ffi::duckdb_bind_blob(
+            stmt,
+            idx,
+            bytes.as_ptr() as *const std::ffi::c_void,
+            bytes.len() as u64,
+        )
+    };
+    check_rc(rc, format_args!("bind BLOB to parameter {idx}"))
+}
+
 impl AppendAble for Blob {
     fn appender_append(
         &mut self,
         appender: crate::ffi::duckdb_appender,
     ) -> crate::error::Result<()> {
-        // SAFETY: `self.0.as_ptr()` is valid for `self.0.len()` bytes; append copies the data.
-        unsafe {
-            crate::ffi::duckdb_append_blob(
-                appender,
-                self.0.as_ptr() as *const std::ffi::c_void,
-                self.0.len() as u64,
-            )
-        };
-        Ok(())
+        append_bytes(&self.0, appender)
+    }
+
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> crate::error::Result<()> {
+        bind_bytes(&self.0, idx, stmt)
+    }
+}
+
+/// Binds/appends a byte slice as a `BLOB` without copying it into a [`Blob`] first.
+///
+/// An owned `Vec<u8>` cannot get its own BLOB impl: `u8` converts into a `DuckValue`, so
+/// `Vec<u8>` is already covered by the generic `Vec<T>` impl, which binds a `LIST`
+/// (here `UTINYINT[]`) that DuckDB will not append into a `BLOB` column. Pass
+/// `vec.as_slice()`, or move the vector into a [`Blob`] with `Blob::from(vec)`, to get a
+/// `BLOB`.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, params, types::{value::DuckValue, Blob}};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let bytes = vec![0u8, 255, 128];
+/// let row = conn.execute_with("SELECT ? AS b", params![bytes.as_slice()])?.next().unwrap()?;
+/// assert_eq!(row.get("b"), Some(&DuckValue::Blob(Blob::from(bytes.clone()))));
+///
+/// let row = conn.execute_with("SELECT ? AS b", params![Blob::from(bytes)])?.next().unwrap()?;
+/// assert_eq!(row.get("b"), Some(&DuckValue::Blob(Blob::new(vec![0, 255, 128]))));
+/// # Ok(())
+/// # }
+/// ```
+impl AppendAble for &[u8] {
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> crate::error::Result<()> {
+        append_bytes(self, appender)
     }
 
     fn stmt_append(
@@ -85,15 +151,6 @@ impl AppendAble for Blob {
         idx: u64,
         stmt: crate::ffi::duckdb_prepared_statement,
     ) -> crate::error::Result<()> {
-        // SAFETY: `self.0.as_ptr()` is valid for `self.0.len()` bytes; bind copies the data.
-        unsafe {
-            crate::ffi::duckdb_bind_blob(
-                stmt,
-                idx,
-                self.0.as_ptr() as *const std::ffi::c_void,
-                self.0.len() as u64,
-            )
-        };
-        Ok(())
+        bind_bytes(self, idx, stmt)
     }
 }
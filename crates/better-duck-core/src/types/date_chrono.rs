@@ -7,7 +7,7 @@
 
 use super::*;
 use crate::error::DuckDBConversionError;
-use crate::types::appendable::AppendAble;
+use crate::types::appendable::{check_rc, AppendAble};
 use crate::{
     ffi::{
         duckdb_create_date, duckdb_create_interval, duckdb_create_time, duckdb_create_time_ns,
@@ -34,6 +34,37 @@ use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime,
 * Simple scalar types
 */
 
+// Packing into the FFI structs, shared by `to_duck` and the `AppendAble` impls.
+
+/// Days since 1970-01-01.
+fn pack_date(date: &NaiveDate) -> Result<duckdb_date, DuckDBConversionError> {
+    Ok(duckdb_date { days: date.num_days_from_ce() - 719_163 })
+}
+
+/// Microseconds since midnight; sub-microsecond digits are truncated.
+fn pack_time(time: &NaiveTime) -> Result<duckdb_time, DuckDBConversionError> {
+    let micros =
+        (time.num_seconds_from_midnight() as i64) * 1_000_000 + (time.nanosecond() as i64) / 1_000;
+    Ok(duckdb_time { micros })
+}
+
+/// Microseconds since the Unix epoch; sub-microsecond digits are truncated.
+fn pack_datetime(datetime: &NaiveDateTime) -> Result<duckdb_timestamp, DuckDBConversionError> {
+    Ok(duckdb_timestamp { micros: datetime.and_utc().timestamp_micros() })
+}
+
+/// An interval of `duration` microseconds, with no month or day component.
+fn pack_duration(duration: &Duration) -> Result<duckdb_interval, DuckDBConversionError> {
+    let micros = duration.num_microseconds().ok_or_else(|| {
+        DuckDBConversionError::ConversionError(format!(
+            "duration {duration} overflows the INTERVAL microseconds"
+        ))
+    })?;
+    Ok(duckdb_interval { months: 0, days: 0, micros })
+}
+
+/// Reading is lossy: months count as 30 days. Use
+/// [`Interval`](crate::types::interval::Interval) to keep the components apart.
 impl DuckDialect<duckdb_interval> for Duration {
     fn from_duck(value: duckdb_interval) -> Result<Self, DuckDBConversionError> {
         let total_days = value.months as i64 * 30 + value.days as i64;
@@ -41,8 +72,7 @@ impl DuckDialect<duckdb_interval> for Duration {
         Ok(Duration::microseconds(total_micros))
     }
     fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
-        let micros = self.num_microseconds().unwrap_or(0);
-        let interval = duckdb_interval { months: 0, days: 0, micros };
+        let interval = pack_duration(self)?;
         // SAFETY: `interval` is a fully initialized `duckdb_interval` value.
         Ok(unsafe { duckdb_create_interval(interval) })
     }
@@ -56,8 +86,7 @@ impl DuckDialect<duckdb_date> for NaiveDate {
             .ok_or_else(|| DuckDBConversionError::ConversionError("Invalid date".to_string()))
     }
     fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
-        let days = self.num_days_from_ce() - 719_163;
-        let raw_date = duckdb_date { days };
+        let raw_date = pack_date(self)?;
         // SAFETY: `raw_date` is a fully initialized `duckdb_date` value.
         Ok(unsafe { duckdb_create_date(raw_date) })
     }
@@ -72,9 +101,7 @@ impl DuckDialect<duckdb_time> for NaiveTime {
         .ok_or_else(|| DuckDBConversionError::ConversionError("Invalid time".to_string()))
     }
     fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
-        let micros = (self.num_seconds_from_midnight() as i64) * 1_000_000
-            + (self.nanosecond() as i64) / 1_000;
-        let raw_time = duckdb_time { micros };
+        let raw_time = pack_time(self)?;
         // SAFETY: `raw_time` is a fully initialized `duckdb_time` value.
         Ok(unsafe { duckdb_create_time(raw_time) })
     }
@@ -88,9 +115,7 @@ impl DuckDialect<duckdb_timestamp> for NaiveDateTime {
         })
     }
     fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
-        let micros = self.and_utc().timestamp() * 1_000_000
-            + (self.and_utc().timestamp_subsec_micros() as i64);
-        let raw_ts = duckdb_timestamp { micros };
+        let raw_ts = pack_datetime(self)?;
         // SAFETY: `raw_ts` is a fully initialized `duckdb_timestamp` value.
         Ok(unsafe { duckdb_create_timestamp(raw_ts) })
     }
@@ -326,103 +351,52 @@ impl TimeNs {
     }
 }
 
-impl AppendAble for NaiveDate {
-    fn appender_append(
-        &mut self,
-        appender: crate::ffi::duckdb_appender,
-    ) -> crate::error::Result<()> {
-        let raw = duckdb_date { days: self.num_days_from_ce() - 719_163 };
-        // SAFETY: `raw` is a valid duckdb_date; `appender` is a valid duckdb_appender.
-        unsafe { crate::ffi::duckdb_append_date(appender, raw) };
-        Ok(())
-    }
-    fn stmt_append(
-        &mut self,
-        idx: u64,
-        stmt: crate::ffi::duckdb_prepared_statement,
-    ) -> crate::error::Result<()> {
-        let raw = duckdb_date { days: self.num_days_from_ce() - 719_163 };
-        // SAFETY: `raw` is a valid duckdb_date; `stmt`/`idx` are valid.
-        unsafe { crate::ffi::duckdb_bind_date(stmt, idx, raw) };
-        Ok(())
-    }
-}
-
-impl AppendAble for NaiveTime {
-    fn appender_append(
-        &mut self,
-        appender: crate::ffi::duckdb_appender,
-    ) -> crate::error::Result<()> {
-        let micros = (self.num_seconds_from_midnight() as i64) * 1_000_000
-            + (self.nanosecond() as i64) / 1_000;
-        let raw = duckdb_time { micros };
-        // SAFETY: `raw` is a valid duckdb_time; `appender` is valid.
-        unsafe { crate::ffi::duckdb_append_time(appender, raw) };
-        Ok(())
-    }
-    fn stmt_append(
-        &mut self,
-        idx: u64,
-        stmt: crate::ffi::duckdb_prepared_statement,
-    ) -> crate::error::Result<()> {
-        let micros = (self.num_seconds_from_midnight() as i64) * 1_000_000
-            + (self.nanosecond() as i64) / 1_000;
-        let raw = duckdb_time { micros };
-        // SAFETY: `raw` is a valid duckdb_time; `stmt`/`idx` are valid.
-        unsafe { crate::ffi::duckdb_bind_time(stmt, idx, raw) };
-        Ok(())
-    }
-}
-
-impl AppendAble for NaiveDateTime {
-    fn appender_append(
-        &mut self,
-        appender: crate::ffi::duckdb_appender,
-    ) -> crate::error::Result<()> {
-        let micros = self.and_utc().timestamp() * 1_000_000
-            + self.and_utc().timestamp_subsec_micros() as i64;
-        let raw = duckdb_timestamp { micros };
-        // SAFETY: `raw` is a valid duckdb_timestamp; `appender` is valid.
-        unsafe { crate::ffi::duckdb_append_timestamp(appender, raw) };
-        Ok(())
-    }
-    fn stmt_append(
-        &mut self,
-        idx: u64,
-        stmt: crate::ffi::duckdb_prepared_statement,
-    ) -> crate::error::Result<()> {
-        let micros = self.and_utc().timestamp() * 1_000_000
-            + self.and_utc().timestamp_subsec_micros() as i64;
-        let raw = duckdb_timestamp { micros };
-        // SAFETY: `raw` is a valid duckdb_timestamp; `stmt`/`idx` are valid.
-        unsafe { crate::ffi::duckdb_bind_timestamp(stmt, idx, raw) };
-        Ok(())
-    }
+/// Implements [`AppendAble`] for a chrono type with a dedicated `duckdb_append_*` and
+/// `duckdb_bind_*` function, packing it with the same helper as `to_duck`.
+macro_rules! impl_chrono_append_able {
+    ($rust_type:ty, $pack:ident, $append_fn:ident, $bind_fn:ident, $sql_type:literal) => {
+        impl AppendAble for $rust_type {
+            fn appender_append(
+                &mut self,
+                appender: crate::ffi::duckdb_appender,
+            ) -> crate::error::Result<()> {
+                let raw = $pack(self)?;
+                // SAFETY: `raw` is a fully initialized FFI value; `appender` is a valid
+                // duckdb_appender inside a begin_row/end_row pair.
+                let rc = unsafe { crate::ffi::$append_fn(appender, raw) };
+                check_rc(rc, concat!("append ", $sql_type, " value"))
+            }
+            fn stmt_append(
+                &mut self,
+                idx: u64,
+                stmt: crate::ffi::duckdb_prepared_statement,
+            ) -> crate::error::Result<()> {
+                let raw = $pack(self)?;
+                // SAFETY: `raw` is a fully initialized FFI value; `stmt` is a valid
+                // prepared statement and `idx` a 1-based parameter index.
+                let rc = unsafe { crate::ffi::$bind_fn(stmt, idx, raw) };
+                check_rc(rc, format_args!(concat!("bind ", $sql_type, " to parameter {}"), idx))
+            }
+        }
+    };
 }
 
-impl AppendAble for Duration {
-    fn appender_append(
-        &mut self,
-        appender: crate::ffi::duckdb_appender,
-    ) -> crate::error::Result<()> {
-        let micros = self.num_microseconds().unwrap_or(0);
-        let raw = duckdb_interval { months: 0, days: 0, micros };
-        // SAFETY: `raw` is a valid duckdb_interval; `appender` is valid.
-        unsafe { crate::ffi::duckdb_append_interval(appender, raw) };
-        Ok(())
-    }
-    fn stmt_append(
-        &mut self,
-        idx: u64,
-        stmt: crate::ffi::duckdb_prepared_statement,
-    ) -> crate::error::Result<()> {
-        let micros = self.num_microseconds().unwrap_or(0);
-        let raw = duckdb_interval { months: 0, days: 0, micros };
-        // SAFETY: `raw` is a valid duckdb_interval; `stmt`/`idx` are valid.
-        unsafe { crate::ffi::duckdb_bind_interval(stmt, idx, raw) };
-        Ok(())
-    }
-}
+impl_chrono_append_able!(NaiveDate, pack_date, duckdb_append_date, duckdb_bind_date, "DATE");
+impl_chrono_append_able!(NaiveTime, pack_time, duckdb_append_time, duckdb_bind_time, "TIME");
+impl_chrono_append_able!(
+    NaiveDateTime,
+    pack_datetime,
+    duckdb_append_timestamp,
+    duckdb_bind_timestamp,
+    "TIMESTAMP"
+);
+impl_chrono_append_able!(
+    Duration,
+    pack_duration,
+    duckdb_append_interval,
+    duckdb_bind_interval,
+    "INTERVAL"
+);
 
 // For `TimestampS`, `TimestampMs`, `TimestampNs`, `TimeTz`, `TimeNs`:
 // no dedicated `duckdb_append_*` / `duckdb_bind_*` function exists, so we go through
@@ -463,6 +437,24 @@ impl AppendAble for TimestampTz {
     }
 }
 
+/// A `DateTime<Utc>` binds and appends as `TIMESTAMP WITH TIME ZONE`, like [`TimestampTz`].
+impl AppendAble for DateTime<Utc> {
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> crate::error::Result<()> {
+        TimestampTz(*self).appender_append(appender)
+    }
+
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> crate::error::Result<()> {
+        TimestampTz(*self).stmt_append(idx, stmt)
+    }
+}
+
 // Tests
 
 #[cfg(test)]
@@ -481,6 +473,12 @@ mod test_chrono_conversion {
         unsafe { duckdb_destroy_value(&mut duck_value) };
     }
 
+    #[test]
+    fn test_duration_overflowing_micros_is_an_error() {
+        use super::*;
+        assert!(Duration::MAX.to_duck().is_err());
+    }
+
     #[test]
     fn test_date_conversion() {
         use super::*;
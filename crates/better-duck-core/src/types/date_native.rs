@@ -182,6 +182,8 @@ impl DuckDialect<duckdb_time_tz> for DuckTimeTz {
 
 // StdDuration (Interval)
 
+/// Reading is lossy: months count as 30 days. Use
+/// [`Interval`](crate::types::interval::Interval) to keep the components apart.
 impl DuckDialect<duckdb_interval> for StdDuration {
     fn from_duck(value: duckdb_interval) -> Result<Self, DuckDBConversionError> {
         let total_days = value.months as u64 * 30 + value.days as u64;
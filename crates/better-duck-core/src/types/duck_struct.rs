@@ -6,7 +6,7 @@
 // FFI pointer arguments are used safely inside `unsafe` blocks.
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::{
     error::{DuckDBConversionError, Error, Result},
@@ -17,7 +17,7 @@ use crate::{
         duckdb_struct_type_child_name, duckdb_struct_vector_get_child, duckdb_value, duckdb_vector,
         duckdb_vector_get_column_type, idx_t,
     },
-    types::appendable::AppendAble,
+    types::appendable::{append_duck_value, bind_duck_value, AppendAble},
 };
 
 use super::value::DuckValue;
@@ -106,7 +106,17 @@ pub(crate) fn read_struct(
 pub(crate) fn struct_to_duck(
     m: &HashMap<String, DuckValue>
 ) -> Result<duckdb_value, DuckDBConversionError> {
-    let entries: Vec<(&String, &DuckValue)> = m.iter().collect();
+    struct_entries_to_duck(&m.iter().collect::<Vec<_>>())
+}
+
+/// Build a `duckdb_value` of type `STRUCT` whose fields are declared in the order of
+/// `entries`.
+///
+/// Returns an error for an empty slice.
+/// The caller is responsible for destroying the returned value.
+pub(crate) fn struct_entries_to_duck(
+    entries: &[(&String, &DuckValue)]
+) -> Result<duckdb_value, DuckDBConversionError> {
     let n = entries.len();
     if n == 0 {
         return Err(DuckDBConversionError::ConversionError(
@@ -116,7 +126,7 @@ pub(crate) fn struct_to_duck(
     let mut member_types: Vec<duckdb_logical_type> = Vec::with_capacity(n);
     let mut c_names: Vec<std::ffi::CString> = Vec::with_capacity(n);
     let mut err: Option<DuckDBConversionError> = None;
-    for (k, v) in &entries {
+    for (k, v) in entries {
         match DuckValue::logical_type_of(v) {
             Ok(lt) => member_types.push(lt),
             Err(e) => {
@@ -151,7 +161,7 @@ pub(crate) fn struct_to_duck(
     }
     let mut member_dvs: Vec<duckdb_value> = Vec::with_capacity(n);
     let mut err: Option<DuckDBConversionError> = None;
-    for (_, v) in &entries {
+    for (_, v) in entries {
         match v.to_duck() {
             Ok(dv) => member_dvs.push(dv),
             Err(e) => {
@@ -265,3 +275,28 @@ impl AppendAble for HashMap<String, DuckValue> {
         Ok(())
     }
 }
+
+/// Bind/append a `BTreeMap<String, DuckValue>` as a DuckDB `STRUCT`.
+///
+/// Unlike the `HashMap` impl, the struct's fields are declared in the map's key order,
+/// so the resulting type is deterministic (see [`DuckValue::struct_from_map`]).
+impl AppendAble for BTreeMap<String, DuckValue> {
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        let dv = struct_entries_to_duck(&self.iter().collect::<Vec<_>>())
+            .map_err(Error::ConversionError)?;
+        bind_duck_value(dv, idx, stmt)
+    }
+
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        let dv = struct_entries_to_duck(&self.iter().collect::<Vec<_>>())
+            .map_err(Error::ConversionError)?;
+        append_duck_value(dv, appender)
+    }
+}
@@ -1,8 +1,24 @@
-//! Ergonomic `From<T>` conversions into `DuckValue`.
+//! Ergonomic `From<T>` conversions into `DuckValue`, and `TryFrom<DuckValue>` back out.
 //!
 //! These impls let you write `DuckValue::from(42i32)` or `"hello".into()` instead
 //! of constructing the enum variant by hand.
 //!
+//! ## Reading values back
+//!
+//! Converting a `DuckValue` into a Rust type can fail, so it goes through `TryFrom`
+//! (for both `DuckValue` and `&DuckValue`) and returns a [`DuckDBConversionError`]:
+//! `NULL` is [`NullValue`](DuckDBConversionError::NullValue), any other variant that
+//! does not fit is a [`ConversionError`](DuckDBConversionError::ConversionError).
+//! Integer targets accept every integer variant whose value is in range, `f64` also
+//! accepts `FLOAT`, and `BigDecimal` (`bigdecimal` feature) accepts both decimal variants.
+//!
+//! ```rust
+//! # use better_duck_core::types::value::DuckValue;
+//! assert_eq!(i64::try_from(DuckValue::Int(7)), Ok(7));
+//! assert!(i64::try_from(DuckValue::text("7")).is_err());
+//! assert!(u8::try_from(&DuckValue::BigInt(300)).is_err());
+//! ```
+//!
 //! ## Note on the read path
 //!
 //! `from_duckdb_vec` is **not** expressed as a `From` impl because it requires three
@@ -12,6 +28,7 @@
 use std::collections::HashMap;
 
 use super::value::DuckValue;
+use crate::error::DuckDBConversionError;
 
 // Primitives
 
@@ -115,6 +132,14 @@ impl From<super::blob::Blob> for DuckValue {
     }
 }
 
+// Interval
+
+impl From<super::interval::Interval> for DuckValue {
+    fn from(i: super::interval::Interval) -> Self {
+        DuckValue::Interval(i)
+    }
+}
+
 // Collections
 
 /// Converts a `Vec<DuckValue>` into `DuckValue::List`.
@@ -177,3 +202,126 @@ impl<T: Into<DuckValue>> From<Option<T>> for DuckValue {
         }
     }
 }
+
+// Fallible conversions out of DuckValue
+
+/// The error for a value that is not `NULL` but cannot become `target`.
+#[cold]
+fn mismatch(
+    value: &DuckValue,
+    target: &str,
+) -> DuckDBConversionError {
+    DuckDBConversionError::ConversionError(format!("cannot convert {value:?} to {target}"))
+}
+
+/// Implements `TryFrom<&DuckValue>` with the given match arms (each yielding
+/// `Option<$t>`, `None` meaning out of range) and derives `TryFrom<DuckValue>` from it.
+macro_rules! try_from_duck_value {
+    ($t:ty, { $($pat:pat => $conv:expr),+ $(,)? }) => {
+        impl TryFrom<&DuckValue> for $t {
+            type Error = DuckDBConversionError;
+
+            #[allow(clippy::useless_conversion)]
+            fn try_from(value: &DuckValue) -> Result<Self, Self::Error> {
+                let converted: Option<$t> = match value {
+                    DuckValue::Null => return Err(DuckDBConversionError::NullValue),
+                    $($pat => $conv,)+
+                    _ => None,
+                };
+                converted.ok_or_else(|| mismatch(value, stringify!($t)))
+            }
+        }
+
+        impl TryFrom<DuckValue> for $t {
+            type Error = DuckDBConversionError;
+
+            fn try_from(value: DuckValue) -> Result<Self, Self::Error> {
+                <$t>::try_from(&value)
+            }
+        }
+    };
+}
+
+/// `try_from_duck_value!` for an integer type: every integer variant is accepted when
+/// its value is in range.
+macro_rules! try_from_duck_integer {
+    ($($t:ty),+) => {$(
+        try_from_duck_value!($t, {
+            DuckValue::TinyInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::SmallInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::Int(v) => <$t>::try_from(*v).ok(),
+            DuckValue::BigInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::HugeInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::UTinyInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::USmallInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::UInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::UBigInt(v) => <$t>::try_from(*v).ok(),
+            DuckValue::UHugeInt(v) => <$t>::try_from(*v).ok(),
+        });
+    )+};
+}
+
+try_from_duck_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+try_from_duck_value!(bool, { DuckValue::Boolean(v) => Some(*v) });
+try_from_duck_value!(f32, { DuckValue::Float(v) => Some(*v) });
+try_from_duck_value!(f64, {
+    DuckValue::Float(v) => Some(f64::from(*v)),
+    DuckValue::Double(v) => Some(*v),
+});
+try_from_duck_value!(String, { DuckValue::Text(v) => Some(v.clone()) });
+try_from_duck_value!(Vec<u8>, { DuckValue::Blob(v) => Some(v.as_bytes().to_vec()) });
+
+#[cfg(all(feature = "bigdecimal", feature = "decimal"))]
+try_from_duck_value!(bigdecimal::BigDecimal, {
+    DuckValue::Decimal(d) => Some(bigdecimal::BigDecimal::new(d.mantissa().into(), d.scale().into())),
+    DuckValue::WideDecimal(d) => Some((*d).into()),
+});
+#[cfg(all(feature = "bigdecimal", not(feature = "decimal")))]
+try_from_duck_value!(bigdecimal::BigDecimal, { DuckValue::WideDecimal(d) => Some((*d).into()) });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_integers_widen_and_check_range() {
+        assert_eq!(i64::try_from(DuckValue::Int(-5)), Ok(-5));
+        assert_eq!(i64::try_from(&DuckValue::UInt(u32::MAX)), Ok(u32::MAX as i64));
+        assert_eq!(u8::try_from(DuckValue::BigInt(255)), Ok(255));
+        assert!(u8::try_from(DuckValue::BigInt(256)).is_err());
+        assert!(u64::try_from(DuckValue::TinyInt(-1)).is_err());
+        assert_eq!(i128::try_from(DuckValue::UBigInt(u64::MAX)), Ok(u64::MAX as i128));
+    }
+
+    #[test]
+    fn test_try_from_mismatch_is_an_error_not_a_panic() {
+        let err = i64::try_from(DuckValue::Text("42".to_owned())).unwrap_err();
+        assert!(
+            matches!(err, DuckDBConversionError::ConversionError(ref m)
+                if m == "cannot convert Text(\"42\") to i64"),
+            "{err:?}"
+        );
+        assert!(i32::try_from(DuckValue::Double(1.0)).is_err());
+        assert!(String::try_from(DuckValue::Int(1)).is_err());
+        assert!(bool::try_from(&DuckValue::Int(1)).is_err());
+        assert!(f32::try_from(DuckValue::Double(1.0)).is_err());
+    }
+
+    #[test]
+    fn test_try_from_null_is_null_value() {
+        assert!(matches!(i64::try_from(DuckValue::Null), Err(DuckDBConversionError::NullValue)));
+        assert!(matches!(
+            String::try_from(&DuckValue::Null),
+            Err(DuckDBConversionError::NullValue)
+        ));
+    }
+
+    #[test]
+    fn test_try_from_other_primitives() {
+        assert_eq!(f64::try_from(DuckValue::Float(1.5)), Ok(1.5));
+        assert_eq!(String::try_from(&DuckValue::text("hi")), Ok("hi".to_owned()));
+        assert_eq!(bool::try_from(DuckValue::Boolean(true)), Ok(true));
+        assert_eq!(Vec::<u8>::try_from(DuckValue::Blob(vec![1, 2].into())), Ok(vec![1, 2]));
+    }
+}
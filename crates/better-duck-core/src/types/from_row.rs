@@ -0,0 +1,59 @@
+use crate::{
+    error::{Error, Result},
+    raw::row::DuckRow,
+};
+
+use super::value::DuckValue;
+
+/// Derives [`FromRow`] for a struct with named fields, reading each field from the column
+/// of the same name (or `#[duck(rename = "...")]`).
+#[cfg(feature = "derive")]
+pub use better_duck_derive::FromRow;
+
+/// Decodes a Rust type from one row of a query result.
+///
+/// Implement this to read rows by column name. Besides calling it on rows from
+/// [`DuckResult`](crate::DuckResult), a prepared statement can decode straight into
+/// `T` with `query_typed`, which reuses one row buffer for the whole result.
+/// [`row_field`] looks a column up with a descriptive error when it is missing.
+/// With the `derive` feature, `#[derive(FromRow)]` writes this implementation.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{error::Result, types::{from_row::{row_field, FromRow}}, DuckRow};
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// impl FromRow for User {
+///     fn from_row(row: &DuckRow) -> Result<Self> {
+///         Ok(User {
+///             id: row_field(row, "id")?.try_into()?,
+///             name: row_field(row, "name")?.try_into()?,
+///         })
+///     }
+/// }
+/// ```
+pub trait FromRow: Sized {
+    /// Builds `Self` from a single result row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required column is missing or has an unexpected type.
+    fn from_row(row: &DuckRow) -> Result<Self>;
+}
+
+/// Returns the value of the column `name`, or [`Error::InvalidColumnName`] if the row
+/// has no such column.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidColumnName`] if `row` has no column called `name`.
+pub fn row_field<'a>(
+    row: &'a DuckRow,
+    name: &str,
+) -> Result<&'a DuckValue> {
+    row.get(name).ok_or_else(|| Error::InvalidColumnName(name.to_owned()))
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use crate::error::{DuckDBConversionError, Error, Result};
+
+use super::value::DuckValue;
+
+/// Decodes a Rust type from the fields of a DuckDB `STRUCT` value.
+///
+/// Implement this for a plain Rust struct to read `STRUCT` values (or a `LIST` of
+/// them, via [`DuckRow::get_struct_list`](crate::DuckRow::get_struct_list)) by field
+/// name. [`struct_field`] looks a field up with a descriptive error when it is missing.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use better_duck_core::{error::Result, types::{from_struct::{struct_field, FromStruct}, value::DuckValue}};
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl FromStruct for Point {
+///     fn from_struct(fields: &HashMap<String, DuckValue>) -> Result<Self> {
+///         Ok(Point {
+///             x: struct_field(fields, "x")?.try_into()?,
+///             y: struct_field(fields, "y")?.try_into()?,
+///         })
+///     }
+/// }
+/// ```
+pub trait FromStruct: Sized {
+    /// Builds `Self` from the field name → value map of one `STRUCT` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field is missing or has an unexpected type.
+    fn from_struct(fields: &HashMap<String, DuckValue>) -> Result<Self>;
+}
+
+/// Returns the value of the field `name`, or a conversion error naming the missing field.
+///
+/// # Errors
+///
+/// Returns [`Error::ConversionError`] if `fields` has no entry called `name`.
+pub fn struct_field<'a>(
+    fields: &'a HashMap<String, DuckValue>,
+    name: &str,
+) -> Result<&'a DuckValue> {
+    fields.get(name).ok_or_else(|| {
+        Error::ConversionError(DuckDBConversionError::ConversionError(format!(
+            "STRUCT has no field named {name}"
+        )))
+    })
+}
+
+/// Decodes every element of a `LIST(STRUCT(..))` value with [`FromStruct`].
+///
+/// # Errors
+///
+/// Returns a conversion error if `value` is not a `LIST`/`ARRAY`, if an element is not a
+/// `STRUCT` (including `NULL` elements), or if `T::from_struct` fails.
+pub(crate) fn struct_list_from_value<T: FromStruct>(value: &DuckValue) -> Result<Vec<T>> {
+    let items = match value {
+        DuckValue::List(items) => &items[..],
+        DuckValue::Array(items) => &items[..],
+        DuckValue::Null => return Err(Error::ConversionError(DuckDBConversionError::NullValue)),
+        other => {
+            return Err(Error::ConversionError(DuckDBConversionError::ConversionError(format!(
+                "expected a LIST of STRUCT, got {other:?}"
+            ))))
+        },
+    };
+    items
+        .iter()
+        .map(|item| match item {
+            DuckValue::Struct(fields) => T::from_struct(fields),
+            other => Err(Error::ConversionError(DuckDBConversionError::ConversionError(format!(
+                "expected a STRUCT list element, got {other:?}"
+            )))),
+        })
+        .collect()
+}
@@ -0,0 +1,219 @@
+use super::*;
+
+use crate::{
+    ffi::{
+        duckdb_append_interval, duckdb_bind_interval, duckdb_create_interval, duckdb_interval,
+        duckdb_value,
+    },
+    types::appendable::check_rc,
+};
+
+/// Microseconds in one day.
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// A DuckDB `INTERVAL`, keeping its months, days and microseconds apart.
+///
+/// DuckDB stores intervals as three independent components because a month has no fixed
+/// number of days (and a day no fixed number of microseconds across DST changes), so
+/// `INTERVAL '1 month'` and `INTERVAL '30 days'` are different values. `Interval`
+/// mirrors that layout (`duckdb_interval`) and round-trips exactly; converting to a
+/// single duration is available as an explicitly lossy helper.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, types::{interval::Interval, value::DuckValue}};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let row = conn.query("SELECT INTERVAL '1 month 2 days' AS iv")?.next().unwrap()?;
+/// assert_eq!(row.get("iv"), Some(&DuckValue::Interval(Interval::new(1, 2, 0))));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Interval {
+    /// Whole months.
+    pub months: i32,
+    /// Whole days, on top of `months`.
+    pub days: i32,
+    /// Microseconds, on top of `months` and `days`.
+    pub micros: i64,
+}
+
+impl Interval {
+    /// Creates an interval from its three components.
+    pub const fn new(
+        months: i32,
+        days: i32,
+        micros: i64,
+    ) -> Interval {
+        Interval { months, days, micros }
+    }
+
+    /// Collapses the interval into a single [`chrono::Duration`].
+    ///
+    /// This is lossy: a month is counted as 30 days and a day as 24 hours, so
+    /// `INTERVAL '1 month'` becomes the same duration as `INTERVAL '30 days'`.
+    #[cfg(feature = "chrono")]
+    pub fn to_duration_lossy(&self) -> chrono::Duration {
+        chrono::Duration::microseconds(self.total_micros_lossy())
+    }
+
+    /// Collapses the interval into a single [`std::time::Duration`].
+    ///
+    /// This is lossy in the same way as counting a month as 30 days and a day as 24
+    /// hours; negative intervals saturate to zero.
+    #[cfg(not(feature = "chrono"))]
+    pub fn to_duration_lossy(&self) -> std::time::Duration {
+        std::time::Duration::from_micros(self.total_micros_lossy().max(0) as u64)
+    }
+
+    fn total_micros_lossy(&self) -> i64 {
+        let total_days = self.months as i64 * 30 + self.days as i64;
+        total_days.saturating_mul(MICROS_PER_DAY).saturating_add(self.micros)
+    }
+
+    fn to_raw(self) -> duckdb_interval {
+        duckdb_interval { months: self.months, days: self.days, micros: self.micros }
+    }
+}
+
+/// A duration becomes a pure-microseconds interval. Sub-microsecond digits are
+/// truncated, and durations beyond `i64` microseconds (about 292,000 years) saturate to
+/// `i64::MIN` or `i64::MAX` micros by sign.
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for Interval {
+    fn from(d: chrono::Duration) -> Self {
+        let micros = d.num_microseconds().unwrap_or(if d < chrono::Duration::zero() {
+            i64::MIN
+        } else {
+            i64::MAX
+        });
+        Interval::new(0, 0, micros)
+    }
+}
+
+/// A duration becomes a pure-microseconds interval, saturating at `i64::MAX` micros.
+#[cfg(not(feature = "chrono"))]
+impl From<std::time::Duration> for Interval {
+    fn from(d: std::time::Duration) -> Self {
+        Interval::new(0, 0, d.as_micros().min(i64::MAX as u128) as i64)
+    }
+}
+
+/// Renders like DuckDB: `1 month 2 days 03:00:00`, with a `00:00:00` time part for
+/// zero intervals.
+impl std::fmt::Display for Interval {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let plural = |n: i64| if n.abs() == 1 { "" } else { "s" };
+        let (years, months) = (self.months / 12, self.months % 12);
+        let mut parts = Vec::new();
+        if years != 0 {
+            parts.push(format!("{years} year{}", plural(years as i64)));
+        }
+        if months != 0 {
+            parts.push(format!("{months} month{}", plural(months as i64)));
+        }
+        if self.days != 0 {
+            parts.push(format!("{} day{}", self.days, plural(self.days as i64)));
+        }
+        if self.micros != 0 || parts.is_empty() {
+            let sign = if self.micros < 0 { "-" } else { "" };
+            let micros = self.micros.unsigned_abs();
+            let secs = micros / 1_000_000;
+            let mut time =
+                format!("{sign}{:02}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60);
+            if !micros.is_multiple_of(1_000_000) {
+                time.push_str(&format!(".{:06}", micros % 1_000_000));
+            }
+            parts.push(time);
+        }
+        f.write_str(&parts.join(" "))
+    }
+}
+
+impl DuckDialect<duckdb_interval> for Interval {
+    fn from_duck(value: duckdb_interval) -> Result<Self, DuckDBConversionError> {
+        Ok(Interval::new(value.months, value.days, value.micros))
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        // SAFETY: any `duckdb_interval` is a plain struct of integers; creation cannot fail.
+        Ok(unsafe { duckdb_create_interval(self.to_raw()) })
+    }
+}
+
+impl AppendAble for Interval {
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        // SAFETY: `appender` is a valid duckdb_appender inside a begin_row/end_row pair.
+        let rc = unsafe { duckdb_append_interval(appender, self.to_raw()) };
+        check_rc(rc, "append INTERVAL value")
+    }
+
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        // SAFETY: `stmt` is a valid prepared statement; `idx` is a 1-based parameter index.
+        let rc = unsafe { duckdb_bind_interval(stmt, idx, self.to_raw()) };
+        check_rc(rc, format_args!("bind INTERVAL to parameter {idx}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{connection::Connection, params, types::value::DuckValue};
+
+    #[test]
+    fn test_interval_display() {
+        assert_eq!(Interval::new(1, 2, 3 * 3_600_000_000).to_string(), "1 month 2 days 03:00:00");
+        assert_eq!(Interval::new(14, 0, 0).to_string(), "1 year 2 months");
+        assert_eq!(Interval::new(0, 0, -1_500_000).to_string(), "-00:00:01.500000");
+        assert_eq!(Interval::default().to_string(), "00:00:00");
+    }
+
+    #[test]
+    fn test_interval_lossy_duration() {
+        let d = Interval::new(1, 0, 0).to_duration_lossy();
+        assert_eq!(d, Interval::new(0, 30, 0).to_duration_lossy());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_interval_from_chrono_duration_saturates_by_sign() {
+        assert_eq!(Interval::from(chrono::Duration::microseconds(-7)).micros, -7);
+        assert_eq!(Interval::from(chrono::Duration::MAX).micros, i64::MAX);
+        assert_eq!(Interval::from(chrono::Duration::MIN).micros, i64::MIN);
+    }
+
+    #[test]
+    fn test_interval_appender_round_trip() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE t (iv INTERVAL)").unwrap();
+        let iv = Interval::new(-3, 40, 5);
+        {
+            let mut appender = conn.appender("t", "main").unwrap();
+            let mut row = iv;
+            appender.append(&mut row).unwrap();
+            appender.save().unwrap();
+        }
+        let v = conn.query_row("SELECT iv FROM t", |row| Ok(row.get("iv").cloned())).unwrap();
+        assert_eq!(v, Some(DuckValue::Interval(iv)));
+
+        let same = conn
+            .execute_with("SELECT count(*) AS n FROM t WHERE iv = ?", params![iv])
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(same.get("n"), Some(&DuckValue::BigInt(1)));
+    }
+}
@@ -0,0 +1,155 @@
+//! [`Json`] — JSON text for DuckDB `JSON` columns, checked for well-formedness before
+//! it is sent.
+//!
+//! DuckDB stores `JSON` as `VARCHAR` text, so a `Json` binds and appends through the
+//! VARCHAR path and reads back as the stored text. Malformed input would otherwise only
+//! fail inside DuckDB's cast, or be stored as-is in a plain `VARCHAR` column.
+//! Validation uses `serde_json`, so this module needs the `json` feature.
+
+use crate::{
+    error::{Error, Result},
+    ffi::duckdb_value,
+    types::{
+        appendable::AppendAble,
+        varchar::{append_str, bind_str, str_to_duck},
+        DuckDBConversionError, DuckDialect,
+    },
+};
+
+/// Well-formed JSON text, e.g. `Json::new(r#"{"a":1}"#)`.
+///
+/// The text is validated by [`validate`](Json::validate) each time it is bound or
+/// appended, and by [`Json::parse`]; [`Json::new`] does not check it.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, types::json::Json};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// conn.execute_batch("CREATE TABLE docs (body VARCHAR)")?;
+/// let mut appender = conn.appender("docs", "main")?;
+/// appender.append(&mut Json::new(r#"{"a": [1, 2.5e3, null]}"#))?;
+/// assert!(appender.append(&mut Json::new("{a: 1}")).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Json(pub String);
+
+impl Json {
+    /// Wraps `text` without validating it.
+    pub fn new(text: impl Into<String>) -> Json {
+        Json(text.into())
+    }
+
+    /// Wraps `text` after checking that it is well-formed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DuckDBConversionError::ConversionError`] describing the first syntax
+    /// error.
+    pub fn parse(text: impl Into<String>) -> Result<Json, DuckDBConversionError> {
+        let json = Json(text.into());
+        json.validate()?;
+        Ok(json)
+    }
+
+    /// Returns the JSON text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Checks that the text is a single well-formed JSON value (RFC 8259), surrounded
+    /// by optional whitespace. Nesting deeper than 128 levels is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DuckDBConversionError::ConversionError`] with the line and column of
+    /// the first syntax error.
+    pub fn validate(&self) -> Result<(), DuckDBConversionError> {
+        serde_json::from_str::<serde::de::IgnoredAny>(&self.0)
+            .map(|_| ())
+            .map_err(|e| DuckDBConversionError::ConversionError(format!("invalid JSON: {e}")))
+    }
+}
+
+impl From<Json> for String {
+    fn from(json: Json) -> String {
+        json.0
+    }
+}
+
+impl DuckDialect for Json {
+    /// Reads the stored text as-is, without validating it: a `JSON` column only holds
+    /// JSON, but a `VARCHAR` column may hold anything.
+    fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
+        String::from_duck(value).map(Json)
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        self.validate()?;
+        str_to_duck(&self.0)
+    }
+}
+
+impl AppendAble for Json {
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        self.validate().map_err(Error::ConversionError)?;
+        append_str(&self.0, appender)
+    }
+
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        self.validate().map_err(Error::ConversionError)?;
+        bind_str(&self.0, idx, stmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_accepts_well_formed_json() {
+        for text in [
+            r#"{"a":1}"#,
+            " [1, -2.5, 3e10, 0.1E-2, true, false, null] ",
+            r#"{"nested": {"list": [{}, []]}, "s": "tab\té \" \\ \/"}"#,
+            r#""just a string""#,
+            "0",
+        ] {
+            assert!(Json::parse(text).is_ok(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_json() {
+        for text in [
+            "",
+            "{a: 1}",
+            r#"{"a":1,}"#,
+            "[1 2]",
+            "01",
+            "1.",
+            "-",
+            r#""unterminated"#,
+            r#""bad \x escape""#,
+            "[1] [2]",
+            "nul",
+            "{\"a\":\"line\nbreak\"}",
+        ] {
+            assert!(
+                matches!(Json::parse(text), Err(DuckDBConversionError::ConversionError(_))),
+                "{text:?}"
+            );
+        }
+        assert!(Json::new("[".repeat(200)).validate().is_err());
+    }
+}
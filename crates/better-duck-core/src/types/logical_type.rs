@@ -0,0 +1,194 @@
+//! [`LogicalType`] — an owned DuckDB logical type with its parameters.
+//!
+//! A `DUCKDB_TYPE` only names the kind of a column. The logical type also carries what
+//! the kind is parameterized with: the width and scale of a `DECIMAL`, the element type
+//! of a `LIST` or `ARRAY`, the members of an `ENUM`, the fields of a `STRUCT`.
+
+use std::{
+    ffi::{c_void, CStr},
+    fmt,
+    os::raw::c_char,
+};
+
+use crate::{
+    error::{DuckDBConversionError, Error, Result},
+    ffi::{
+        duckdb_array_type_array_size, duckdb_array_type_child_type, duckdb_decimal_scale,
+        duckdb_decimal_width, duckdb_destroy_logical_type, duckdb_enum_dictionary_size,
+        duckdb_enum_dictionary_value, duckdb_free, duckdb_get_type_id, duckdb_list_type_child_type,
+        duckdb_logical_type, duckdb_map_type_key_type, duckdb_map_type_value_type,
+        duckdb_struct_type_child_count, duckdb_struct_type_child_name,
+        duckdb_struct_type_child_type, DUCKDB_TYPE, DUCKDB_TYPE_DUCKDB_TYPE_ARRAY,
+        DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL, DUCKDB_TYPE_DUCKDB_TYPE_ENUM,
+        DUCKDB_TYPE_DUCKDB_TYPE_LIST, DUCKDB_TYPE_DUCKDB_TYPE_MAP, DUCKDB_TYPE_DUCKDB_TYPE_STRUCT,
+    },
+};
+
+/// An owned DuckDB logical type, destroyed on drop.
+///
+/// Obtained from [`DuckResult::column_logical_type`](crate::DuckResult::column_logical_type).
+/// The accessors for a parameter return `None` when the type is of a different kind,
+/// e.g. [`decimal_scale`](LogicalType::decimal_scale) on an `INTEGER`.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::connection::Connection;
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let result = conn.query("SELECT 1.5::DECIMAL(10, 3) AS d, [1, 2] AS l")?;
+/// assert_eq!(result.column_logical_type(0)?.decimal_scale(), Some(3));
+/// assert!(result.column_logical_type(1)?.list_child().is_some());
+/// # Ok(())
+/// # }
+/// ```
+pub struct LogicalType(duckdb_logical_type);
+
+impl LogicalType {
+    /// Takes ownership of `lt`, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// A non-null `lt` must be a valid logical type that nothing else destroys.
+    pub(crate) unsafe fn from_raw(lt: duckdb_logical_type) -> Option<LogicalType> {
+        (!lt.is_null()).then_some(LogicalType(lt))
+    }
+
+    /// Takes ownership of a child type returned by DuckDB for `self`.
+    fn child(
+        &self,
+        get: unsafe extern "C" fn(duckdb_logical_type) -> duckdb_logical_type,
+    ) -> Option<LogicalType> {
+        // SAFETY: `self.0` is valid and `get` is one of the child-type getters, which
+        // return a new logical type (or null) owned by the caller.
+        unsafe { LogicalType::from_raw(get(self.0)) }
+    }
+
+    /// Returns the kind of this type, e.g. `DUCKDB_TYPE_DECIMAL`.
+    pub fn type_id(&self) -> DUCKDB_TYPE {
+        // SAFETY: `self.0` is a valid logical type.
+        unsafe { duckdb_get_type_id(self.0) }
+    }
+
+    /// Returns the width (total number of digits) of a `DECIMAL` type.
+    pub fn decimal_width(&self) -> Option<u8> {
+        // SAFETY: `self.0` is a valid DECIMAL logical type.
+        (self.type_id() == DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL)
+            .then(|| unsafe { duckdb_decimal_width(self.0) })
+    }
+
+    /// Returns the scale (digits after the decimal point) of a `DECIMAL` type.
+    pub fn decimal_scale(&self) -> Option<u8> {
+        // SAFETY: `self.0` is a valid DECIMAL logical type.
+        (self.type_id() == DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL)
+            .then(|| unsafe { duckdb_decimal_scale(self.0) })
+    }
+
+    /// Returns the element type of a `LIST` type.
+    pub fn list_child(&self) -> Option<LogicalType> {
+        if self.type_id() != DUCKDB_TYPE_DUCKDB_TYPE_LIST {
+            return None;
+        }
+        self.child(duckdb_list_type_child_type)
+    }
+
+    /// Returns the element type of an `ARRAY` type.
+    pub fn array_child(&self) -> Option<LogicalType> {
+        if self.type_id() != DUCKDB_TYPE_DUCKDB_TYPE_ARRAY {
+            return None;
+        }
+        self.child(duckdb_array_type_child_type)
+    }
+
+    /// Returns the fixed number of elements of an `ARRAY` type.
+    pub fn array_size(&self) -> Option<u64> {
+        // SAFETY: `self.0` is a valid ARRAY logical type.
+        (self.type_id() == DUCKDB_TYPE_DUCKDB_TYPE_ARRAY)
+            .then(|| unsafe { duckdb_array_type_array_size(self.0) })
+    }
+
+    /// Returns the key and value types of a `MAP` type.
+    pub fn map_key_value(&self) -> Option<(LogicalType, LogicalType)> {
+        if self.type_id() != DUCKDB_TYPE_DUCKDB_TYPE_MAP {
+            return None;
+        }
+        Some((self.child(duckdb_map_type_key_type)?, self.child(duckdb_map_type_value_type)?))
+    }
+
+    /// Returns the members of an `ENUM` type, in dictionary order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a member is not valid UTF-8.
+    pub fn enum_values(&self) -> Result<Option<Vec<String>>> {
+        if self.type_id() != DUCKDB_TYPE_DUCKDB_TYPE_ENUM {
+            return Ok(None);
+        }
+        // SAFETY: `self.0` is a valid ENUM logical type.
+        let size = unsafe { duckdb_enum_dictionary_size(self.0) };
+        (0..size)
+            // SAFETY: `self.0` is a valid ENUM logical type and `i < size`; the returned
+            // string is owned by the caller.
+            .map(|i| take_c_string(unsafe { duckdb_enum_dictionary_value(self.0, i as u64) }))
+            .collect::<Result<_>>()
+            .map(Some)
+    }
+
+    /// Returns the field names and types of a `STRUCT` type, in declaration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field name is not valid UTF-8.
+    pub fn struct_fields(&self) -> Result<Option<Vec<(String, LogicalType)>>> {
+        if self.type_id() != DUCKDB_TYPE_DUCKDB_TYPE_STRUCT {
+            return Ok(None);
+        }
+        // SAFETY: `self.0` is a valid STRUCT logical type.
+        let count = unsafe { duckdb_struct_type_child_count(self.0) };
+        (0..count)
+            .map(|i| {
+                // SAFETY: `self.0` is a valid STRUCT logical type and `i < count`; the
+                // returned name and type are owned by the caller.
+                let name = take_c_string(unsafe { duckdb_struct_type_child_name(self.0, i) })?;
+                // SAFETY: as above.
+                let child =
+                    unsafe { LogicalType::from_raw(duckdb_struct_type_child_type(self.0, i)) }
+                        .ok_or_else(|| {
+                            Error::ConversionError(DuckDBConversionError::ConversionError(format!(
+                                "struct field {name} has no type"
+                            )))
+                        })?;
+                Ok((name, child))
+            })
+            .collect::<Result<_>>()
+            .map(Some)
+    }
+}
+
+/// Copies and frees a string that DuckDB allocated for the caller.
+fn take_c_string(ptr: *mut c_char) -> Result<String> {
+    if ptr.is_null() {
+        return Err(Error::ConversionError(DuckDBConversionError::NullValue));
+    }
+    // SAFETY: `ptr` is a non-null, null-terminated C string.
+    let s = unsafe { CStr::from_ptr(ptr) }.to_str().map(str::to_owned);
+    // SAFETY: `ptr` was allocated by DuckDB for the caller and is freed exactly once.
+    unsafe { duckdb_free(ptr as *mut c_void) };
+    Ok(s?)
+}
+
+impl fmt::Debug for LogicalType {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_tuple("LogicalType").field(&self.type_id()).finish()
+    }
+}
+
+impl Drop for LogicalType {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` is a valid logical type owned by `self`; destroyed exactly once.
+        unsafe { duckdb_destroy_logical_type(&mut self.0) };
+    }
+}
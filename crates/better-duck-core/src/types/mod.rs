@@ -22,10 +22,29 @@ pub mod date_native;
 pub mod duck_struct;
 /// Ergonomic `From<T>` conversions into `DuckValue`.
 pub mod from_impls;
+/// [`from_row::FromRow`] — decode result rows into Rust types by column name.
+pub mod from_row;
+/// [`from_struct::FromStruct`] — decode `STRUCT` values into Rust types by field name.
+pub mod from_struct;
+/// `INTERVAL` as separate months/days/micros — [`interval::Interval`], feature-independent.
+pub mod interval;
+/// [`json::Json`] — JSON text, validated before it is bound or appended (feature: `json`).
+#[cfg(feature = "json")]
+pub mod json;
+/// [`LogicalType`] — a column's full logical type (decimal scale, child types, ...).
+pub mod logical_type;
+pub use logical_type::LogicalType;
 /// MAP read/write helpers + `AppendAble` impl for `HashMap<DuckValue, DuckValue>`.
 pub mod map;
+/// `Option<T>` conversions — `None` binds, appends and reads as SQL `NULL`.
+pub mod nullable;
 /// Numeric DuckDB type conversions and `AppendAble` implementations.
 pub mod numeric;
+/// `serde::Serialize` for `DuckValue` and `DuckRow` (feature: `serde`).
+#[cfg(feature = "serde")]
+pub mod serialize;
+/// `TIME` as microseconds since midnight — [`time_micros::TimeMicros`], feature-independent.
+pub mod time_micros;
 /// UNION read/write helpers.
 pub mod union;
 /// The `DuckValue` enum representing any DuckDB column value.
@@ -40,7 +59,9 @@ use crate::error::Result;
 use crate::ffi::duckdb_bind_boolean;
 use appendable::AppendAble;
 
-use crate::ffi::{duckdb_append_bool, duckdb_create_bool, duckdb_get_bool, duckdb_value};
+use crate::ffi::{
+    duckdb_append_bool, duckdb_create_bool, duckdb_get_bool, duckdb_value, DUCKDB_TYPE,
+};
 
 /// Trait for converting between DuckDB values and Rust types.
 ///
@@ -116,6 +137,33 @@ where
     /// unsafe { duckdb_destroy_value(&mut duckdb_value) };
     /// ```
     fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError>;
+
+    /// Returns what a SQL `NULL` converts to.
+    ///
+    /// Readers that see a `NULL` cell call this instead of [`from_duck`](Self::from_duck).
+    /// The default rejects it with [`DuckDBConversionError::NullValue`]; `Option<T>`
+    /// returns `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DuckDBConversionError::NullValue`] unless the type can represent `NULL`.
+    fn from_null() -> Result<Self, DuckDBConversionError> {
+        Err(DuckDBConversionError::NullValue)
+    }
+}
+
+/// A primitive type stored unboxed in DuckDB chunk vectors, one `Self` per row.
+///
+/// Used by [`DuckResult::column_chunk`](crate::DuckResult::column_chunk) to read a whole
+/// column vector at once instead of converting it value by value.
+///
+/// # Safety
+///
+/// The data buffer of a vector whose logical type is [`DUCK_TYPE`](Self::DUCK_TYPE) must
+/// be a packed array of valid `Self` values.
+pub unsafe trait VectorPrimitive: DuckDialect + Copy {
+    /// The DuckDB column type whose vectors hold `Self` values.
+    const DUCK_TYPE: DUCKDB_TYPE;
 }
 
 macro_rules! impl_duck_append_able {
@@ -144,7 +192,7 @@ macro_rules! impl_duck_append_able {
     };
 }
 macro_rules! impl_duck_dialect {
-    ($rust_type:ty, $duck_type:expr, $to_duck_fn:expr, $from_duck_fn:expr) => {
+    ($rust_type:ty, $duck_type:ident, $to_duck_fn:expr, $from_duck_fn:expr) => {
         impl DuckDialect for $rust_type {
             fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
                 // SAFETY: `value` is a valid duckdb_value of the matching DuckDB type.
@@ -156,6 +204,11 @@ macro_rules! impl_duck_dialect {
                 Ok(unsafe { $to_duck_fn(*self) })
             }
         }
+
+        // SAFETY: DuckDB stores vectors of this column type as packed arrays of the primitive.
+        unsafe impl VectorPrimitive for $rust_type {
+            const DUCK_TYPE: DUCKDB_TYPE = crate::ffi::$duck_type;
+        }
     };
 }
 
@@ -0,0 +1,129 @@
+use super::*;
+
+use crate::{
+    ffi::{duckdb_append_null, duckdb_bind_null, duckdb_create_null_value, duckdb_is_null_value},
+    types::appendable::check_rc,
+};
+
+/// `None` binds/appends SQL `NULL`; `Some(v)` delegates to `v`.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, params, types::value::DuckValue};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let missing: Option<String> = None;
+/// let row = conn.execute_with("SELECT ?::VARCHAR AS name", params![missing])?.next().unwrap()?;
+/// assert_eq!(row.get("name"), Some(&DuckValue::Null));
+/// # Ok(())
+/// # }
+/// ```
+impl<T: AppendAble> AppendAble for Option<T> {
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        let Some(value) = self else {
+            // SAFETY: `stmt` is a valid prepared statement; `idx` is a 1-based parameter index.
+            let rc = unsafe { duckdb_bind_null(stmt, idx) };
+            return check_rc(rc, format_args!("bind NULL to parameter {idx}"));
+        };
+        value.stmt_append(idx, stmt)
+    }
+
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        let Some(value) = self else {
+            // SAFETY: `appender` is a valid duckdb_appender inside a begin_row/end_row pair.
+            let rc = unsafe { duckdb_append_null(appender) };
+            return check_rc(rc, "append NULL");
+        };
+        value.appender_append(appender)
+    }
+}
+
+/// A SQL `NULL` (or a null handle) reads as `None`; anything else goes through `T`.
+impl<T: DuckDialect> DuckDialect for Option<T> {
+    fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
+        // SAFETY: `value` is either null or a valid duckdb_value owned by the caller.
+        if value.is_null() || unsafe { duckdb_is_null_value(value) } {
+            return Ok(None);
+        }
+        T::from_duck(value).map(Some)
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        match self {
+            Some(v) => v.to_duck(),
+            // SAFETY: duckdb_create_null_value always succeeds.
+            None => Ok(unsafe { duckdb_create_null_value() }),
+        }
+    }
+
+    fn from_null() -> Result<Self, DuckDBConversionError> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        connection::Connection, ffi::duckdb_destroy_value, params, types::value::DuckValue,
+    };
+
+    #[test]
+    fn test_option_round_trip_through_duck_value() {
+        let mut null = Option::<i32>::None.to_duck().unwrap();
+        assert_eq!(Option::<i32>::from_duck(null).unwrap(), None);
+        // SAFETY: `null` was created by `to_duck()` above; destroy once.
+        unsafe { duckdb_destroy_value(&mut null) };
+
+        let mut seven = Some(7i32).to_duck().unwrap();
+        assert_eq!(Option::<i32>::from_duck(seven).unwrap(), Some(7));
+        // SAFETY: `seven` was created by `to_duck()` above; destroy once.
+        unsafe { duckdb_destroy_value(&mut seven) };
+    }
+
+    #[test]
+    fn test_bind_null_name() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE people (id INTEGER, name VARCHAR)").unwrap();
+        let missing: Option<String> = None;
+        let present = Some("Ada".to_string());
+        conn.execute_with("INSERT INTO people VALUES (1, ?)", params![missing]).unwrap();
+        conn.execute_with("INSERT INTO people VALUES (2, ?)", params![present]).unwrap();
+
+        let rows = conn
+            .query("SELECT name FROM people ORDER BY id")
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rows[0].get("name"), Some(&DuckValue::Null));
+        assert_eq!(rows[1].get("name"), Some(&DuckValue::text("Ada")));
+    }
+
+    #[test]
+    fn test_append_null_name() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE people (name VARCHAR)").unwrap();
+        {
+            let mut appender = conn.appender("people", "main").unwrap();
+            appender.append(&mut Option::<String>::None).unwrap();
+            appender.append(&mut Some("Ada".to_string())).unwrap();
+            appender.save().unwrap();
+        }
+
+        let rows = conn
+            .query("SELECT name FROM people ORDER BY name NULLS FIRST")
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rows[0].get("name"), Some(&DuckValue::Null));
+        assert_eq!(rows[1].get("name"), Some(&DuckValue::text("Ada")));
+    }
+}
@@ -2,34 +2,39 @@
 // by design. Implementations are responsible for passing valid pointers.
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use super::{DuckDBConversionError, DuckDialect};
+use super::{DuckDBConversionError, DuckDialect, VectorPrimitive};
 use crate::{
     error::{Error, Result},
     ffi::{
         duckdb_create_decimal, duckdb_create_double, duckdb_create_float, duckdb_create_hugeint,
         duckdb_create_int16, duckdb_create_int32, duckdb_create_int64, duckdb_create_int8,
-        duckdb_create_uint16, duckdb_create_uint32, duckdb_create_uint64, duckdb_create_uint8,
-        duckdb_decimal, duckdb_get_decimal, duckdb_get_double, duckdb_get_float, duckdb_get_int16,
-        duckdb_get_int32, duckdb_get_int64, duckdb_get_int8, duckdb_get_uint16, duckdb_get_uint32,
-        duckdb_get_uint64, duckdb_get_uint8, duckdb_hugeint, duckdb_value,
+        duckdb_create_uhugeint, duckdb_create_uint16, duckdb_create_uint32, duckdb_create_uint64,
+        duckdb_create_uint8, duckdb_decimal, duckdb_get_decimal, duckdb_get_double,
+        duckdb_get_float, duckdb_get_int16, duckdb_get_int32, duckdb_get_int64, duckdb_get_int8,
+        duckdb_get_uint16, duckdb_get_uint32, duckdb_get_uint64, duckdb_get_uint8, duckdb_hugeint,
+        duckdb_uhugeint, duckdb_value,
     },
-    types::appendable::AppendAble,
+    types::{appendable::AppendAble, value::DuckValue},
 };
 
 use crate::ffi::{
     duckdb_append_double, duckdb_append_float, duckdb_append_hugeint, duckdb_append_int16,
-    duckdb_append_int32, duckdb_append_int64, duckdb_append_int8, duckdb_append_uint16,
-    duckdb_append_uint32, duckdb_append_uint64, duckdb_append_uint8, duckdb_bind_double,
-    duckdb_bind_float, duckdb_bind_hugeint, duckdb_bind_int16, duckdb_bind_int32,
-    duckdb_bind_int64, duckdb_bind_int8, duckdb_bind_uint16, duckdb_bind_uint32,
-    duckdb_bind_uint64, duckdb_bind_uint8,
+    duckdb_append_int32, duckdb_append_int64, duckdb_append_int8, duckdb_append_uhugeint,
+    duckdb_append_uint16, duckdb_append_uint32, duckdb_append_uint64, duckdb_append_uint8,
+    duckdb_bind_double, duckdb_bind_float, duckdb_bind_hugeint, duckdb_bind_int16,
+    duckdb_bind_int32, duckdb_bind_int64, duckdb_bind_int8, duckdb_bind_uhugeint,
+    duckdb_bind_uint16, duckdb_bind_uint32, duckdb_bind_uint64, duckdb_bind_uint8,
 };
 #[cfg(feature = "decimal")]
+use crate::types::appendable::check_rc;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::{num_bigint::BigInt, BigDecimal};
+#[cfg(feature = "decimal")]
 use rust_decimal::Decimal;
 
 // Macro to implement DuckDialect for primitive numeric types.
 macro_rules! impl_duck_dialect {
-    ($rust_type:ty, $duck_type:expr, $to_duck_fn:expr, $from_duck_fn:expr) => {
+    ($rust_type:ty, $duck_type:ident, $to_duck_fn:expr, $from_duck_fn:expr) => {
         impl DuckDialect for $rust_type {
             fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
                 // SAFETY: `value` is a valid duckdb_value of the matching DuckDB type.
@@ -43,6 +48,11 @@ macro_rules! impl_duck_dialect {
                 Ok(unsafe { $to_duck_fn(*self) })
             }
         }
+
+        // SAFETY: DuckDB stores vectors of this column type as packed arrays of the primitive.
+        unsafe impl VectorPrimitive for $rust_type {
+            const DUCK_TYPE: crate::ffi::DUCKDB_TYPE = crate::ffi::$duck_type;
+        }
     };
 }
 
@@ -150,6 +160,50 @@ impl AppendAble for i128 {
     }
 }
 
+/// Decode a DuckDB UHUGEINT into a [`u128`]: `value = upper * 2^64 + lower`.
+fn u128_from_uhugeint(uhugeint: duckdb_uhugeint) -> u128 {
+    (uhugeint.upper as u128) << 64 | (uhugeint.lower as u128)
+}
+
+/// Encode a [`u128`] as a DuckDB UHUGEINT. The full `u128` range is supported.
+fn uhugeint_from_u128(value: u128) -> duckdb_uhugeint {
+    duckdb_uhugeint { upper: (value >> 64) as u64, lower: value as u64 }
+}
+
+impl DuckDialect<duckdb_uhugeint> for u128 {
+    fn from_duck(uhugeint: duckdb_uhugeint) -> Result<Self, DuckDBConversionError> {
+        Ok(u128_from_uhugeint(uhugeint))
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        // SAFETY: `uhugeint_from_u128` converts any u128 to a valid duckdb_uhugeint.
+        Ok(unsafe { duckdb_create_uhugeint(uhugeint_from_u128(*self)) })
+    }
+}
+
+impl AppendAble for u128 {
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        // SAFETY: `appender` is a valid duckdb_appender. `uhugeint_from_u128` converts the
+        // value to a valid duckdb_uhugeint.
+        unsafe { duckdb_append_uhugeint(appender, uhugeint_from_u128(*self)) };
+        Ok(())
+    }
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        // SAFETY: `stmt` is a valid prepared statement. `idx` is a 1-based parameter index
+        // within the statement's parameter count (as required by the DuckDB C API).
+        // `uhugeint_from_u128` converts the value to a valid duckdb_uhugeint.
+        unsafe { duckdb_bind_uhugeint(stmt, idx, uhugeint_from_u128(*self)) };
+        Ok(())
+    }
+}
+
 #[cfg(feature = "decimal")]
 impl DuckDialect for Decimal {
     fn from_duck(value: duckdb_value) -> Result<Self, super::DuckDBConversionError>
@@ -162,9 +216,13 @@ impl DuckDialect for Decimal {
         let scale = decimal_value.scale;
         // TODO: surface decimal_value.width (precision) for callers that need it
 
-        let decimal =
-            Decimal::from_i128_with_scale(i128_from_hugeint(decimal_value.value), scale as u32);
-        Ok(decimal)
+        let unscaled = i128_from_hugeint(decimal_value.value);
+        Decimal::try_from_i128_with_scale(unscaled, scale as u32).map_err(|_| {
+            super::DuckDBConversionError::PrecisionLoss(format!(
+                "{} does not fit a Decimal",
+                WideDecimal { value: unscaled, scale }
+            ))
+        })
     }
 
     fn to_duck(&self) -> Result<duckdb_value, super::DuckDBConversionError> {
@@ -193,6 +251,10 @@ impl DuckDialect for Decimal {
 
 #[cfg(feature = "decimal")]
 impl AppendAble for Decimal {
+    /// Appends the decimal through the generic `duckdb_value` path.
+    ///
+    /// The value is created with the minimal width/scale computed by
+    /// [`to_duck`](DuckDialect::to_duck); DuckDB casts it to the column's `DECIMAL(w, s)`.
     fn appender_append(
         &mut self,
         appender: crate::ffi::duckdb_appender,
@@ -200,12 +262,13 @@ impl AppendAble for Decimal {
         use crate::types::DuckDialect as _;
         let mut dv = self.to_duck().map_err(Error::ConversionError)?;
         // SAFETY: `appender` is valid; `dv` was just created by `to_duck()`.
-        unsafe { crate::ffi::duckdb_append_value(appender, dv) };
+        let rc = unsafe { crate::ffi::duckdb_append_value(appender, dv) };
         // SAFETY: `dv` was created above; destroy exactly once.
         unsafe { crate::ffi::duckdb_destroy_value(&mut dv) };
-        Ok(())
+        check_rc(rc, format_args!("append decimal {self}"))
     }
 
+    /// Binds the decimal through the generic `duckdb_value` path.
     fn stmt_append(
         &mut self,
         idx: u64,
@@ -214,17 +277,310 @@ impl AppendAble for Decimal {
         use crate::types::DuckDialect as _;
         let mut dv = self.to_duck().map_err(Error::ConversionError)?;
         // SAFETY: `stmt` is valid; `dv` was just created by `to_duck()`.
-        unsafe { crate::ffi::duckdb_bind_value(stmt, idx, dv) };
+        let rc = unsafe { crate::ffi::duckdb_bind_value(stmt, idx, dv) };
         // SAFETY: `dv` was created above; destroy exactly once.
         unsafe { crate::ffi::duckdb_destroy_value(&mut dv) };
-        Ok(())
+        check_rc(rc, format_args!("bind decimal {self} at parameter {idx}"))
+    }
+}
+
+/// A [`Decimal`] bound or appended with an explicit `DECIMAL(precision, scale)` type.
+///
+/// A bare `Decimal` is sent with the narrowest width and scale that hold it, so DuckDB
+/// picks the parameter type from the value. `DecimalParam` sends exactly the requested
+/// type: the value is rescaled to `scale`, rounding half away from zero like a DuckDB
+/// cast, and must then fit in `precision` digits.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{
+/// #     connection::Connection, types::numeric::DecimalParam, types::value::DuckValue,
+/// # };
+/// # use rust_decimal::Decimal;
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let conn = Connection::open_in_memory()?;
+/// let mut stmt = conn.db().prepare("SELECT ?::VARCHAR AS d")?;
+/// stmt.bind(&mut DecimalParam { value: Decimal::new(125, 1), precision: 10, scale: 4 })?;
+/// let row = stmt.execute()?.next().unwrap()?;
+/// assert_eq!(row.get("d"), Some(&DuckValue::text("12.5000")));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalParam {
+    /// The value to send.
+    pub value: Decimal,
+    /// Total number of digits, 1 to 38.
+    pub precision: u8,
+    /// Digits after the decimal point, at most `precision`.
+    pub scale: u8,
+}
+
+#[cfg(feature = "decimal")]
+impl DecimalParam {
+    /// Returns the value as an integer scaled by `10^scale`.
+    fn unscaled(&self) -> Result<i128, DuckDBConversionError> {
+        let out_of_range = || {
+            DuckDBConversionError::PrecisionLoss(format!(
+                "{} does not fit DECIMAL({}, {})",
+                self.value, self.precision, self.scale
+            ))
+        };
+        if !(1..=38).contains(&self.precision) || self.scale > self.precision {
+            return Err(DuckDBConversionError::ConversionError(format!(
+                "DECIMAL({}, {}) is not a valid type",
+                self.precision, self.scale
+            )));
+        }
+        let (mantissa, from, to) = (self.value.mantissa(), self.value.scale(), self.scale as u32);
+        let unscaled = if from > to {
+            let divisor = 10i128.pow(from - to);
+            let (quotient, remainder) = (mantissa / divisor, mantissa % divisor);
+            quotient + (remainder.abs() * 2 >= divisor) as i128 * mantissa.signum()
+        } else {
+            mantissa.checked_mul(10i128.pow(to - from)).ok_or_else(out_of_range)?
+        };
+        if unscaled.unsigned_abs() >= 10u128.pow(self.precision as u32) {
+            return Err(out_of_range());
+        }
+        Ok(unscaled)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl DuckDialect for DecimalParam {
+    fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
+        // SAFETY: `value` is a valid duckdb_value of type DECIMAL.
+        let decimal = unsafe { duckdb_get_decimal(value) };
+        Ok(DecimalParam {
+            value: Decimal::from_i128_with_scale(
+                i128_from_hugeint(decimal.value),
+                decimal.scale as u32,
+            ),
+            precision: decimal.width,
+            scale: decimal.scale,
+        })
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        let val = duckdb_decimal {
+            width: self.precision,
+            scale: self.scale,
+            value: hugeint_from_i128(self.unscaled()?),
+        };
+        // SAFETY: `val` is a fully initialized `duckdb_decimal` whose value fits its
+        // validated width and scale.
+        Ok(unsafe { duckdb_create_decimal(val) })
     }
 }
 
+#[cfg(feature = "decimal")]
+crate::impl_appendable_via_to_duck_native!(DecimalParam);
+
+/// A `DECIMAL` value of up to 38 digits, kept as its unscaled integer.
+///
+/// A `rust_decimal::Decimal` holds a 96-bit unscaled value, so it cannot represent every
+/// `DECIMAL(38, s)`. `WideDecimal` covers DuckDB's whole decimal
+/// range and needs no feature: columns wider than 28 digits read as
+/// [`DuckValue::WideDecimal`], as does every `DECIMAL` when the `decimal` feature is off.
+/// With the `bigdecimal` feature it converts to and from a `BigDecimal`. It is bound and appended as
+/// `DECIMAL(38, scale)`, which DuckDB casts to the target column's type.
+///
+/// [`DuckValue::WideDecimal`]: crate::types::value::DuckValue::WideDecimal
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{
+/// #     connection::Connection, types::numeric::WideDecimal, types::value::DuckValue,
+/// # };
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let conn = Connection::open_in_memory()?;
+/// let wide = WideDecimal { value: 12_345_678_901_234_567_890_123_456_789_012_345_678, scale: 10 };
+/// assert_eq!(wide.to_string(), "1234567890123456789012345678.9012345678");
+/// let mut stmt = conn.db().prepare("SELECT ?::DECIMAL(38, 10) AS d")?;
+/// stmt.bind(&mut wide.clone())?;
+/// let row = stmt.execute()?.next().unwrap()?;
+/// assert_eq!(row.get("d"), Some(&DuckValue::WideDecimal(wide)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WideDecimal {
+    /// The value times `10^scale`; at most 38 digits.
+    pub value: i128,
+    /// Digits after the decimal point, at most 38.
+    pub scale: u8,
+}
+
+impl std::fmt::Display for WideDecimal {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.value.unsigned_abs(), width = scale + 1);
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        let sign = if self.value < 0 { "-" } else { "" };
+        if frac.is_empty() {
+            write!(f, "{sign}{int}")
+        } else {
+            write!(f, "{sign}{int}.{frac}")
+        }
+    }
+}
+
+impl DuckDialect for WideDecimal {
+    fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
+        // SAFETY: `value` is a valid duckdb_value of type DECIMAL.
+        let decimal = unsafe { duckdb_get_decimal(value) };
+        Ok(WideDecimal { value: i128_from_hugeint(decimal.value), scale: decimal.scale })
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        if self.scale > 38 || self.value.unsigned_abs() >= 10u128.pow(38) {
+            return Err(DuckDBConversionError::PrecisionLoss(format!(
+                "{self} does not fit DECIMAL(38, {})",
+                self.scale
+            )));
+        }
+        let val =
+            duckdb_decimal { width: 38, scale: self.scale, value: hugeint_from_i128(self.value) };
+        // SAFETY: `val` is a fully initialized `duckdb_decimal` whose value fits its
+        // validated width and scale.
+        Ok(unsafe { duckdb_create_decimal(val) })
+    }
+}
+
+crate::impl_appendable_via_to_duck_native!(WideDecimal);
+
+#[cfg(feature = "bigdecimal")]
+impl From<WideDecimal> for BigDecimal {
+    fn from(decimal: WideDecimal) -> Self {
+        BigDecimal::new(BigInt::from(decimal.value), i64::from(decimal.scale))
+    }
+}
+
+/// Converts a `BigDecimal` of at most 38 digits, at most 38 of them after the point.
+///
+/// The value is never rounded: one with more digits fails with
+/// [`DuckDBConversionError::PrecisionLoss`]. Round it first with
+/// [`BigDecimal::with_scale_round`] to store it anyway.
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<&BigDecimal> for WideDecimal {
+    type Error = DuckDBConversionError;
+
+    fn try_from(decimal: &BigDecimal) -> Result<Self, Self::Error> {
+        let out_of_range = || {
+            DuckDBConversionError::PrecisionLoss(format!("{decimal} does not fit DECIMAL(38, s)"))
+        };
+        // A negative exponent stands for trailing zeros of an integer.
+        let (digits, exponent) = match decimal.as_bigint_and_exponent() {
+            (_, exponent) if exponent < 0 => (decimal.with_scale(0).as_bigint_and_exponent().0, 0),
+            parts => parts,
+        };
+        let scale = u8::try_from(exponent).ok().filter(|&s| s <= 38).ok_or_else(out_of_range)?;
+        let value = i128::try_from(&digits)
+            .ok()
+            .filter(|v| v.unsigned_abs() < 10u128.pow(38))
+            .ok_or_else(out_of_range)?;
+        Ok(WideDecimal { value, scale })
+    }
+}
+
+/// Reads any `DECIMAL` exactly, and binds or appends the value as `DECIMAL(38, scale)`
+/// through [`WideDecimal`], so it must fit 38 digits.
+#[cfg(feature = "bigdecimal")]
+impl DuckDialect for BigDecimal {
+    fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
+        WideDecimal::from_duck(value).map(BigDecimal::from)
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        WideDecimal::try_from(self)?.to_duck()
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+crate::impl_appendable_via_to_duck_native!(BigDecimal);
+
+/// The widest `DECIMAL` that always fits a `Decimal`, whose unscaled value is 96 bits.
+#[cfg(feature = "decimal")]
+const MAX_DECIMAL_WIDTH: u8 = 28;
+
+/// Reads the `DECIMAL` at `row_idx` of `vector` straight from the vector data.
+///
+/// DuckDB stores a decimal as its unscaled integer in the narrowest type that fits its
+/// width: `SMALLINT` up to width 4, `INTEGER` up to 9, `BIGINT` up to 18 and `HUGEINT`
+/// beyond. The storage type and the scale come from the column's logical type.
+///
+/// The variant follows the column, not the value, so every row of a column reads the
+/// same way: a `DuckValue::Decimal` up to width 28, and a `DuckValue::WideDecimal` for
+/// wider columns or without the `decimal` feature.
+///
+/// # Errors
+///
+/// Returns an error for an unknown storage type.
+pub(crate) fn read_decimal(
+    vector: crate::ffi::duckdb_vector,
+    row_idx: u64,
+) -> Result<DuckValue, DuckDBConversionError> {
+    use crate::ffi::{
+        duckdb_decimal_internal_type, duckdb_decimal_scale, duckdb_decimal_width,
+        duckdb_destroy_logical_type, duckdb_vector_get_column_type, duckdb_vector_get_data,
+        DUCKDB_TYPE_DUCKDB_TYPE_BIGINT, DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT,
+        DUCKDB_TYPE_DUCKDB_TYPE_INTEGER, DUCKDB_TYPE_DUCKDB_TYPE_SMALLINT,
+    };
+
+    // SAFETY: `vector` is a valid DECIMAL vector; the returned logical type is owned by
+    // us and destroyed below.
+    let mut logical_type = unsafe { duckdb_vector_get_column_type(vector) };
+    // SAFETY: `logical_type` is a valid DECIMAL logical type.
+    let (width, scale, storage) = unsafe {
+        (
+            duckdb_decimal_width(logical_type),
+            duckdb_decimal_scale(logical_type),
+            duckdb_decimal_internal_type(logical_type),
+        )
+    };
+    // SAFETY: `logical_type` was obtained above and is destroyed exactly once.
+    unsafe { duckdb_destroy_logical_type(&mut logical_type) };
+
+    // SAFETY: `vector` is a valid duckdb_vector; its data holds one `storage`-typed value
+    // per row of the chunk, and `row_idx` is within [0, chunk_size).
+    let unscaled = unsafe {
+        let data = duckdb_vector_get_data(vector);
+        let row = row_idx as usize;
+        match storage {
+            DUCKDB_TYPE_DUCKDB_TYPE_SMALLINT => *(data as *const i16).add(row) as i128,
+            DUCKDB_TYPE_DUCKDB_TYPE_INTEGER => *(data as *const i32).add(row) as i128,
+            DUCKDB_TYPE_DUCKDB_TYPE_BIGINT => *(data as *const i64).add(row) as i128,
+            DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT => {
+                i128_from_hugeint(*(data as *const duckdb_hugeint).add(row))
+            },
+            other => {
+                return Err(DuckDBConversionError::ConversionError(format!(
+                    "unsupported DECIMAL storage type {other}"
+                )))
+            },
+        }
+    };
+    #[cfg(feature = "decimal")]
+    if width <= MAX_DECIMAL_WIDTH {
+        // The column's width bounds every value, so it always fits a `Decimal`.
+        return Ok(DuckValue::Decimal(Decimal::from_i128_with_scale(unscaled, scale as u32)));
+    }
+    #[cfg(not(feature = "decimal"))]
+    let _ = width;
+    Ok(DuckValue::WideDecimal(WideDecimal { value: unscaled, scale }))
+}
+
 #[cfg(test)]
 #[allow(clippy::undocumented_unsafe_blocks)]
 mod test_numeric_conversion {
-    use crate::ffi::{duckdb_destroy_value, duckdb_get_hugeint};
+    use crate::ffi::{duckdb_destroy_value, duckdb_get_hugeint, duckdb_get_uhugeint};
 
     #[test]
     fn test_i8_conversion() {
@@ -345,6 +701,39 @@ mod test_numeric_conversion {
         assert_eq!(value, converted_value);
         unsafe { duckdb_destroy_value(&mut duck_value) };
     }
+    #[test]
+    fn test_u128_conversion() {
+        use super::*;
+
+        for value in [0, 5, 1 << 64, (1 << 64) - 1, u128::MAX / 3, u128::MAX] {
+            let mut duck_value = value.to_duck().unwrap();
+            let converted_value =
+                u128::from_duck(unsafe { duckdb_get_uhugeint(duck_value) }).unwrap();
+            assert_eq!(value, converted_value);
+            unsafe { duckdb_destroy_value(&mut duck_value) };
+        }
+    }
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn test_bigdecimal_to_wide_decimal() {
+        use super::*;
+
+        let wide = |s: &str| WideDecimal::try_from(&s.parse::<BigDecimal>().unwrap());
+        assert_eq!(wide("-12.50"), Ok(WideDecimal { value: -1_250, scale: 2 }));
+        // A negative exponent becomes trailing zeros.
+        assert_eq!(wide("15e2"), Ok(WideDecimal { value: 1_500, scale: 0 }));
+        assert_eq!(wide(&"9".repeat(38)), Ok(WideDecimal { value: 10i128.pow(38) - 1, scale: 0 }));
+        assert!(matches!(wide(&"9".repeat(39)), Err(DuckDBConversionError::PrecisionLoss(_))));
+        let digits_after_point_39 = format!("0.{}1", "0".repeat(38));
+        assert!(matches!(
+            wide(&digits_after_point_39),
+            Err(DuckDBConversionError::PrecisionLoss(_))
+        ));
+
+        let back = BigDecimal::from(WideDecimal { value: -1_250, scale: 2 });
+        assert_eq!(back, "-12.5".parse::<BigDecimal>().unwrap());
+    }
+
     #[cfg(feature = "decimal")]
     #[test]
     fn test_decimal_conversion() {
@@ -398,4 +787,24 @@ mod test_numeric_conversion {
         assert_eq!(value, converted_value);
         unsafe { duckdb_destroy_value(&mut duck_value) };
     }
+
+    #[test]
+    fn test_hugeint_split_is_exact_twos_complement() {
+        use super::*;
+        let two_64 = 1_i128 << 64;
+        let cases = [
+            (0, 0, 0),
+            (-1, -1, u64::MAX),
+            (two_64 - 1, 0, u64::MAX),
+            (two_64, 1, 0),
+            (-two_64, -1, 0),
+            (i128::MAX, i64::MAX, u64::MAX),
+            (i128::MIN, i64::MIN, 0),
+        ];
+        for (value, upper, lower) in cases {
+            let h = hugeint_from_i128(value);
+            assert_eq!((h.upper, h.lower), (upper, lower), "{value}");
+            assert_eq!(i128_from_hugeint(h), value);
+        }
+    }
 }
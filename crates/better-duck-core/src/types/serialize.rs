@@ -0,0 +1,151 @@
+//! `serde::Serialize` for [`DuckValue`] and [`DuckRow`], for dumping results to JSON and
+//! other self-describing formats.
+//!
+//! | DuckDB value | Serialized as |
+//! |---|---|
+//! | `NULL` | none (`null` in JSON) |
+//! | booleans, integers, floats | the matching primitive |
+//! | `DECIMAL` | a string, so no precision is lost to a float |
+//! | `VARCHAR`, `ENUM` | a string |
+//! | `BLOB` | bytes (an array of numbers in JSON) |
+//! | dates, times, timestamps, intervals | their display string, e.g. `2024-01-01` |
+//! | `LIST`, `ARRAY` | a sequence |
+//! | `STRUCT` | a map of field name to value, sorted by field name |
+//! | `MAP` | a map keyed by each key's display string, sorted by key |
+//! | `UNION` | the active member's value |
+//!
+//! A [`DuckRow`] serializes as a map of column name to value, in column order.
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use crate::{raw::row::DuckRow, types::value::DuckValue};
+
+impl Serialize for DuckValue {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            DuckValue::Null => serializer.serialize_none(),
+            DuckValue::Boolean(v) => serializer.serialize_bool(*v),
+            DuckValue::TinyInt(v) => serializer.serialize_i8(*v),
+            DuckValue::SmallInt(v) => serializer.serialize_i16(*v),
+            DuckValue::Int(v) => serializer.serialize_i32(*v),
+            DuckValue::BigInt(v) => serializer.serialize_i64(*v),
+            DuckValue::HugeInt(v) => serializer.serialize_i128(*v),
+            DuckValue::UTinyInt(v) => serializer.serialize_u8(*v),
+            DuckValue::USmallInt(v) => serializer.serialize_u16(*v),
+            DuckValue::UInt(v) => serializer.serialize_u32(*v),
+            DuckValue::UBigInt(v) => serializer.serialize_u64(*v),
+            DuckValue::UHugeInt(v) => serializer.serialize_u128(*v),
+            DuckValue::Float(v) => serializer.serialize_f32(*v),
+            DuckValue::Double(v) => serializer.serialize_f64(*v),
+            DuckValue::Text(s) | DuckValue::Enum(s) => serializer.serialize_str(s),
+            #[cfg(feature = "decimal")]
+            DuckValue::Decimal(d) => serializer.collect_str(d),
+            DuckValue::WideDecimal(d) => serializer.collect_str(d),
+            DuckValue::Blob(b) => serializer.serialize_bytes(&b.0),
+            DuckValue::List(items) => serialize_items(serializer, items),
+            DuckValue::Array(items) => serialize_items(serializer, items),
+            DuckValue::Struct(fields) => {
+                let mut fields: Vec<_> = fields.iter().collect();
+                fields.sort_by(|a, b| a.0.cmp(b.0));
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (name, value) in fields {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            },
+            DuckValue::Map(entries) => {
+                let mut entries: Vec<_> = entries.iter().map(|(k, v)| (k.to_string(), v)).collect();
+                entries.sort_by(|a, b| a.0.cmp(&b.0));
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(&key, value)?;
+                }
+                map.end()
+            },
+            DuckValue::Union(inner) => inner.serialize(serializer),
+            // Dates, times, timestamps and intervals: the same text `Display` renders.
+            temporal => serializer.collect_str(temporal),
+        }
+    }
+}
+
+/// Serializes `items` as a sequence.
+fn serialize_items<S: Serializer>(
+    serializer: S,
+    items: &[DuckValue],
+) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(items.len()))?;
+    for item in items {
+        seq.serialize_element(item)?;
+    }
+    seq.end()
+}
+
+impl Serialize for DuckRow {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.values().len()))?;
+        for (name, value) in self.iter() {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, helpers::path::path_to_cstring, raw::connection::RawConnection};
+
+    fn get_test_connection() -> RawConnection {
+        let c_path = path_to_cstring(":memory:".as_ref()).unwrap();
+        let config = Config::default().with("duckdb_api", "rust").unwrap();
+        RawConnection::open_with_flags(&c_path, config).unwrap()
+    }
+
+    #[test]
+    fn test_row_serializes_as_json_object() {
+        let con = get_test_connection();
+        let mut stmt = con
+            .prepare("SELECT 42 AS id, 'duck' AS name, NULL AS missing, [1, 2] AS tags")
+            .unwrap();
+        let row = stmt.execute().unwrap().next().expect("expected a row").unwrap();
+        assert_eq!(
+            serde_json::to_string(&row).unwrap(),
+            r#"{"id":42,"name":"duck","missing":null,"tags":[1,2]}"#
+        );
+    }
+
+    #[test]
+    fn test_nested_and_lossless_values() {
+        let con = get_test_connection();
+        let mut stmt = con
+            .prepare(
+                "SELECT {'b': 2, 'a': 'x'} AS s, MAP {'k': 1} AS m, '\\x01\\x02'::BLOB AS bytes, \
+                 DATE '2024-02-29' AS d",
+            )
+            .unwrap();
+        let row = stmt.execute().unwrap().next().expect("expected a row").unwrap();
+        assert_eq!(
+            serde_json::to_value(&row).unwrap(),
+            serde_json::json!({
+                "s": {"a": "x", "b": 2},
+                "m": {"k": 1},
+                "bytes": [1, 2],
+                "d": "2024-02-29",
+            })
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_serializes_as_string() {
+        let value = DuckValue::Decimal(rust_decimal::Decimal::new(12_345, 3));
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""12.345""#);
+    }
+}
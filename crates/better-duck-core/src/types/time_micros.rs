@@ -0,0 +1,139 @@
+use super::*;
+
+use crate::{
+    ffi::{duckdb_append_time, duckdb_bind_time, duckdb_create_time, duckdb_time, duckdb_value},
+    types::appendable::check_rc,
+};
+
+/// A DuckDB `TIME` value as microseconds since midnight.
+///
+/// Mirrors DuckDB's own `TIME` storage (`duckdb_time { micros }`), so binding and reading
+/// it is exact and needs neither the `chrono` feature nor component decoding into
+/// hour/minute/second.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, params, types::time_micros::TimeMicros};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let micros = conn.execute_with("SELECT ?::TIME AS t", params![TimeMicros(45_000_000)])?
+///     .next()
+///     .unwrap()?
+///     .get_time_micros("t")?;
+/// assert_eq!(micros, 45_000_000); // 00:00:45
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeMicros(pub i64);
+
+impl DuckDialect<duckdb_time> for TimeMicros {
+    fn from_duck(value: duckdb_time) -> Result<Self, DuckDBConversionError> {
+        Ok(TimeMicros(value.micros))
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        // SAFETY: any `duckdb_time` is a plain integer wrapper; creation cannot fail.
+        Ok(unsafe { duckdb_create_time(duckdb_time { micros: self.0 }) })
+    }
+}
+
+impl AppendAble for TimeMicros {
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> Result<()> {
+        // SAFETY: `appender` is a valid duckdb_appender inside a begin_row/end_row pair.
+        let rc = unsafe { duckdb_append_time(appender, duckdb_time { micros: self.0 }) };
+        check_rc(rc, "append TIME value")
+    }
+
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> Result<()> {
+        // SAFETY: `stmt` is a valid prepared statement; `idx` is a 1-based parameter index.
+        let rc = unsafe { duckdb_bind_time(stmt, idx, duckdb_time { micros: self.0 }) };
+        check_rc(rc, format_args!("bind TIME to parameter {idx}"))
+    }
+}
+
+impl TimeMicros {
+    /// Extracts the microseconds since midnight from a [`DuckValue::Time`].
+    ///
+    /// Returns `None` for any other variant, including `Null`.
+    ///
+    /// [`DuckValue::Time`]: crate::types::value::DuckValue::Time
+    pub fn from_value(value: &crate::types::value::DuckValue) -> Option<TimeMicros> {
+        use crate::types::value::DuckValue;
+
+        match value {
+            #[cfg(feature = "chrono")]
+            DuckValue::Time(t) => {
+                use chrono::Timelike;
+                Some(TimeMicros(
+                    t.num_seconds_from_midnight() as i64 * 1_000_000
+                        + (t.nanosecond() / 1_000) as i64,
+                ))
+            },
+            #[cfg(not(feature = "chrono"))]
+            DuckValue::Time(t) => Some(TimeMicros(
+                t.hour as i64 * 3_600_000_000
+                    + t.min as i64 * 60_000_000
+                    + t.sec as i64 * 1_000_000
+                    + t.micros as i64,
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{connection::Connection, params};
+
+    #[test]
+    fn test_time_micros_bind_round_trip() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let noon_ish = 12 * 3_600_000_000 + 30 * 60_000_000 + 45_000_123;
+        let row = conn
+            .execute_with("SELECT ?::TIME AS t", params![TimeMicros(noon_ish)])
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.get_time_micros("t").unwrap(), noon_ish);
+    }
+
+    #[test]
+    fn test_time_micros_appender_round_trip() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE t (t TIME)").unwrap();
+        {
+            let mut appender = conn.appender("t", "main").unwrap();
+            appender.append(&mut TimeMicros(45_000_000)).unwrap();
+            appender.save().unwrap();
+        }
+        let micros = conn.query_row("SELECT t FROM t", |row| row.get_time_micros("t")).unwrap();
+        assert_eq!(micros, 45_000_000);
+    }
+
+    #[test]
+    fn test_time_micros_bind_past_last_parameter_fails() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut stmt = conn.db().prepare("SELECT ?::TIME AS t").unwrap();
+        stmt.bind(&mut TimeMicros(1)).unwrap();
+        let err = stmt.bind(&mut TimeMicros(2)).unwrap_err();
+        assert!(err.to_string().contains("failed to bind TIME to parameter 2"), "{err}");
+    }
+
+    #[test]
+    fn test_time_micros_from_non_time_value() {
+        use crate::types::value::DuckValue;
+        assert_eq!(TimeMicros::from_value(&DuckValue::Null), None);
+        assert_eq!(TimeMicros::from_value(&DuckValue::Int(3)), None);
+    }
+}
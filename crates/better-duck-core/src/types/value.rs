@@ -1,17 +1,17 @@
 #![allow(non_snake_case)]
 #[cfg(feature = "chrono")]
-use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use libduckdb_sys::duckdb_hugeint;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::CStr;
 use std::hash::{Hash, Hasher};
 use std::mem;
 #[cfg(not(feature = "chrono"))]
-use std::time::{Duration, SystemTime};
+use std::time::SystemTime;
 
 use crate::{
     ffi::{
-        duckdb_create_logical_type, duckdb_create_null_value, duckdb_create_uhugeint, duckdb_date,
+        duckdb_create_logical_type, duckdb_create_null_value, duckdb_date,
         duckdb_destroy_logical_type, duckdb_enum_dictionary_size, duckdb_enum_dictionary_value,
         duckdb_free, duckdb_interval, duckdb_logical_type, duckdb_string_t, duckdb_string_t_data,
         duckdb_string_t_length, duckdb_time, duckdb_time_ns, duckdb_time_tz, duckdb_timestamp,
@@ -23,8 +23,8 @@ use crate::{
         DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL, DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE,
         DUCKDB_TYPE_DUCKDB_TYPE_ENUM, DUCKDB_TYPE_DUCKDB_TYPE_FLOAT,
         DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT, DUCKDB_TYPE_DUCKDB_TYPE_INTEGER,
-        DUCKDB_TYPE_DUCKDB_TYPE_INTERVAL, DUCKDB_TYPE_DUCKDB_TYPE_INVALID,
-        DUCKDB_TYPE_DUCKDB_TYPE_LIST, DUCKDB_TYPE_DUCKDB_TYPE_MAP,
+        DUCKDB_TYPE_DUCKDB_TYPE_INTEGER_LITERAL, DUCKDB_TYPE_DUCKDB_TYPE_INTERVAL,
+        DUCKDB_TYPE_DUCKDB_TYPE_INVALID, DUCKDB_TYPE_DUCKDB_TYPE_LIST, DUCKDB_TYPE_DUCKDB_TYPE_MAP,
         DUCKDB_TYPE_DUCKDB_TYPE_SMALLINT, DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL,
         DUCKDB_TYPE_DUCKDB_TYPE_STRING_LITERAL, DUCKDB_TYPE_DUCKDB_TYPE_STRUCT,
         DUCKDB_TYPE_DUCKDB_TYPE_TIME, DUCKDB_TYPE_DUCKDB_TYPE_TIMESTAMP,
@@ -126,12 +126,8 @@ pub enum DuckValue {
     #[cfg(not(feature = "chrono"))]
     Time(crate::types::date_native::DuckTime),
 
-    /// The value is an interval (months, days, microseconds).
-    #[cfg(feature = "chrono")]
-    Interval(Duration),
-    /// The value is an interval (months, days, microseconds).
-    #[cfg(not(feature = "chrono"))]
-    Interval(Duration),
+    /// The value is an interval, with months, days and microseconds kept separate.
+    Interval(crate::types::interval::Interval),
 
     /// The value is a microsecond-precision time with timezone (`TIME_TZ`).
     #[cfg(feature = "chrono")]
@@ -140,10 +136,12 @@ pub enum DuckValue {
     #[cfg(not(feature = "chrono"))]
     TimeTz(crate::types::date_native::DuckTimeTz),
 
-    /// The value is a nanosecond-precision time (`TIME_NS`).
+    /// The value is a nanosecond-precision time (`TIME_NS`); all nine sub-second
+    /// digits are kept.
     #[cfg(feature = "chrono")]
     TimeNs(NaiveTime),
-    /// The value is a nanosecond-precision time (`TIME_NS`).
+    /// The value is a nanosecond-precision time (`TIME_NS`); all nine sub-second
+    /// digits are kept.
     #[cfg(not(feature = "chrono"))]
     TimeNs(crate::types::date_native::DuckTimeNs),
 
@@ -152,6 +150,9 @@ pub enum DuckValue {
     #[cfg(feature = "decimal")]
     /// The value is a Decimal.
     Decimal(Decimal),
+    /// The value is a `DECIMAL` wider than 28 digits, or any `DECIMAL` without the
+    /// `decimal` feature.
+    WideDecimal(crate::types::numeric::WideDecimal),
     /// The value is a blob of data (raw byte sequence).
     Blob(Blob),
     /// The value is a list
@@ -229,9 +230,6 @@ impl PartialEq for DuckValue {
             (Time(a), Time(b)) => a == b,
             #[cfg(not(feature = "chrono"))]
             (Time(a), Time(b)) => a == b,
-            #[cfg(feature = "chrono")]
-            (Interval(a), Interval(b)) => a == b,
-            #[cfg(not(feature = "chrono"))]
             (Interval(a), Interval(b)) => a == b,
             #[cfg(feature = "chrono")]
             (TimeTz(a), TimeTz(b)) => a == b,
@@ -245,6 +243,7 @@ impl PartialEq for DuckValue {
             (Enum(a), Enum(b)) => a == b,
             #[cfg(feature = "decimal")]
             (Decimal(a), Decimal(b)) => a == b,
+            (WideDecimal(a), WideDecimal(b)) => a == b,
             (Blob(a), Blob(b)) => a == b,
             (List(a), List(b)) => a == b,
             (Array(a), Array(b)) => a == b,
@@ -307,9 +306,6 @@ impl Hash for DuckValue {
             DuckValue::Time(t) => t.hash(state),
             #[cfg(not(feature = "chrono"))]
             DuckValue::Time(t) => t.hash(state),
-            #[cfg(feature = "chrono")]
-            DuckValue::Interval(i) => i.hash(state),
-            #[cfg(not(feature = "chrono"))]
             DuckValue::Interval(i) => i.hash(state),
             #[cfg(feature = "chrono")]
             DuckValue::TimeTz(t) => t.hash(state),
@@ -323,6 +319,7 @@ impl Hash for DuckValue {
             DuckValue::Enum(s) => s.hash(state),
             #[cfg(feature = "decimal")]
             DuckValue::Decimal(d) => d.hash(state),
+            DuckValue::WideDecimal(d) => d.hash(state),
             DuckValue::Blob(b) => b.hash(state),
             DuckValue::List(items) => items.hash(state),
             DuckValue::Array(items) => items.hash(state),
@@ -339,6 +336,234 @@ impl Hash for DuckValue {
     }
 }
 
+// Display
+//
+// Renders values roughly the way the DuckDB CLI does: NULL as `NULL`, text unquoted,
+// blobs with non-printable bytes as `\xNN`, lists as `[a, b]`, structs as
+// `{'k': v}` and maps as `{k=v}`. Struct fields and map entries are sorted so the
+// output does not depend on HashMap iteration order. `to_sql_string` below renders
+// quoted SQL literals instead.
+
+impl std::fmt::Display for DuckValue {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            DuckValue::Null => f.write_str("NULL"),
+            DuckValue::Boolean(v) => write!(f, "{v}"),
+            DuckValue::TinyInt(v) => write!(f, "{v}"),
+            DuckValue::SmallInt(v) => write!(f, "{v}"),
+            DuckValue::Int(v) => write!(f, "{v}"),
+            DuckValue::BigInt(v) => write!(f, "{v}"),
+            DuckValue::HugeInt(v) => write!(f, "{v}"),
+            DuckValue::UTinyInt(v) => write!(f, "{v}"),
+            DuckValue::USmallInt(v) => write!(f, "{v}"),
+            DuckValue::UInt(v) => write!(f, "{v}"),
+            DuckValue::UBigInt(v) => write!(f, "{v}"),
+            DuckValue::UHugeInt(v) => write!(f, "{v}"),
+            DuckValue::Float(v) => write!(f, "{v}"),
+            DuckValue::Double(v) => write!(f, "{v}"),
+            #[cfg(feature = "chrono")]
+            DuckValue::Timestamp(t)
+            | DuckValue::TimestampS(t)
+            | DuckValue::TimestampMs(t)
+            | DuckValue::TimestampNs(t) => write!(f, "{t}"),
+            #[cfg(feature = "chrono")]
+            DuckValue::TimestampTz(t) => write!(f, "{}+00", t.naive_utc()),
+            #[cfg(feature = "chrono")]
+            DuckValue::Date(d) => write!(f, "{d}"),
+            #[cfg(feature = "chrono")]
+            DuckValue::Time(t) | DuckValue::TimeNs(t) => write!(f, "{t}"),
+            DuckValue::Interval(i) => write!(f, "{i}"),
+            #[cfg(feature = "chrono")]
+            DuckValue::TimeTz(t) => {
+                let sign = if t.offset_secs < 0 { '-' } else { '+' };
+                let offset = t.offset_secs.unsigned_abs();
+                write!(f, "{}{sign}{:02}:{:02}", t.time, offset / 3600, offset % 3600 / 60)
+            },
+            #[cfg(not(feature = "chrono"))]
+            DuckValue::Timestamp(t)
+            | DuckValue::TimestampS(t)
+            | DuckValue::TimestampMs(t)
+            | DuckValue::TimestampNs(t)
+            | DuckValue::TimestampTz(t) => write!(f, "{t:?}"),
+            #[cfg(not(feature = "chrono"))]
+            DuckValue::Date(d) => write!(f, "{:04}-{:02}-{:02}", d.year, d.month, d.day),
+            #[cfg(not(feature = "chrono"))]
+            DuckValue::Time(t) => {
+                write!(f, "{:02}:{:02}:{:02}.{:06}", t.hour, t.min, t.sec, t.micros)
+            },
+            #[cfg(not(feature = "chrono"))]
+            DuckValue::TimeTz(t) => write!(f, "{t:?}"),
+            #[cfg(not(feature = "chrono"))]
+            DuckValue::TimeNs(t) => {
+                write!(f, "{:02}:{:02}:{:02}.{:09}", t.hour, t.min, t.sec, t.nanos)
+            },
+            DuckValue::Text(s) | DuckValue::Enum(s) => f.write_str(s),
+            #[cfg(feature = "decimal")]
+            DuckValue::Decimal(d) => write!(f, "{d}"),
+            DuckValue::WideDecimal(d) => write!(f, "{d}"),
+            DuckValue::Blob(b) => {
+                for &byte in &b.0 {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        write!(f, "{}", byte as char)?;
+                    } else {
+                        write!(f, "\\x{byte:02X}")?;
+                    }
+                }
+                Ok(())
+            },
+            DuckValue::List(items) => fmt_items(f, items),
+            DuckValue::Array(items) => fmt_items(f, items),
+            DuckValue::Struct(fields) => {
+                let mut fields: Vec<_> = fields.iter().collect();
+                fields.sort_by(|a, b| a.0.cmp(b.0));
+                f.write_str("{")?;
+                for (i, (name, value)) in fields.into_iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "'{name}': {value}")?;
+                }
+                f.write_str("}")
+            },
+            DuckValue::Map(entries) => {
+                let mut entries: Vec<String> =
+                    entries.iter().map(|(k, v)| format!("{k}={v}")).collect();
+                entries.sort();
+                write!(f, "{{{}}}", entries.join(", "))
+            },
+            DuckValue::Union(inner) => write!(f, "{inner}"),
+        }
+    }
+}
+
+/// Writes `items` as a bracketed, comma-separated list.
+fn fmt_items(
+    f: &mut std::fmt::Formatter<'_>,
+    items: &[DuckValue],
+) -> std::fmt::Result {
+    f.write_str("[")?;
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    f.write_str("]")
+}
+
+// SQL literals
+//
+// Mirrors DuckDB's `Value::ToSQLString`: text is single-quoted, temporal values and
+// blobs are quoted and cast to their type, non-finite floats are cast from strings, and
+// nested values recurse so every element is itself a literal.
+
+impl DuckValue {
+    /// Renders the value as DuckDB SQL literal text, e.g. `'it''s'`, `NULL`,
+    /// `[1, NULL]`, `{'a': 'x'}` or `'2024-01-01'::DATE`.
+    ///
+    /// Unlike [`Display`](std::fmt::Display), strings are quoted, so nested text stays
+    /// distinguishable from numbers. Decimals keep their scale (`1.50`). The output is
+    /// meant for logs, CLIs and test assertions; bind values as parameters rather than
+    /// splicing this text into queries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use better_duck_core::types::value::DuckValue;
+    ///
+    /// let v = DuckValue::List(vec![DuckValue::text("a'b"), DuckValue::Null]);
+    /// assert_eq!(v.to_sql_string(), "['a''b', NULL]");
+    /// ```
+    pub fn to_sql_string(&self) -> String {
+        let mut out = String::new();
+        self.write_sql(&mut out);
+        out
+    }
+
+    fn write_sql(
+        &self,
+        out: &mut String,
+    ) {
+        use std::fmt::Write;
+
+        let cast = |out: &mut String, value: &DuckValue, ty: &str| {
+            let text = value.to_string();
+            let _ = write!(out, "'{}'::{ty}", text.replace('\'', "''"));
+        };
+        let list = |out: &mut String, items: &[DuckValue]| {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                item.write_sql(out);
+            }
+            out.push(']');
+        };
+        match self {
+            DuckValue::Text(s) | DuckValue::Enum(s) if s.contains('\0') => {
+                // A NUL cannot appear inside a SQL string literal, so each one is spliced
+                // in with `chr(0)`.
+                let mut parts = Vec::new();
+                for (i, part) in s.split('\0').enumerate() {
+                    if i > 0 {
+                        parts.push("chr(0)".to_owned());
+                    }
+                    if !part.is_empty() {
+                        parts.push(crate::sql::quote_literal(part));
+                    }
+                }
+                out.push_str(&parts.join(" || "));
+            },
+            DuckValue::Text(s) | DuckValue::Enum(s) => out.push_str(&crate::sql::quote_literal(s)),
+            DuckValue::Float(v) if !v.is_finite() => cast(out, self, "FLOAT"),
+            DuckValue::Double(v) if !v.is_finite() => cast(out, self, "DOUBLE"),
+            DuckValue::Date(_) => cast(out, self, "DATE"),
+            DuckValue::Time(_) => cast(out, self, "TIME"),
+            DuckValue::TimeNs(_) => cast(out, self, "TIME_NS"),
+            DuckValue::TimeTz(_) => cast(out, self, "TIME WITH TIME ZONE"),
+            DuckValue::Timestamp(_) => cast(out, self, "TIMESTAMP"),
+            DuckValue::TimestampS(_) => cast(out, self, "TIMESTAMP_S"),
+            DuckValue::TimestampMs(_) => cast(out, self, "TIMESTAMP_MS"),
+            DuckValue::TimestampNs(_) => cast(out, self, "TIMESTAMP_NS"),
+            DuckValue::TimestampTz(_) => cast(out, self, "TIMESTAMP WITH TIME ZONE"),
+            DuckValue::Interval(_) => cast(out, self, "INTERVAL"),
+            DuckValue::Blob(_) => cast(out, self, "BLOB"),
+            DuckValue::List(items) => list(out, items),
+            DuckValue::Array(items) => list(out, items),
+            DuckValue::Struct(fields) => {
+                let mut fields: Vec<_> = fields.iter().collect();
+                fields.sort_by(|a, b| a.0.cmp(b.0));
+                out.push('{');
+                for (i, (name, value)) in fields.into_iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    out.push_str(&crate::sql::quote_literal(name));
+                    out.push_str(": ");
+                    value.write_sql(out);
+                }
+                out.push('}');
+            },
+            DuckValue::Map(entries) => {
+                let mut entries: Vec<String> = entries
+                    .iter()
+                    .map(|(k, v)| format!("{}: {}", k.to_sql_string(), v.to_sql_string()))
+                    .collect();
+                entries.sort();
+                let _ = write!(out, "MAP {{{}}}", entries.join(", "));
+            },
+            DuckValue::Union(inner) => inner.write_sql(out),
+            other => {
+                let _ = write!(out, "{other}");
+            },
+        }
+    }
+}
+
 // From<&DuckValueRef>
 
 impl<'a> From<&DuckValueRef<'a>> for DuckValue {
@@ -387,9 +612,6 @@ impl<'a> From<&DuckValueRef<'a>> for DuckValue {
             DuckValueRef::Time(t) => DuckValue::Time(*t),
             #[cfg(not(feature = "chrono"))]
             DuckValueRef::Time(t) => DuckValue::Time(*t),
-            #[cfg(feature = "chrono")]
-            DuckValueRef::Interval(i) => DuckValue::Interval(*i),
-            #[cfg(not(feature = "chrono"))]
             DuckValueRef::Interval(i) => DuckValue::Interval(*i),
             #[cfg(feature = "chrono")]
             DuckValueRef::TimeTz(t) => DuckValue::TimeTz(*t),
@@ -402,6 +624,7 @@ impl<'a> From<&DuckValueRef<'a>> for DuckValue {
             DuckValueRef::Text(s) => DuckValue::Text(s.to_string()),
             #[cfg(feature = "decimal")]
             DuckValueRef::Decimal(d) => DuckValue::Decimal(*d),
+            DuckValueRef::WideDecimal(d) => DuckValue::WideDecimal(*d),
             DuckValueRef::Blob(b) => DuckValue::Blob(b.clone()),
             DuckValueRef::List(l) => DuckValue::List(l.iter().map(DuckValue::from).collect()),
             DuckValueRef::Enum(e) => DuckValue::Enum(e.to_string()),
@@ -460,6 +683,10 @@ impl DuckValue {
         t: duckdb_type,
         row_idx: u64,
     ) -> Result<DuckValue, DuckDBConversionError> {
+        // A SQLNULL vector holds no data and every row is NULL; don't touch it at all.
+        if t == DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL {
+            return Ok(DuckValue::Null);
+        }
         // SAFETY: `val` is a valid duckdb_vector; the validity bitmap is valid for at
         // least the chunk's row count.
         let validity_ptr = unsafe { duckdb_vector_get_validity(val) };
@@ -474,7 +701,13 @@ impl DuckValue {
             DUCKDB_TYPE_DUCKDB_TYPE_INVALID => {
                 Err(DuckDBConversionError::ConversionError(String::from("invalid type")))
             },
-            DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL => Ok(DuckValue::Null),
+            // DuckDB resolves untyped integer constants to a concrete integer type before
+            // they reach a result; an integer-literal type has no physical layout of its
+            // own, and the C API does not expose which width it stands for, so its data
+            // cannot be read safely.
+            DUCKDB_TYPE_DUCKDB_TYPE_INTEGER_LITERAL => Err(DuckDBConversionError::ConversionError(
+                String::from("cannot read an unresolved INTEGER_LITERAL vector"),
+            )),
             DUCKDB_TYPE_DUCKDB_TYPE_BOOLEAN => {
                 simple_type_conversion!(row_idx, val, DuckValue::Boolean, bool)
             },
@@ -510,7 +743,7 @@ impl DuckValue {
             },
 
             DUCKDB_TYPE_DUCKDB_TYPE_UHUGEINT => {
-                simple_type_conversion!(row_idx, val, DuckValue::UHugeInt, u128)
+                read_packed!(val, row_idx, duckdb_uhugeint, u128).map(DuckValue::UHugeInt)
             },
             DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT => {
                 read_packed!(val, row_idx, duckdb_hugeint, i128).map(DuckValue::HugeInt)
@@ -550,16 +783,8 @@ impl DuckValue {
                 }
             },
             DUCKDB_TYPE_DUCKDB_TYPE_INTERVAL => {
-                #[cfg(feature = "chrono")]
-                {
-                    read_packed!(val, row_idx, duckdb_interval, chrono::Duration)
-                        .map(DuckValue::Interval)
-                }
-                #[cfg(not(feature = "chrono"))]
-                {
-                    read_packed!(val, row_idx, duckdb_interval, std::time::Duration)
-                        .map(DuckValue::Interval)
-                }
+                read_packed!(val, row_idx, duckdb_interval, crate::types::interval::Interval)
+                    .map(DuckValue::Interval)
             },
             DUCKDB_TYPE_DUCKDB_TYPE_TIMESTAMP_S => {
                 #[cfg(feature = "chrono")]
@@ -681,15 +906,7 @@ impl DuckValue {
                 };
                 Ok(DuckValue::Blob(Blob::new(bytes)))
             },
-            #[cfg(feature = "decimal")]
-            DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL => {
-                // SAFETY: `val` is a valid duckdb_vector; the data pointer is valid for the
-                // chunk's row count. We read the raw i64 at `row_idx` as a decimal.
-                let data_ptr = unsafe { duckdb_vector_get_data(val) as *mut i64 };
-                // SAFETY: `row_idx` is within [0, chunk_size).
-                let value = unsafe { *data_ptr.add(row_idx as usize) as crate::ffi::duckdb_value };
-                Decimal::from_duck(value).map(DuckValue::Decimal)
-            },
+            DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL => crate::types::numeric::read_decimal(val, row_idx),
             DUCKDB_TYPE_DUCKDB_TYPE_ENUM => {
                 // SAFETY: `val` is a valid duckdb_vector from an active DuckDB result.
                 // `duckdb_vector_get_column_type` returns a new logical type that the caller
@@ -841,11 +1058,7 @@ impl DuckValue {
             DuckValue::USmallInt(n) => n.to_duck(),
             DuckValue::UInt(n) => n.to_duck(),
             DuckValue::UBigInt(n) => n.to_duck(),
-            DuckValue::UHugeInt(n) => {
-                let uhi = duckdb_uhugeint { lower: *n as u64, upper: (*n >> 64) as u64 };
-                // SAFETY: `uhi` is a valid duckdb_uhugeint computed from a u128 value.
-                Ok(unsafe { duckdb_create_uhugeint(uhi) })
-            },
+            DuckValue::UHugeInt(n) => n.to_duck(),
             DuckValue::Float(f) => f.to_duck(),
             DuckValue::Double(d) => d.to_duck(),
 
@@ -863,8 +1076,7 @@ impl DuckValue {
             DuckValue::Date(d) => d.to_duck(),
             #[cfg(feature = "chrono")]
             DuckValue::Time(t) => t.to_duck(),
-            #[cfg(feature = "chrono")]
-            DuckValue::Interval(d) => d.to_duck(),
+            DuckValue::Interval(i) => i.to_duck(),
             #[cfg(feature = "chrono")]
             DuckValue::TimeTz(tz) => tz.to_duck(),
             #[cfg(feature = "chrono")]
@@ -881,8 +1093,6 @@ impl DuckValue {
             #[cfg(not(feature = "chrono"))]
             DuckValue::Time(t) => t.to_duck(),
             #[cfg(not(feature = "chrono"))]
-            DuckValue::Interval(d) => d.to_duck(),
-            #[cfg(not(feature = "chrono"))]
             DuckValue::TimeTz(tz) => tz.to_duck(),
             #[cfg(not(feature = "chrono"))]
             DuckValue::TimeNs(t) => t.to_duck(),
@@ -892,6 +1102,7 @@ impl DuckValue {
 
             #[cfg(feature = "decimal")]
             DuckValue::Decimal(d) => d.to_duck(),
+            DuckValue::WideDecimal(d) => d.to_duck(),
 
             DuckValue::List(items) => crate::types::array::list_to_duck(items),
             DuckValue::Array(items) => crate::types::array::array_to_duck(items),
@@ -901,6 +1112,23 @@ impl DuckValue {
         }
     }
 
+    /// Creates a `STRUCT` [`duckdb_value`] from `fields`, declaring the fields in the
+    /// map's key order.
+    ///
+    /// Unlike [`DuckValue::Struct`], whose `HashMap` makes the field order arbitrary,
+    /// the resulting STRUCT type is the same for the same set of keys. The returned
+    /// value must be destroyed with `duckdb_destroy_value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DuckDBConversionError`] if `fields` is empty, a field name contains a
+    /// NUL byte, or a field value cannot be converted.
+    pub fn struct_from_map(
+        fields: BTreeMap<String, DuckValue>
+    ) -> Result<duckdb_value, DuckDBConversionError> {
+        crate::types::duck_struct::struct_entries_to_duck(&fields.iter().collect::<Vec<_>>())
+    }
+
     /// Returns a newly-allocated [`duckdb_logical_type`] that describes `val`.
     ///
     /// The caller is responsible for destroying the returned type with
@@ -947,6 +1175,11 @@ impl DuckValue {
             },
             #[cfg(feature = "decimal")]
             DuckValue::Decimal(_) => scalar_lt!(DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL),
+            DuckValue::WideDecimal(d) => {
+                // SAFETY: `duckdb_create_decimal_type` accepts any width and scale and
+                // returns a new logical type that the caller destroys.
+                Ok(unsafe { crate::ffi::duckdb_create_decimal_type(38, d.scale) })
+            },
             DuckValue::Blob(_) => scalar_lt!(DUCKDB_TYPE_DUCKDB_TYPE_BLOB),
 
             DuckValue::List(items) => crate::types::array::list_logical_type(items),
@@ -977,6 +1210,30 @@ impl DuckValue {
         DuckValue::Text(s.into())
     }
 
+    /// Returns `true` if the value is SQL `NULL`.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, DuckValue::Null)
+    }
+
+    /// Converts the value into an [`Option`], mapping SQL `NULL` to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use better_duck_core::types::value::DuckValue;
+    ///
+    /// assert_eq!(DuckValue::Null.as_option(), None);
+    /// assert_eq!(DuckValue::Int(1).as_option(), Some(DuckValue::Int(1)));
+    /// ```
+    #[inline]
+    pub fn as_option(self) -> Option<DuckValue> {
+        match self {
+            DuckValue::Null => None,
+            value => Some(value),
+        }
+    }
+
     /// Looks up a value in a `Map` variant by any key convertible into [`DuckValue`].
     ///
     /// Returns `None` for non-`Map` variants or a missing key.
@@ -1054,38 +1311,9 @@ impl AppendAble for DuckValue {
     ) -> crate::error::Result<()> {
         DuckValueRef::from(&*self).appender_append(appender)
     }
-}
 
-impl From<DuckValue> for String {
-    fn from(val: DuckValue) -> Self {
-        match val {
-            DuckValue::Text(ref s) => s.clone(),
-            DuckValue::Null => String::new(),
-            _ => panic!("Cannot convert {:?} to String", val),
-        }
-    }
-}
-impl From<DuckValue> for i64 {
-    fn from(val: DuckValue) -> Self {
-        match val {
-            DuckValue::BigInt(v) => v,
-            DuckValue::Int(v) => v as i64,
-            DuckValue::SmallInt(v) => v as i64,
-            DuckValue::TinyInt(v) => v as i64,
-            DuckValue::Null => 0,
-            _ => panic!("Cannot convert {:?} to i64", val),
-        }
-    }
-}
-impl From<DuckValue> for i32 {
-    fn from(val: DuckValue) -> Self {
-        match val {
-            DuckValue::Int(v) => v,
-            DuckValue::SmallInt(v) => v as i32,
-            DuckValue::TinyInt(v) => v as i32,
-            DuckValue::Null => 0,
-            _ => panic!("Cannot convert {:?} to i32", val),
-        }
+    fn appender_column_count(&self) -> Option<usize> {
+        Some(1)
     }
 }
 
@@ -1111,6 +1339,79 @@ mod tests {
         assert_eq!(DuckValue::text("same"), DuckValue::text("same".to_string()));
     }
 
+    // is_null / as_option
+
+    #[test]
+    fn test_is_null() {
+        assert!(DuckValue::Null.is_null());
+        assert!(!DuckValue::Int(0).is_null());
+        assert!(!DuckValue::text("").is_null());
+    }
+
+    #[test]
+    fn test_as_option() {
+        assert_eq!(DuckValue::Null.as_option(), None);
+        assert_eq!(DuckValue::Int(0).as_option(), Some(DuckValue::Int(0)));
+    }
+
+    // Display / to_sql_string
+
+    #[test]
+    fn test_display_scalars_and_nested() {
+        assert_eq!(DuckValue::Null.to_string(), "NULL");
+        assert_eq!(DuckValue::Int(-3).to_string(), "-3");
+        assert_eq!(DuckValue::text("plain").to_string(), "plain");
+        let nested = DuckValue::List(vec![
+            DuckValue::List(vec![DuckValue::Int(1), DuckValue::Null]),
+            DuckValue::List(vec![]),
+        ]);
+        assert_eq!(nested.to_string(), "[[1, NULL], []]");
+    }
+
+    #[test]
+    fn test_to_sql_string_quotes_and_recurses() {
+        assert_eq!(DuckValue::Null.to_sql_string(), "NULL");
+        assert_eq!(DuckValue::Boolean(true).to_sql_string(), "true");
+        assert_eq!(DuckValue::text("it's").to_sql_string(), "'it''s'");
+        let nested = DuckValue::List(vec![
+            DuckValue::List(vec![DuckValue::text("a"), DuckValue::Null]),
+            DuckValue::Struct(HashMap::from([
+                ("n".to_owned(), DuckValue::BigInt(2)),
+                ("s".to_owned(), DuckValue::text("x")),
+            ])),
+        ]);
+        assert_eq!(nested.to_sql_string(), "[['a', NULL], {'n': 2, 's': 'x'}]");
+        assert_eq!(
+            DuckValue::Interval(crate::types::interval::Interval::new(0, 2, 0)).to_sql_string(),
+            "'2 days'::INTERVAL"
+        );
+        assert_eq!(DuckValue::Double(f64::NEG_INFINITY).to_sql_string(), "'-inf'::DOUBLE");
+        assert_eq!(DuckValue::text("\0").to_sql_string(), "chr(0)");
+        assert_eq!(DuckValue::text("a\0\0b").to_sql_string(), "'a' || chr(0) || chr(0) || 'b'");
+    }
+
+    #[test]
+    fn test_to_sql_string_evaluates_back_to_the_value() {
+        let mut conn = crate::connection::Connection::open_in_memory().unwrap();
+        for value in [
+            DuckValue::text("a\0b\0"),
+            DuckValue::text("\0it's"),
+            DuckValue::Double(f64::INFINITY),
+            DuckValue::Double(f64::NEG_INFINITY),
+        ] {
+            let sql = format!("SELECT {} AS v", value.to_sql_string());
+            let row = conn.query(&sql).unwrap().next().unwrap().unwrap();
+            assert_eq!(row.get("v"), Some(&value), "{sql}");
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_to_sql_string_keeps_decimal_scale() {
+        let d = rust_decimal::Decimal::new(150, 2);
+        assert_eq!(DuckValue::Decimal(d).to_sql_string(), "1.50");
+    }
+
     // DuckValue::get / get_mut / contains_key
 
     fn int_map() -> DuckValue {
@@ -1,12 +1,12 @@
 #![allow(non_snake_case)]
 #[cfg(feature = "chrono")]
-use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::mem;
 #[cfg(not(feature = "chrono"))]
-use std::time::{Duration, SystemTime};
+use std::time::SystemTime;
 
 #[cfg(feature = "decimal")]
 use rust_decimal::Decimal;
@@ -102,11 +102,7 @@ pub enum DuckValueRef<'a> {
     Time(crate::types::date_native::DuckTime),
 
     /// The value is an interval.
-    #[cfg(feature = "chrono")]
-    Interval(Duration),
-    /// The value is an interval.
-    #[cfg(not(feature = "chrono"))]
-    Interval(Duration),
+    Interval(crate::types::interval::Interval),
 
     /// The value is a microsecond-precision time with timezone (`TIME_TZ`).
     #[cfg(feature = "chrono")]
@@ -127,6 +123,9 @@ pub enum DuckValueRef<'a> {
     #[cfg(feature = "decimal")]
     /// The value is a Decimal.
     Decimal(Decimal),
+    /// The value is a `DECIMAL` wider than 28 digits, or any `DECIMAL` without the
+    /// `decimal` feature.
+    WideDecimal(crate::types::numeric::WideDecimal),
     /// The value is a blob of data.
     Blob(Blob),
     /// The value is a list
@@ -199,9 +198,6 @@ impl<'a> PartialEq for DuckValueRef<'a> {
             (Time(a), Time(b)) => a == b,
             #[cfg(not(feature = "chrono"))]
             (Time(a), Time(b)) => a == b,
-            #[cfg(feature = "chrono")]
-            (Interval(a), Interval(b)) => a == b,
-            #[cfg(not(feature = "chrono"))]
             (Interval(a), Interval(b)) => a == b,
             #[cfg(feature = "chrono")]
             (TimeTz(a), TimeTz(b)) => a == b,
@@ -215,6 +211,7 @@ impl<'a> PartialEq for DuckValueRef<'a> {
             (Enum(a), Enum(b)) => a == b,
             #[cfg(feature = "decimal")]
             (Decimal(a), Decimal(b)) => a == b,
+            (WideDecimal(a), WideDecimal(b)) => a == b,
             (Blob(a), Blob(b)) => a == b,
             (List(a), List(b)) => a == b,
             (Array(a), Array(b)) => a == b,
@@ -277,9 +274,6 @@ impl<'a> Hash for DuckValueRef<'a> {
             DuckValueRef::Time(t) => t.hash(state),
             #[cfg(not(feature = "chrono"))]
             DuckValueRef::Time(t) => t.hash(state),
-            #[cfg(feature = "chrono")]
-            DuckValueRef::Interval(i) => i.hash(state),
-            #[cfg(not(feature = "chrono"))]
             DuckValueRef::Interval(i) => i.hash(state),
             #[cfg(feature = "chrono")]
             DuckValueRef::TimeTz(t) => t.hash(state),
@@ -293,6 +287,7 @@ impl<'a> Hash for DuckValueRef<'a> {
             DuckValueRef::Enum(s) => s.hash(state),
             #[cfg(feature = "decimal")]
             DuckValueRef::Decimal(d) => d.hash(state),
+            DuckValueRef::WideDecimal(d) => d.hash(state),
             DuckValueRef::Blob(b) => b.hash(state),
             DuckValueRef::List(items) => items.hash(state),
             DuckValueRef::Array(items) => items.hash(state),
@@ -367,9 +362,6 @@ impl<'a> From<&'a DuckValue> for DuckValueRef<'a> {
             DuckValue::Time(t) => DuckValueRef::Time(*t),
             #[cfg(not(feature = "chrono"))]
             DuckValue::Time(t) => DuckValueRef::Time(*t),
-            #[cfg(feature = "chrono")]
-            DuckValue::Interval(i) => DuckValueRef::Interval(*i),
-            #[cfg(not(feature = "chrono"))]
             DuckValue::Interval(i) => DuckValueRef::Interval(*i),
             #[cfg(feature = "chrono")]
             DuckValue::TimeTz(t) => DuckValueRef::TimeTz(*t),
@@ -383,6 +375,7 @@ impl<'a> From<&'a DuckValue> for DuckValueRef<'a> {
             DuckValue::Text(s) => DuckValueRef::Text(Cow::Borrowed(s.as_str())),
             #[cfg(feature = "decimal")]
             DuckValue::Decimal(d) => DuckValueRef::Decimal(*d),
+            DuckValue::WideDecimal(d) => DuckValueRef::WideDecimal(*d),
             DuckValue::Blob(b) => DuckValueRef::Blob(b.clone()),
             DuckValue::List(l) => DuckValueRef::List(l.iter().map(DuckValueRef::from).collect()),
             DuckValue::Enum(e) => DuckValueRef::Enum(Cow::Borrowed(e.as_str())),
@@ -456,9 +449,6 @@ impl<'a> From<DuckValue> for DuckValueRef<'a> {
             DuckValue::Time(t) => DuckValueRef::Time(t),
             #[cfg(not(feature = "chrono"))]
             DuckValue::Time(t) => DuckValueRef::Time(t),
-            #[cfg(feature = "chrono")]
-            DuckValue::Interval(i) => DuckValueRef::Interval(i),
-            #[cfg(not(feature = "chrono"))]
             DuckValue::Interval(i) => DuckValueRef::Interval(i),
             #[cfg(feature = "chrono")]
             DuckValue::TimeTz(t) => DuckValueRef::TimeTz(t),
@@ -473,6 +463,7 @@ impl<'a> From<DuckValue> for DuckValueRef<'a> {
             DuckValue::Blob(b) => DuckValueRef::Blob(b),
             #[cfg(feature = "decimal")]
             DuckValue::Decimal(d) => DuckValueRef::Decimal(d),
+            DuckValue::WideDecimal(d) => DuckValueRef::WideDecimal(d),
             DuckValue::List(items) => {
                 DuckValueRef::List(items.into_iter().map(DuckValueRef::from).collect())
             },
@@ -552,6 +543,14 @@ impl crate::types::appendable::AppendAble for DuckValueRef<'_> {
     ) -> crate::error::Result<()> {
         use crate::error::Error;
         use crate::ffi;
+        use crate::types::appendable::check_rc;
+
+        fn bind_result(
+            rc: ffi::duckdb_state,
+            idx: u64,
+        ) -> crate::error::Result<()> {
+            check_rc(rc, format_args!("bind value to parameter {idx}"))
+        }
 
         /// Convert `self` to DuckValue, call to_duck(), then bind via value path.
         macro_rules! bind_via_to_duck {
@@ -559,18 +558,17 @@ impl crate::types::appendable::AppendAble for DuckValueRef<'_> {
                 let owned = DuckValue::from(&*self);
                 let mut dv = owned.to_duck().map_err(Error::ConversionError)?;
                 // SAFETY: `stmt`/`idx` are valid; `dv` was created by `to_duck()`.
-                unsafe { ffi::duckdb_bind_value(stmt, idx, dv) };
+                let rc = unsafe { ffi::duckdb_bind_value(stmt, idx, dv) };
                 // SAFETY: `dv` was created above; destroy exactly once.
                 unsafe { ffi::duckdb_destroy_value(&mut dv) };
-                return Ok(());
+                return bind_result(rc, idx);
             }};
         }
 
         match self {
             DuckValueRef::Null => {
                 // SAFETY: `stmt` is a valid prepared statement; `idx` is 1-based.
-                unsafe { ffi::duckdb_bind_null(stmt, idx) };
-                Ok(())
+                bind_result(unsafe { ffi::duckdb_bind_null(stmt, idx) }, idx)
             },
             DuckValueRef::Boolean(v) => v.stmt_append(idx, stmt),
             DuckValueRef::TinyInt(v) => v.stmt_append(idx, stmt),
@@ -615,9 +613,6 @@ impl crate::types::appendable::AppendAble for DuckValueRef<'_> {
             | DuckValueRef::TimestampS(st)
             | DuckValueRef::TimestampMs(st)
             | DuckValueRef::TimestampNs(st) => st.stmt_append(idx, stmt),
-            #[cfg(feature = "chrono")]
-            DuckValueRef::Interval(d) => d.stmt_append(idx, stmt),
-            #[cfg(not(feature = "chrono"))]
             DuckValueRef::Interval(d) => d.stmt_append(idx, stmt),
             // TIMESTAMP_TZ: delegate to TimestampTz wrapper which uses duckdb_bind_timestamp_tz.
             #[cfg(feature = "chrono")]
@@ -631,12 +626,14 @@ impl crate::types::appendable::AppendAble for DuckValueRef<'_> {
             DuckValueRef::TimeTz(tz) => tz.stmt_append(idx, stmt),
             #[cfg(not(feature = "chrono"))]
             DuckValueRef::TimeTz(tz) => tz.stmt_append(idx, stmt),
+            // A bare `NaiveTime` would bind as TIME and drop the sub-microsecond digits.
             #[cfg(feature = "chrono")]
-            DuckValueRef::TimeNs(t) => t.stmt_append(idx, stmt),
+            DuckValueRef::TimeNs(t) => crate::types::date_chrono::TimeNs(*t).stmt_append(idx, stmt),
             #[cfg(not(feature = "chrono"))]
             DuckValueRef::TimeNs(t) => t.stmt_append(idx, stmt),
             #[cfg(feature = "decimal")]
             DuckValueRef::Decimal(d) => d.stmt_append(idx, stmt),
+            DuckValueRef::WideDecimal(d) => d.stmt_append(idx, stmt),
 
             // Remaining types go through the value path.
             DuckValueRef::List(_)
@@ -731,9 +728,6 @@ impl crate::types::appendable::AppendAble for DuckValueRef<'_> {
             | DuckValueRef::TimestampS(st)
             | DuckValueRef::TimestampMs(st)
             | DuckValueRef::TimestampNs(st) => st.appender_append(appender),
-            #[cfg(feature = "chrono")]
-            DuckValueRef::Interval(d) => d.appender_append(appender),
-            #[cfg(not(feature = "chrono"))]
             DuckValueRef::Interval(d) => d.appender_append(appender),
             // TIMESTAMP_TZ: delegate to TimestampTz wrapper (value path inside it).
             #[cfg(feature = "chrono")]
@@ -755,8 +749,13 @@ impl crate::types::appendable::AppendAble for DuckValueRef<'_> {
             },
             #[cfg(feature = "decimal")]
             DuckValueRef::Decimal(d) => d.appender_append(appender),
+            DuckValueRef::WideDecimal(d) => d.appender_append(appender),
         }
     }
+
+    fn appender_column_count(&self) -> Option<usize> {
+        Some(1)
+    }
 }
 
 //
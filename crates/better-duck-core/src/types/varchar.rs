@@ -1,15 +1,64 @@
 use std::{
-    ffi::{CStr, CString},
+    ffi::CStr,
     os::raw::{c_char, c_void},
 };
 
 use crate::{
-    ffi::{duckdb_create_varchar, duckdb_free, duckdb_get_varchar, duckdb_value},
-    types::appendable::AppendAble,
+    ffi::{
+        duckdb_append_varchar_length, duckdb_bind_varchar_length, duckdb_create_varchar_length,
+        duckdb_free, duckdb_get_varchar, duckdb_value,
+    },
+    types::appendable::{check_rc, AppendAble},
 };
 
 use super::{DuckDBConversionError, DuckDialect};
 
+/// Creates a VARCHAR `duckdb_value` from `s` by length, so embedded NUL bytes are kept.
+pub(super) fn str_to_duck(s: &str) -> Result<duckdb_value, DuckDBConversionError> {
+    // SAFETY: `s` is valid UTF-8 of `s.len()` bytes; DuckDB copies the data.
+    let value =
+        unsafe { duckdb_create_varchar_length(s.as_ptr() as *const c_char, s.len() as u64) };
+    if value.is_null() {
+        return Err(DuckDBConversionError::ConversionError(
+            "DuckDB rejected the VARCHAR value".into(),
+        ));
+    }
+    Ok(value)
+}
+
+/// Appends `s` to the current appender row by length.
+pub(super) fn append_str(
+    s: &str,
+    appender: crate::ffi::duckdb_appender,
+) -> crate::error::Result<()> {
+    // SAFETY: `s.as_ptr()` is valid UTF-8 data of `s.len()` bytes.
+    // `duckdb_append_varchar_length` copies the data and does not retain the pointer.
+    let rc = unsafe {
+        duckdb_append_varchar_length(appender, s.as_ptr() as *const c_char, s.len() as u64)
+    };
+    check_rc(rc, "append VARCHAR value")
+}
+
+/// Binds `s` to the 1-based parameter `idx` by length.
+pub(super) fn bind_str(
+    s: &str,
+    idx: u64,
+    stmt: crate::ffi::duckdb_prepared_statement,
+) -> crate::error::Result<()> {
+    // SAFETY: `s.as_ptr()` is valid UTF-8 of `s.len()` bytes.
+    // `duckdb_bind_varchar_length` copies the data and does not retain the pointer.
+    let rc = unsafe {
+        duckdb_bind_varchar_length(stmt, idx, s.as_ptr() as *const c_char, s.len() as u64)
+    };
+    check_rc(rc, format_args!("bind VARCHAR to parameter {idx}"))
+}
+
+/// Copies `s` into a `String`, failing with [`DuckDBConversionError::Utf8`] if it is
+/// not valid UTF-8.
+fn cstr_to_string(s: &CStr) -> Result<String, DuckDBConversionError> {
+    Ok(s.to_str()?.to_owned())
+}
+
 impl DuckDialect for String {
     fn from_duck(value: duckdb_value) -> Result<Self, DuckDBConversionError> {
         // SAFETY: `value` is a valid duckdb_value of VARCHAR type. `duckdb_get_varchar`
@@ -20,21 +69,14 @@ impl DuckDialect for String {
             if c_str.is_null() {
                 return Err(DuckDBConversionError::NullValue);
             }
-            let result = CStr::from_ptr(c_str)
-                .to_str()
-                .map_err(|e| DuckDBConversionError::ConversionError(e.to_string()))?
-                .to_string();
+            let result = cstr_to_string(CStr::from_ptr(c_str));
             duckdb_free(c_str as *mut c_void);
-            Ok(result)
+            result
         }
     }
 
     fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
-        let c_str = CString::new(self.as_str())
-            .map_err(|e| DuckDBConversionError::ConversionError(e.to_string()))?;
-        // SAFETY: `c_str` is a valid null-terminated C string. `duckdb_create_varchar`
-        // copies the string contents internally.
-        Ok(unsafe { duckdb_create_varchar(c_str.as_ptr()) })
+        str_to_duck(self)
     }
 }
 
@@ -43,17 +85,7 @@ impl AppendAble for String {
         &mut self,
         appender: crate::ffi::duckdb_appender,
     ) -> crate::error::Result<()> {
-        let bytes = self.as_bytes();
-        // SAFETY: `bytes.as_ptr()` is valid UTF-8 data of `bytes.len()` bytes.
-        // `duckdb_append_varchar_length` copies the data and does not retain the pointer.
-        unsafe {
-            crate::ffi::duckdb_append_varchar_length(
-                appender,
-                bytes.as_ptr() as *const c_char,
-                bytes.len() as u64,
-            )
-        };
-        Ok(())
+        append_str(self, appender)
     }
 
     fn stmt_append(
@@ -61,17 +93,66 @@ impl AppendAble for String {
         idx: u64,
         stmt: crate::ffi::duckdb_prepared_statement,
     ) -> crate::error::Result<()> {
-        let bytes = self.as_bytes();
-        // SAFETY: `bytes.as_ptr()` is valid UTF-8 of `bytes.len()` bytes.
-        // `duckdb_bind_varchar_length` copies the data and does not retain the pointer.
-        unsafe {
-            crate::ffi::duckdb_bind_varchar_length(
-                stmt,
-                idx,
-                bytes.as_ptr() as *const c_char,
-                bytes.len() as u64,
-            )
-        };
-        Ok(())
+        bind_str(self, idx, stmt)
+    }
+}
+
+/// Write-only: a borrowed `&str` can be bound without allocating a `String`, but
+/// `from_duck` always fails because DuckDB's copy of the text is freed before it could
+/// be borrowed. Read into a [`String`] instead.
+impl DuckDialect for &str {
+    fn from_duck(_value: duckdb_value) -> Result<Self, DuckDBConversionError> {
+        Err(DuckDBConversionError::ConversionError(
+            "cannot borrow a &str from a DuckDB value; read a String instead".into(),
+        ))
+    }
+
+    fn to_duck(&self) -> Result<duckdb_value, DuckDBConversionError> {
+        str_to_duck(self)
+    }
+}
+
+/// Binds/appends a string slice by length, so embedded NUL bytes survive.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, params, types::value::DuckValue};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// let row = conn.execute_with("SELECT ? AS s", params!["duck"])?.next().unwrap()?;
+/// assert_eq!(row.get("s"), Some(&DuckValue::text("duck")));
+/// # Ok(())
+/// # }
+/// ```
+impl AppendAble for &str {
+    fn appender_append(
+        &mut self,
+        appender: crate::ffi::duckdb_appender,
+    ) -> crate::error::Result<()> {
+        append_str(self, appender)
+    }
+
+    fn stmt_append(
+        &mut self,
+        idx: u64,
+        stmt: crate::ffi::duckdb_prepared_statement,
+    ) -> crate::error::Result<()> {
+        bind_str(self, idx, stmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_cstr_to_string_reports_invalid_utf8() {
+        assert_eq!(cstr_to_string(c"h\u{e9}llo").unwrap(), "h\u{e9}llo");
+
+        let err = cstr_to_string(c"ab\xff").unwrap_err();
+        assert!(matches!(err, DuckDBConversionError::Utf8(e) if e.valid_up_to() == 2), "{err:?}");
+        assert!(Error::from(err).to_string().starts_with("Invalid UTF-8"));
     }
 }
@@ -0,0 +1,287 @@
+//! Scalar user-defined functions backed by Rust closures.
+//!
+//! A [`ScalarFunction`] wraps a closure over numeric or boolean arguments, such as
+//! `|a: i64, b: i64| a + b`, and registers it with
+//! [`Connection::register_scalar_function`](crate::connection::Connection::register_scalar_function).
+//! The SQL parameter and return types follow from the closure's signature through
+//! [`VectorPrimitive::DUCK_TYPE`]. A `NULL` in any argument makes the result `NULL`
+//! without calling the closure.
+
+use std::{
+    ffi::{c_void, CString},
+    marker::PhantomData,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+use crate::{
+    error::{Error, Result},
+    ffi::{
+        duckdb_create_logical_type, duckdb_create_scalar_function, duckdb_data_chunk,
+        duckdb_data_chunk_get_size, duckdb_data_chunk_get_vector, duckdb_destroy_logical_type,
+        duckdb_destroy_scalar_function, duckdb_function_info, duckdb_register_scalar_function,
+        duckdb_scalar_function, duckdb_scalar_function_add_parameter,
+        duckdb_scalar_function_get_extra_info, duckdb_scalar_function_set_error,
+        duckdb_scalar_function_set_extra_info, duckdb_scalar_function_set_function,
+        duckdb_scalar_function_set_name, duckdb_scalar_function_set_return_type,
+        duckdb_scalar_function_set_volatile, duckdb_validity_row_is_valid,
+        duckdb_validity_set_row_invalid, duckdb_vector, duckdb_vector_ensure_validity_writable,
+        duckdb_vector_get_data, duckdb_vector_get_validity, DuckDBSuccess, DUCKDB_TYPE,
+    },
+    raw::connection::RawConnection,
+    types::VectorPrimitive,
+};
+
+/// A closure that can run as a scalar function, implemented for `Fn`s of one to six
+/// [`VectorPrimitive`] arguments returning a `VectorPrimitive`.
+///
+/// `Args` is the tuple of argument types; it only exists to keep the implementations
+/// for different arities apart and is always inferred.
+pub trait ScalarClosure<Args>: Send + Sync + 'static {
+    /// The DuckDB type of each argument, in order.
+    fn param_types() -> Vec<DUCKDB_TYPE>;
+
+    /// The DuckDB type of the result.
+    fn return_type() -> DUCKDB_TYPE;
+
+    /// Evaluates the closure for every row of `input`, writing the results to `output`.
+    ///
+    /// # Safety
+    ///
+    /// `input` must be a flat chunk whose columns have the types in
+    /// [`param_types`](Self::param_types), and `output` a flat vector of
+    /// [`return_type`](Self::return_type) with room for every row.
+    unsafe fn invoke(
+        &self,
+        input: duckdb_data_chunk,
+        output: duckdb_vector,
+    );
+}
+
+/// One flat input column of a scalar function call.
+struct Column<T> {
+    data: *const T,
+    validity: *mut u64,
+}
+
+impl<T: VectorPrimitive> Column<T> {
+    /// Reads column `idx` of `chunk`.
+    ///
+    /// # Safety
+    ///
+    /// `chunk` must be valid, flat, and have a column `idx` of type `T::DUCK_TYPE`.
+    unsafe fn new(
+        chunk: duckdb_data_chunk,
+        idx: u64,
+    ) -> Column<T> {
+        // SAFETY: guaranteed by the caller.
+        unsafe {
+            let vector = duckdb_data_chunk_get_vector(chunk, idx);
+            Column {
+                data: duckdb_vector_get_data(vector) as *const T,
+                validity: duckdb_vector_get_validity(vector),
+            }
+        }
+    }
+
+    /// Returns the value at `row`, or `None` if it is `NULL`.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be less than the chunk's size.
+    unsafe fn get(
+        &self,
+        row: u64,
+    ) -> Option<T> {
+        // SAFETY: `row` is in bounds; by the `VectorPrimitive` contract the slot of a
+        // valid row holds a `T`. NULL slots are never read.
+        unsafe {
+            duckdb_validity_row_is_valid(self.validity, row).then(|| *self.data.add(row as usize))
+        }
+    }
+}
+
+macro_rules! impl_scalar_closure {
+    ($($arg:ident $var:ident $idx:literal),+) => {
+        impl<F, R, $($arg),+> ScalarClosure<($($arg,)+)> for F
+        where
+            F: Fn($($arg),+) -> R + Send + Sync + 'static,
+            R: VectorPrimitive,
+            $($arg: VectorPrimitive),+
+        {
+            fn param_types() -> Vec<DUCKDB_TYPE> {
+                vec![$($arg::DUCK_TYPE),+]
+            }
+
+            fn return_type() -> DUCKDB_TYPE {
+                R::DUCK_TYPE
+            }
+
+            unsafe fn invoke(
+                &self,
+                input: duckdb_data_chunk,
+                output: duckdb_vector,
+            ) {
+                // SAFETY: guaranteed by the caller; every index below is < the chunk size.
+                unsafe {
+                    $(let $var = Column::<$arg>::new(input, $idx);)+
+                    let out = duckdb_vector_get_data(output) as *mut R;
+                    for row in 0..duckdb_data_chunk_get_size(input) {
+                        match ($($var.get(row),)+) {
+                            ($(Some($var),)+) => *out.add(row as usize) = self($($var),+),
+                            _ => {
+                                duckdb_vector_ensure_validity_writable(output);
+                                duckdb_validity_set_row_invalid(
+                                    duckdb_vector_get_validity(output),
+                                    row,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_scalar_closure!(A a 0);
+impl_scalar_closure!(A a 0, B b 1);
+impl_scalar_closure!(A a 0, B b 1, C c 2);
+impl_scalar_closure!(A a 0, B b 1, C c 2, D d 3);
+impl_scalar_closure!(A a 0, B b 1, C c 2, D d 3, E e 4);
+impl_scalar_closure!(A a 0, B b 1, C c 2, D d 3, E e 4, G g 5);
+
+/// The C callback DuckDB runs for each chunk; the closure is the function's extra info.
+///
+/// A panic in the closure is caught and reported as a query error.
+unsafe extern "C" fn call_closure<F: ScalarClosure<Args>, Args>(
+    info: duckdb_function_info,
+    input: duckdb_data_chunk,
+    output: duckdb_vector,
+) {
+    // SAFETY: the extra info was set to a `Box<F>` in `ScalarFunction::new` and lives
+    // as long as the function. DuckDB flattens `input` and sizes `output` before the
+    // call, and the parameter and return types were declared from `F`.
+    let outcome = catch_unwind(AssertUnwindSafe(|| unsafe {
+        let f = &*(duckdb_scalar_function_get_extra_info(info) as *const F);
+        f.invoke(input, output);
+    }));
+    if let Err(panic) = outcome {
+        let msg = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "scalar function panicked".to_owned());
+        let msg = CString::new(msg.replace('\0', " ")).unwrap_or_default();
+        // SAFETY: `info` is valid for this call; DuckDB copies the message.
+        unsafe { duckdb_scalar_function_set_error(info, msg.as_ptr()) };
+    }
+}
+
+/// Frees the boxed closure when DuckDB drops the function.
+unsafe extern "C" fn drop_closure<F>(data: *mut c_void) {
+    // SAFETY: `data` is the `Box<F>` leaked in `ScalarFunction::new`, freed only here.
+    drop(unsafe { Box::from_raw(data as *mut F) });
+}
+
+/// A scalar function implemented by a Rust closure, ready to be registered on a
+/// connection.
+///
+/// # Example
+///
+/// ```rust
+/// # use better_duck_core::{connection::Connection, udf::ScalarFunction};
+/// # fn main() -> better_duck_core::error::Result<()> {
+/// let mut conn = Connection::open_in_memory()?;
+/// conn.register_scalar_function(ScalarFunction::new("add_two", |a: i64, b: i64| a + b)?)?;
+/// assert_eq!(conn.query_scalar::<i64>("SELECT add_two(40, 2)")?, 42);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ScalarFunction {
+    name: String,
+    raw: duckdb_scalar_function,
+    /// The raw function is not thread-safe.
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl ScalarFunction {
+    /// Creates a function called `name` that evaluates `f` for every row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NulError`] if `name` contains a nul byte.
+    pub fn new<F, Args>(
+        name: &str,
+        f: F,
+    ) -> Result<ScalarFunction>
+    where
+        F: ScalarClosure<Args>,
+    {
+        let c_name = CString::new(name)?;
+        // SAFETY: creating a scalar function has no preconditions.
+        let raw = unsafe { duckdb_create_scalar_function() };
+        let function = ScalarFunction { name: name.to_owned(), raw, _not_send: PhantomData };
+        // SAFETY: `raw` is a valid scalar function. The name and logical types are
+        // copied by DuckDB, so they are freed right after. The boxed closure is owned
+        // by the function from here on and freed by `drop_closure`.
+        unsafe {
+            duckdb_scalar_function_set_name(raw, c_name.as_ptr());
+            for param in F::param_types() {
+                let mut lt = duckdb_create_logical_type(param);
+                duckdb_scalar_function_add_parameter(raw, lt);
+                duckdb_destroy_logical_type(&mut lt);
+            }
+            let mut lt = duckdb_create_logical_type(F::return_type());
+            duckdb_scalar_function_set_return_type(raw, lt);
+            duckdb_destroy_logical_type(&mut lt);
+            duckdb_scalar_function_set_function(raw, Some(call_closure::<F, Args>));
+            duckdb_scalar_function_set_extra_info(
+                raw,
+                Box::into_raw(Box::new(f)) as *mut c_void,
+                Some(drop_closure::<F>),
+            );
+        }
+        Ok(function)
+    }
+
+    /// Marks the function as volatile, so DuckDB calls it for every row instead of
+    /// folding calls with constant arguments.
+    pub fn volatile(self) -> ScalarFunction {
+        // SAFETY: `self.raw` is a valid scalar function.
+        unsafe { duckdb_scalar_function_set_volatile(self.raw) };
+        self
+    }
+
+    /// Returns the function's SQL name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Registers the function on `con`'s database.
+    pub(crate) fn register(
+        self,
+        con: &RawConnection,
+    ) -> Result<()> {
+        // SAFETY: `con.con` is an open connection and `self.raw` a valid function;
+        // DuckDB copies the function, so `self` is destroyed afterwards as usual.
+        let r = unsafe { duckdb_register_scalar_function(con.con, self.raw) };
+        if r == DuckDBSuccess {
+            Ok(())
+        } else {
+            Err(Error::InvalidFunction(
+                self.name.clone(),
+                "DuckDB rejected its registration".into(),
+            ))
+        }
+    }
+}
+
+impl Drop for ScalarFunction {
+    fn drop(&mut self) {
+        // SAFETY: `self.raw` is a valid scalar function destroyed only here; a registered
+        // copy keeps its own reference to the closure.
+        unsafe { duckdb_destroy_scalar_function(&mut self.raw) };
+        self.raw = ptr::null_mut();
+    }
+}
@@ -1,7 +1,14 @@
 #![allow(missing_docs)]
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use better_duck_core::{connection::Connection, types::value::DuckValue};
+use better_duck_core::{
+    connection::Connection,
+    params,
+    types::{
+        from_struct::{struct_field, FromStruct},
+        value::DuckValue,
+    },
+};
 
 fn open() -> Connection {
     Connection::open_in_memory().unwrap()
@@ -54,6 +61,45 @@ fn list_with_null_element() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[test]
+fn bind_vec_as_list_parameter() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let mut result = conn.execute_with(
+        "SELECT list_contains(?, 2) AS has_two, 3 = ANY(?) AS any_three",
+        params![vec![1i32, 2, 3], vec![3i32]],
+    )?;
+    let row = result.next().unwrap()?;
+    assert_eq!(row.get("has_two"), Some(&DuckValue::Boolean(true)));
+    assert_eq!(row.get("any_three"), Some(&DuckValue::Boolean(true)));
+
+    let mut result = conn.execute_with(
+        "SELECT len(?::INTEGER[]) AS n, list_contains(?, 2) AS has_two",
+        params![Vec::<i32>::new(), Vec::<i32>::new()],
+    )?;
+    let row = result.next().unwrap()?;
+    assert_eq!(row.get("n"), Some(&DuckValue::BigInt(0)));
+    assert_eq!(row.get("has_two"), Some(&DuckValue::Boolean(false)));
+    Ok(())
+}
+
+#[test]
+fn append_empty_vec_to_list_column() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (id INTEGER, tags VARCHAR[])")?;
+    {
+        let mut appender = conn.appender("t", "main")?;
+        appender.append(&mut (1i32, Vec::<String>::new()))?;
+        appender.append(&mut (2i32, vec![String::from("a")]))?;
+    }
+    let mut result = conn.execute("SELECT tags FROM t ORDER BY id")?;
+    assert_eq!(result.next().unwrap()?.get("tags"), Some(&DuckValue::List(vec![])));
+    assert_eq!(
+        result.next().unwrap()?.get("tags"),
+        Some(&DuckValue::List(vec![DuckValue::text("a")]))
+    );
+    Ok(())
+}
+
 #[test]
 fn empty_list() -> better_duck_core::error::Result<()> {
     let mut conn = open();
@@ -89,6 +135,47 @@ fn list_of_list_with_null() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[test]
+fn lists_of_different_lengths_per_row() -> better_duck_core::error::Result<()> {
+    // Each row must read only its own `offset..offset + length` slice of the shared
+    // child vector; 3000 rows also spans more than one chunk.
+    let mut conn = open();
+    let result = conn.execute("SELECT i, range(i % 5) AS v FROM range(3000) t(i) ORDER BY i")?;
+    let mut seen = 0;
+    for (i, row) in result.enumerate() {
+        let row = row?;
+        let expected = DuckValue::List((0..(i as i64 % 5)).map(DuckValue::BigInt).collect());
+        assert_eq!(row.get("v"), Some(&expected), "row {i}");
+        seen += 1;
+    }
+    assert_eq!(seen, 3000);
+    Ok(())
+}
+
+#[test]
+fn nested_lists_of_different_lengths_per_row() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let rows: Vec<_> = conn
+        .execute(
+            "SELECT * FROM (VALUES (1, [[1], [2, 3]]), (2, []), (3, [[], [4, 5, 6]]), \
+             (4, NULL), (5, [[7]])) t(id, v) ORDER BY id",
+        )?
+        .collect::<better_duck_core::error::Result<_>>()?;
+    let ints = |xs: &[i32]| DuckValue::List(xs.iter().copied().map(DuckValue::Int).collect());
+    let expected = [
+        DuckValue::List(vec![ints(&[1]), ints(&[2, 3])]),
+        DuckValue::List(vec![]),
+        DuckValue::List(vec![ints(&[]), ints(&[4, 5, 6])]),
+        DuckValue::Null,
+        DuckValue::List(vec![ints(&[7])]),
+    ];
+    assert_eq!(rows.len(), expected.len());
+    for (row, expected) in rows.iter().zip(&expected) {
+        assert_eq!(row.get("v"), Some(expected));
+    }
+    Ok(())
+}
+
 // ARRAY (fixed-size)
 
 #[test]
@@ -105,6 +192,27 @@ fn fixed_array_integers() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[test]
+fn fixed_array_rows_read_their_own_elements() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE arr_rows (id INTEGER, v INTEGER[3])")?;
+    conn.execute_batch("INSERT INTO arr_rows VALUES (1, [1, 2, 3]), (2, [4, NULL, 6])")?;
+    let mut result = conn.execute("SELECT v FROM arr_rows ORDER BY id")?;
+    let first = result.next().unwrap()?;
+    let second = result.next().unwrap()?;
+    assert!(result.next().is_none());
+    let array = |items: [DuckValue; 3]| DuckValue::Array(items.into());
+    assert_eq!(
+        first.get("v"),
+        Some(&array([DuckValue::Int(1), DuckValue::Int(2), DuckValue::Int(3)]))
+    );
+    assert_eq!(
+        second.get("v"),
+        Some(&array([DuckValue::Int(4), DuckValue::Null, DuckValue::Int(6)]))
+    );
+    Ok(())
+}
+
 #[test]
 fn fixed_array_text() -> better_duck_core::error::Result<()> {
     let mut conn = open();
@@ -250,6 +358,28 @@ fn list_of_struct() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[test]
+fn list_of_struct_different_lengths_per_row() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let rows: Vec<_> = conn
+        .execute(
+            "SELECT * FROM (VALUES (1, [{'x': 1}, {'x': 2}, {'x': 3}]), (2, [{'x': 4}]), \
+             (3, [{'x': 5}, {'x': 6}])) t(id, v) ORDER BY id",
+        )?
+        .collect::<better_duck_core::error::Result<_>>()?;
+    let structs = |xs: &[i32]| {
+        DuckValue::List(
+            xs.iter()
+                .map(|x| DuckValue::Struct(HashMap::from([("x".to_string(), DuckValue::Int(*x))])))
+                .collect(),
+        )
+    };
+    assert_eq!(rows[0].get("v"), Some(&structs(&[1, 2, 3])));
+    assert_eq!(rows[1].get("v"), Some(&structs(&[4])));
+    assert_eq!(rows[2].get("v"), Some(&structs(&[5, 6])));
+    Ok(())
+}
+
 #[test]
 fn list_of_map() -> better_duck_core::error::Result<()> {
     let mut conn = open();
@@ -342,6 +472,60 @@ fn union_null_row() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[test]
+fn struct_bound_from_btree_map() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let fields = BTreeMap::from([
+        ("zeta".to_string(), DuckValue::text("duck")),
+        ("alpha".to_string(), DuckValue::Int(3)),
+    ]);
+    let row = conn
+        .execute_with("SELECT ? AS s, typeof(?) AS ty", params![fields.clone(), fields.clone()])?
+        .next()
+        .unwrap()?;
+    let expected = DuckValue::Struct(HashMap::from_iter(fields));
+    assert_eq!(row.get("s"), Some(&expected));
+    // Fields are declared in key order.
+    assert_eq!(row.get("ty"), Some(&DuckValue::text("STRUCT(alpha INTEGER, zeta VARCHAR)")));
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct Item {
+    a: i32,
+    b: String,
+}
+
+impl FromStruct for Item {
+    fn from_struct(fields: &HashMap<String, DuckValue>) -> better_duck_core::error::Result<Self> {
+        Ok(Item {
+            a: struct_field(fields, "a")?.try_into()?,
+            b: struct_field(fields, "b")?.try_into()?,
+        })
+    }
+}
+
+#[test]
+fn list_of_struct_into_vec() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let items: Vec<Item> = conn
+        .query_row("SELECT [{'a': 1, 'b': 'x'}, {'a': 2, 'b': 'y'}] AS items", |row| {
+            row.get_struct_list("items")
+        })?;
+    assert_eq!(items, [Item { a: 1, b: "x".into() }, Item { a: 2, b: "y".into() }]);
+    Ok(())
+}
+
+#[test]
+fn list_of_struct_missing_field_errors() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let outcome =
+        conn.query_row("SELECT [{'a': 1}] AS items", |row| row.get_struct_list::<Item>("items"));
+    let err = outcome.expect_err("field b is missing");
+    assert!(err.to_string().contains("b"), "unexpected error: {err}");
+    Ok(())
+}
+
 // ENUM
 
 #[test]
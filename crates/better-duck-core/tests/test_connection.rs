@@ -1 +1,534 @@
 #![allow(missing_docs)]
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use better_duck_core::{
+    connection::{Connection, InterruptHandle, SyncConnection},
+    error::{DuckDBConversionError, DuckDBErrorType, Error},
+    params,
+    types::value::DuckValue,
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn sync_connection_is_send_and_sync() {
+    assert_send_sync::<SyncConnection>();
+    assert_send_sync::<Arc<SyncConnection>>();
+    assert_send_sync::<InterruptHandle>();
+}
+
+#[test]
+fn sync_connection_shared_between_threads() -> better_duck_core::error::Result<()> {
+    let conn = Arc::new(SyncConnection::open_in_memory()?);
+    conn.execute_batch("CREATE TABLE t (worker INTEGER, v BIGINT)")?;
+
+    let handles: Vec<_> = (0..2)
+        .map(|worker| {
+            let conn = Arc::clone(&conn);
+            // `Error` is not `Send`, so each worker unwraps instead of returning it.
+            thread::spawn(move || {
+                conn.execute_batch(format!(
+                    "INSERT INTO t SELECT {worker}, range FROM range(1000)"
+                ))
+                .unwrap();
+                let sum = conn
+                    .query_row(
+                        format!("SELECT sum(v)::BIGINT AS s FROM t WHERE worker = {worker}"),
+                        |row| Ok(row.get("s").cloned()),
+                    )
+                    .unwrap();
+                assert_eq!(sum, Some(DuckValue::BigInt(499_500)));
+                conn.query("SELECT * FROM t").unwrap().count()
+            })
+        })
+        .collect();
+    for handle in handles {
+        let seen = handle.join().expect("worker thread panicked");
+        assert!((1000..=2000).contains(&seen), "unexpected row count {seen}");
+    }
+
+    let total = conn.query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))?;
+    assert_eq!(total, Some(DuckValue::BigInt(2000)));
+    Ok(())
+}
+
+#[test]
+fn parallel_appender_ingests_disjoint_ranges() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (id BIGINT)")?;
+
+    let appenders = conn.parallel_appender("t", "main", 2)?;
+    assert_eq!(appenders.len(), 2);
+    let handles: Vec<_> = appenders
+        .into_iter()
+        .enumerate()
+        .map(|(part, mut appender)| {
+            thread::spawn(move || {
+                let start = part as i64 * 10_000;
+                for mut id in start..start + 10_000 {
+                    appender.append(&mut id).unwrap();
+                }
+                appender.save().unwrap();
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("appender thread panicked");
+    }
+
+    let row = conn.query_row("SELECT count(*) AS n, count(DISTINCT id) AS d FROM t", |row| {
+        Ok((row.get("n").cloned(), row.get("d").cloned()))
+    })?;
+    assert_eq!(row, (Some(DuckValue::BigInt(20_000)), Some(DuckValue::BigInt(20_000))));
+    assert!(conn.parallel_appender("missing", "main", 2).is_err());
+    Ok(())
+}
+
+#[test]
+fn primary_key_violation_is_classified_as_constraint() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER PRIMARY KEY)")?;
+    conn.execute_batch("INSERT INTO t VALUES (1)")?;
+
+    let err = conn.execute_batch("INSERT INTO t VALUES (1)").unwrap_err();
+    assert_eq!(err.duckdb_error_type(), Some(DuckDBErrorType::Constraint));
+    assert!(err.is_constraint_violation());
+
+    // The same failure through a prepared statement is classified the same way.
+    let err = conn
+        .execute_with("INSERT INTO t VALUES (?)", params![1i32])
+        .err()
+        .expect("duplicate key is rejected");
+    assert!(err.is_constraint_violation(), "unexpected error: {err}");
+
+    // So is a duplicate found when an appender flushes.
+    let mut appender = conn.appender("t", "main")?;
+    appender.append(&mut 1i32)?;
+    let err = appender.save().unwrap_err();
+    assert!(err.is_constraint_violation(), "unexpected error: {err}");
+    drop(appender);
+
+    let err = conn.query("INSERT INTO t VALUES (").err().expect("syntax error");
+    assert_eq!(err.duckdb_error_type(), Some(DuckDBErrorType::Parser));
+    assert!(!err.is_constraint_violation());
+    Ok(())
+}
+
+#[test]
+fn interrupt_aborts_a_running_query() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let handle = conn.interrupt_handle();
+    let done = AtomicBool::new(false);
+
+    let result = thread::scope(|s| {
+        s.spawn(|| {
+            // An interrupt that lands before the query starts is discarded, so keep
+            // interrupting until the query has returned.
+            while !done.load(Ordering::Acquire) {
+                thread::sleep(Duration::from_millis(20));
+                handle.interrupt();
+            }
+        });
+        let result = conn.execute_batch("SELECT sum(i) FROM range(1000000000000) t(i)");
+        done.store(true, Ordering::Release);
+        result
+    });
+
+    let err = result.unwrap_err();
+    assert_eq!(err.duckdb_error_type(), Some(DuckDBErrorType::Interrupt), "{err}");
+    // The connection stays usable afterwards.
+    let one = conn.query_row("SELECT 1 AS one", |row| Ok(row.get("one").cloned()))?;
+    assert_eq!(one, Some(DuckValue::Int(1)));
+    Ok(())
+}
+
+#[test]
+fn interrupt_when_idle_or_closed_is_a_no_op() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let handle = conn.interrupt_handle();
+    conn.execute_batch("SELECT 1")?;
+
+    handle.interrupt();
+    let n =
+        conn.query_row("SELECT count(*) AS n FROM range(10)", |row| Ok(row.get("n").cloned()))?;
+    assert_eq!(n, Some(DuckValue::BigInt(10)));
+
+    conn.close()?;
+    handle.interrupt();
+    drop(conn);
+    handle.clone().interrupt();
+    Ok(())
+}
+
+#[test]
+fn execute_batch_reports_the_failing_statement() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let err = conn
+        .execute_batch(
+            "CREATE TABLE t (id INTEGER); \
+             INSERT INTO no_such_table VALUES (1); \
+             INSERT INTO t VALUES (2)",
+        )
+        .unwrap_err();
+    match &err {
+        Error::BatchStatementFailed(index, inner) => {
+            assert_eq!(*index, 1);
+            // DuckDB reports no category for a statement that fails to prepare.
+            assert_eq!(inner.duckdb_error_type(), None);
+        },
+        other => panic!("expected BatchStatementFailed, got {other:?}"),
+    }
+    assert!(err.to_string().starts_with("Statement 1 of the batch failed: "), "{err}");
+
+    // The first statement ran; the one after the failure did not.
+    let n = conn.query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))?;
+    assert_eq!(n, Some(DuckValue::BigInt(0)));
+
+    // A statement that fails while running keeps DuckDB's category.
+    let err = conn
+        .execute_batch("CREATE TABLE u (id INTEGER PRIMARY KEY); INSERT INTO u VALUES (1), (1)")
+        .unwrap_err();
+    assert!(matches!(err, Error::BatchStatementFailed(1, _)), "{err:?}");
+    assert_eq!(err.duckdb_error_type(), Some(DuckDBErrorType::Constraint));
+    Ok(())
+}
+
+#[test]
+fn execute_batch_runs_statements_that_depend_on_each_other() -> better_duck_core::error::Result<()>
+{
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(
+        "CREATE TABLE t (id INTEGER);\n\
+         INSERT INTO t VALUES (1), (2);\n\
+         CREATE VIEW v AS SELECT sum(id) AS total FROM t;",
+    )?;
+    let total = conn.query_row("SELECT total FROM v", |row| Ok(row.get("total").cloned()))?;
+    assert_eq!(total, Some(DuckValue::HugeInt(3)));
+
+    // Nothing to run is not an error; a syntax error fails before anything runs.
+    conn.execute_batch("")?;
+    let err = conn.execute_batch("INSERT INTO t VALUES (3); SELEC 1").unwrap_err();
+    assert!(matches!(err, Error::DuckDBFailure { .. }), "{err:?}");
+    let n = conn.query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))?;
+    assert_eq!(n, Some(DuckValue::BigInt(2)));
+    Ok(())
+}
+
+#[test]
+fn open_read_only_rejects_writes() -> better_duck_core::error::Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ro.duckdb");
+    {
+        let mut conn = Connection::open(&path)?;
+        conn.execute_batch("CREATE TABLE t (id INTEGER); INSERT INTO t VALUES (7)")?;
+    }
+
+    let mut conn = Connection::open_read_only(&path)?;
+    let id = conn.query_row("SELECT id FROM t", |row| Ok(row.get("id").cloned()))?;
+    assert_eq!(id, Some(DuckValue::Int(7)));
+    let err = conn.execute_batch("CREATE TABLE u (id INTEGER)").unwrap_err();
+    assert!(err.to_string().contains("read-only"), "{err}");
+    Ok(())
+}
+
+#[test]
+fn shutdown_checkpoints_file_database() -> better_duck_core::error::Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("durable.duckdb");
+    let mut conn = Connection::open(&path)?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER); INSERT INTO t VALUES (1), (2)")?;
+    conn.shutdown()?;
+    assert!(!dir.path().join("durable.duckdb.wal").exists());
+
+    let mut conn = Connection::open_read_only(&path)?;
+    let n = conn.query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))?;
+    assert_eq!(n, Some(DuckValue::BigInt(2)));
+    conn.shutdown()?;
+    Ok(())
+}
+
+#[test]
+fn checkpoint_moves_the_wal_into_the_database_file() -> better_duck_core::error::Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("writer.duckdb");
+    let wal = dir.path().join("writer.duckdb.wal");
+    let wal_len = || std::fs::metadata(&wal).map(|m| m.len()).unwrap_or(0);
+    let mut conn = Connection::open(&path)?;
+    conn.checkpoint()?;
+    let db_len = std::fs::metadata(&path).unwrap().len();
+
+    conn.execute_batch("CREATE TABLE t AS SELECT range AS id FROM range(100000)")?;
+    assert!(wal_len() > 0);
+    conn.checkpoint()?;
+    assert_eq!(wal_len(), 0);
+    assert!(std::fs::metadata(&path).unwrap().len() > db_len);
+
+    conn.execute_batch("INSERT INTO t VALUES (-1)")?;
+    assert!(wal_len() > 0);
+    conn.force_checkpoint()?;
+    assert_eq!(wal_len(), 0);
+    assert_eq!(conn.query_scalar::<i64>("SELECT count(*) FROM t")?, 100_001);
+    Ok(())
+}
+
+#[test]
+fn attach_queries_tables_across_databases() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t AS SELECT 1 AS x")?;
+    conn.attach(":memory:", "other", false)?;
+    conn.execute_batch("CREATE TABLE other.t AS SELECT 41 AS x")?;
+    assert_eq!(conn.query_scalar::<i32>("SELECT x FROM other.t")?, 41);
+    assert_eq!(conn.query_scalar::<i32>("SELECT a.x + b.x FROM main.t a, other.t b")?, 42);
+    conn.detach("other")?;
+    assert!(conn.query("SELECT x FROM other.t").is_err());
+    assert!(conn.detach("other").is_err());
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("it's.duckdb");
+    {
+        let mut file = Connection::open(&path)?;
+        file.execute_batch("CREATE TABLE items (id INTEGER); INSERT INTO items VALUES (7)")?;
+    }
+    conn.attach(path.to_str().unwrap(), "odd \"name\"", true)?;
+    assert_eq!(conn.query_scalar::<i32>(r#"SELECT id FROM "odd ""name""".items"#)?, 7);
+    assert!(conn.execute_batch(r#"INSERT INTO "odd ""name""".items VALUES (8)"#).is_err());
+    Ok(())
+}
+
+#[test]
+fn shutdown_succeeds_while_an_interrupt_handle_is_alive() -> better_duck_core::error::Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("watched.duckdb");
+    let mut conn = Connection::open(&path)?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER); INSERT INTO t VALUES (1)")?;
+    let handle = conn.interrupt_handle();
+    conn.shutdown()?;
+    assert!(!dir.path().join("watched.duckdb.wal").exists());
+    // The connection is gone, so interrupting does nothing.
+    handle.interrupt();
+    Ok(())
+}
+
+#[test]
+fn shutdown_refuses_while_other_connections_are_open() -> better_duck_core::error::Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("shared.duckdb");
+    let mut conn = Connection::open(&path)?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
+    let mut appender = conn.appender("t", "main")?;
+    assert_eq!(conn.shutdown().unwrap_err(), Error::DatabaseInUse);
+    // Nothing was closed, so the connection is still usable.
+    conn.execute_batch("INSERT INTO t VALUES (2)")?;
+    appender.append(&mut 1i32)?;
+    drop(appender);
+    conn.shutdown()?;
+
+    let mut conn = Connection::open(&path)?;
+    let n = conn.query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))?;
+    assert_eq!(n, Some(DuckValue::BigInt(2)));
+    conn.shutdown()?;
+    Connection::open_in_memory()?.shutdown()?;
+    Connection::open_in_memory_named("shutdown_named")?.shutdown()
+}
+
+#[test]
+fn open_read_only_missing_file_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing.duckdb");
+    let err = Connection::open_read_only(&path).err().expect("missing file");
+    assert_eq!(err, Error::InvalidPath(path.clone()));
+    assert!(!path.exists());
+}
+
+#[test]
+fn named_in_memory_databases_are_shared_by_name() -> better_duck_core::error::Result<()> {
+    let mut first = Connection::open_in_memory_named("shared_by_name")?;
+    first.execute_batch("CREATE TABLE t (id INTEGER); INSERT INTO t VALUES (1)")?;
+
+    let mut second = Connection::open_in_memory_named("shared_by_name")?;
+    second.execute_batch("INSERT INTO t VALUES (2)")?;
+    let n = first.query_row("SELECT count(*) AS n FROM t", |row| Ok(row.get("n").cloned()))?;
+    assert_eq!(n, Some(DuckValue::BigInt(2)));
+
+    let mut other = Connection::open_in_memory_named("shared_by_name_other")?;
+    assert!(other.execute_batch("SELECT * FROM t").is_err());
+    // DuckDB would treat `:memory:shared_by_name` as a file name; nothing is written.
+    assert!(!std::path::Path::new(":memory:shared_by_name").exists());
+    Ok(())
+}
+
+#[test]
+fn named_in_memory_database_is_freed_with_its_last_connection(
+) -> better_duck_core::error::Result<()> {
+    {
+        let mut conn = Connection::open_in_memory_named("freed_after_drop")?;
+        conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
+    }
+    let mut conn = Connection::open_in_memory_named("freed_after_drop")?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
+    Ok(())
+}
+
+#[test]
+fn table_exists_and_columns_describe_the_schema() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(
+        "CREATE TABLE people (id INTEGER, name VARCHAR, scores DOUBLE[]); \
+         CREATE SCHEMA other; CREATE TABLE other.people (x BOOLEAN)",
+    )?;
+    assert!(conn.table_exists("main", "people")?);
+    assert!(conn.table_exists("other", "people")?);
+    assert!(!conn.table_exists("main", "missing")?);
+    assert!(!conn.table_exists("missing", "people")?);
+
+    let columns = conn.columns("people")?;
+    let expected = [("id", "INTEGER"), ("name", "VARCHAR"), ("scores", "DOUBLE[]")];
+    assert_eq!(columns.len(), expected.len());
+    for ((name, ty), (expected_name, expected_ty)) in columns.iter().zip(expected) {
+        assert_eq!((name.as_str(), ty.as_str()), (expected_name, expected_ty));
+    }
+    assert!(conn.columns("missing")?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn bulk_insert_skips_conflicts_and_applies_defaults() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(
+        "CREATE TABLE people (id INTEGER PRIMARY KEY, name VARCHAR, team VARCHAR DEFAULT 'core')",
+    )?;
+    let inserted = conn.bulk_insert(
+        "INSERT INTO people (id, name) VALUES (?, ?) ON CONFLICT DO NOTHING",
+        &mut [(1i32, "ada"), (2i32, "grace"), (1i32, "duplicate")],
+    )?;
+    assert_eq!(inserted, 2);
+    let rows: Vec<String> = conn
+        .query("SELECT id || ':' || name || ':' || team AS r FROM people ORDER BY id")?
+        .map(|row| Ok(row?.get("r").unwrap().to_string()))
+        .collect::<better_duck_core::error::Result<_>>()?;
+    assert_eq!(rows, ["1:ada:core", "2:grace:core"]);
+
+    assert!(conn
+        .bulk_insert("INSERT INTO people (id, name) VALUES (?, ?)", &mut [(2i32, "again")])
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn query_with_timeout_returns_fast_results_and_interrupts_slow_ones(
+) -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let started = Instant::now();
+    let mut result = conn.query_with_timeout("SELECT 42 AS answer", Duration::from_secs(30))?;
+    assert_eq!(result.next().unwrap()?.get("answer"), Some(&DuckValue::Int(42)));
+    // The watchdog is woken when the query returns instead of sleeping out the timeout.
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    let timeout = Duration::from_millis(100);
+    let err = match conn.query_with_timeout("SELECT sum(i) FROM range(1000000000000) t(i)", timeout)
+    {
+        Ok(_) => panic!("expected the query to time out"),
+        Err(err) => err,
+    };
+    assert_eq!(err, Error::Timeout(timeout));
+    // The connection stays usable afterwards.
+    let one = conn.query_row("SELECT 1 AS one", |row| Ok(row.get("one").cloned()))?;
+    assert_eq!(one, Some(DuckValue::Int(1)));
+    Ok(())
+}
+
+#[test]
+fn query_scalar_reads_a_single_value() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER, name VARCHAR)")?;
+    conn.execute_batch("INSERT INTO t VALUES (3, 'c'), (7, 'g')")?;
+    assert_eq!(conn.query_scalar::<i64>("SELECT count(*) FROM t")?, 2);
+    assert_eq!(conn.query_scalar::<i32>("SELECT max(id) FROM t")?, 7);
+    assert_eq!(conn.query_scalar::<String>("SELECT name FROM t ORDER BY id")?, "c");
+    assert_eq!(
+        conn.query_scalar::<i32>("SELECT id FROM t WHERE id > 10").unwrap_err(),
+        Error::QueryReturnedNoRows
+    );
+    let empty = "SELECT max(id) FROM t WHERE id > 10";
+    assert_eq!(conn.query_scalar::<Option<i32>>(empty)?, None);
+    assert_eq!(conn.query_scalar::<Option<i32>>("SELECT max(id) FROM t")?, Some(7));
+    assert!(matches!(
+        conn.query_scalar::<i32>(empty).unwrap_err(),
+        Error::ConversionError(DuckDBConversionError::NullValue)
+    ));
+    Ok(())
+}
+
+#[test]
+fn query_scalar_rejects_more_than_one_column() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let err = conn.query_scalar::<i32>("SELECT 1 AS a, 2 AS b").unwrap_err();
+    assert_eq!(err, Error::InvalidColumnCount(2, 1));
+    Ok(())
+}
+
+#[test]
+fn set_pragma_and_read_it_back() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.set_pragma("threads", "2")?;
+    assert_eq!(conn.get_pragma("threads")?, DuckValue::BigInt(2));
+    assert_eq!(conn.current_setting("threads")?, "2");
+
+    // The value is a literal, so a quote cannot end it and start another statement.
+    assert!(conn.set_pragma("threads", "3'; CREATE TABLE injected (x INT); --").is_err());
+    assert!(!conn.table_exists("main", "injected")?);
+    assert!(conn.set_pragma("no_such_setting", "1").is_err());
+    assert!(conn.get_pragma("no_such_setting").is_err());
+    Ok(())
+}
+
+#[test]
+fn explain_renders_the_plan() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(
+        "CREATE TABLE a (id INTEGER, x TEXT); CREATE TABLE b (id INTEGER, y TEXT);
+         INSERT INTO a VALUES (1, 'a'); INSERT INTO b VALUES (1, 'b')",
+    )?;
+    let join = "SELECT x, y FROM a JOIN b ON a.id = b.id";
+    let plan = conn.explain(join)?;
+    assert!(plan.contains("JOIN"), "{plan}");
+
+    let profiled = conn.explain_analyze(join)?;
+    assert!(profiled.contains("JOIN"), "{profiled}");
+    assert!(profiled.contains("Total Time"), "{profiled}");
+    assert!(conn.explain("SELECT * FROM missing").is_err());
+    Ok(())
+}
+
+#[test]
+fn validate_reports_parameters_without_running() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (x INTEGER)")?;
+    let info = conn.validate("SELECT ? FROM t WHERE x = ?")?;
+    assert_eq!(info.param_count(), 2);
+    assert_eq!(info.param_types[1], better_duck_core::ffi::DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+
+    let info = conn.validate("DELETE FROM t")?;
+    assert_eq!(info.param_count(), 0);
+    assert_eq!(conn.query_scalar::<i64>("SELECT count(*) FROM t")?, 0);
+    assert!(conn.validate("SELECT ? FROM missing").is_err());
+    assert!(conn.validate("SELEC 1").is_err());
+    Ok(())
+}
+
+#[test]
+fn source_id_names_the_build() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let source_id = conn.source_id()?;
+    assert!(!source_id.is_empty());
+    assert!(source_id.chars().all(|c| c.is_ascii_hexdigit()), "{source_id}");
+    assert!(better_duck_core::library_version().starts_with('v'));
+    Ok(())
+}
@@ -0,0 +1,144 @@
+#![allow(missing_docs)]
+#![cfg(feature = "derive")]
+use better_duck_core::{
+    connection::Connection,
+    error::Error,
+    types::{from_row::FromRow, value::DuckValue},
+    AppendAble,
+};
+
+#[derive(AppendAble)]
+struct Row(i32, &'static str);
+
+#[derive(Debug, Clone, PartialEq, FromRow, AppendAble)]
+struct Person {
+    id: i32,
+    #[duck(rename = "full_name")]
+    name: String,
+    nickname: Option<String>,
+}
+
+#[test]
+fn tuple_struct_appends_one_column_per_field() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER, name VARCHAR)")?;
+    let mut appender = conn.appender("t", "main")?;
+    appender.append(&mut Row(1, "one"))?;
+    appender.append(&mut Row(2, "two"))?;
+    appender.save()?;
+    drop(appender);
+
+    let mut result = conn.query("SELECT id, name FROM t ORDER BY id")?;
+    let rows: Vec<(i32, String)> = result
+        .by_ref()
+        .map(|row| {
+            let row = row?;
+            Ok((row.get("id").unwrap().try_into()?, row.get("name").unwrap().try_into()?))
+        })
+        .collect::<better_duck_core::error::Result<_>>()?;
+    assert_eq!(rows, vec![(1, "one".to_owned()), (2, "two".to_owned())]);
+    Ok(())
+}
+
+#[test]
+fn named_struct_round_trips_through_appender_and_query() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE people (id INTEGER, full_name VARCHAR, nickname VARCHAR)")?;
+    let people = vec![
+        Person { id: 1, name: "Ada Lovelace".to_owned(), nickname: None },
+        Person {
+            id: 2,
+            name: "Grace Hopper".to_owned(),
+            nickname: Some("Amazing Grace".to_owned()),
+        },
+    ];
+    let mut appender = conn.appender("people", "main")?;
+    for mut person in people.clone() {
+        appender.append(&mut person)?;
+    }
+    appender.save()?;
+    drop(appender);
+
+    let mut result = conn.query("SELECT * FROM people ORDER BY id")?;
+    let read = result.map_rows::<Person>().collect::<better_duck_core::error::Result<Vec<_>>>()?;
+    assert_eq!(read, people);
+    Ok(())
+}
+
+#[test]
+fn named_struct_binds_consecutive_parameters() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE people (id INTEGER, full_name VARCHAR, nickname VARCHAR)")?;
+    let mut person = Person { id: 7, name: "Alan Turing".to_owned(), nickname: None };
+    conn.execute_with("INSERT INTO people VALUES (?, ?, ?)", &mut [&mut person])?;
+
+    let mut result = conn.query("SELECT * FROM people")?;
+    let row = result.next().expect("expected one row")?;
+    assert_eq!(Person::from_row(&row)?, person);
+    Ok(())
+}
+
+#[derive(AppendAble)]
+struct Order {
+    id: i32,
+    customer: Row,
+    paid: bool,
+}
+
+#[test]
+fn nested_struct_shifts_the_fields_after_it() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(
+        "CREATE TABLE orders (id INTEGER, customer_id INTEGER, customer VARCHAR, paid BOOLEAN)",
+    )?;
+    let mut appender = conn.appender("orders", "main")?;
+    appender.append(&mut Order { id: 1, customer: Row(10, "ada"), paid: true })?;
+    appender.save()?;
+    drop(appender);
+    let mut order = Order { id: 2, customer: Row(20, "grace"), paid: false };
+    conn.execute_with("INSERT INTO orders VALUES (?, ?, ?, ?)", &mut [&mut order])?;
+
+    let rows: Vec<_> = conn
+        .query("SELECT * FROM orders ORDER BY id")?
+        .map(|row| row.map(|row| row.values().to_vec()))
+        .collect::<better_duck_core::error::Result<_>>()?;
+    assert_eq!(
+        rows,
+        [
+            [
+                DuckValue::Int(1),
+                DuckValue::Int(10),
+                DuckValue::text("ada"),
+                DuckValue::Boolean(true)
+            ],
+            [
+                DuckValue::Int(2),
+                DuckValue::Int(20),
+                DuckValue::text("grace"),
+                DuckValue::Boolean(false)
+            ],
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn column_count_mismatch_is_reported() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER)")?;
+    let mut appender = conn.appender("t", "main")?;
+    assert!(matches!(appender.append(&mut Row(1, "one")), Err(Error::InvalidColumnCount(..))));
+    Ok(())
+}
+
+#[test]
+fn from_row_reports_renamed_column_missing() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT 1 AS id, 'x' AS name, NULL AS nickname")?;
+    let row = result.next().expect("expected one row")?;
+    assert!(matches!(
+        Person::from_row(&row),
+        Err(Error::InvalidColumnName(name)) if name == "full_name"
+    ));
+    Ok(())
+}
@@ -1,7 +1,19 @@
 #![allow(missing_docs)]
 use better_duck_core::{
     connection::Connection,
-    types::{value::DuckValue, Blob},
+    error::Error,
+    ffi::{
+        DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL, DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE,
+        DUCKDB_TYPE_DUCKDB_TYPE_INTEGER, DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR,
+    },
+    params,
+    types::{
+        from_row::{row_field, FromRow},
+        interval::Interval,
+        value::DuckValue,
+        Blob,
+    },
+    DuckRow,
 };
 #[cfg(feature = "chrono")]
 use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
@@ -23,6 +35,38 @@ fn round_trip_date() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "chrono")]
+#[test]
+fn column_dates_reads_whole_column_with_nulls() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query(
+        "SELECT CASE WHEN i % 10 = 0 THEN NULL ELSE DATE '2024-01-01' + i::INTEGER END AS d \
+         FROM range(100) t(i) ORDER BY i",
+    )?;
+    let dates = result.column_dates(0)?;
+    assert_eq!(dates.len(), 100);
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    for (i, d) in dates.iter().enumerate() {
+        if i % 10 == 0 {
+            assert_eq!(*d, None, "row {i} should be NULL");
+        } else {
+            assert_eq!(*d, Some(start + Duration::days(i as i64)), "row {i}");
+        }
+    }
+    assert!(result.next().is_none());
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn column_dates_rejects_non_date_column() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT 1 AS n")?;
+    assert!(result.column_dates(0).is_err());
+    assert!(result.column_dates(5).is_err());
+    Ok(())
+}
+
 // TIME
 
 #[cfg(feature = "chrono")]
@@ -122,23 +166,39 @@ fn round_trip_timestamp_ns() -> better_duck_core::error::Result<()> {
 
 // INTERVAL
 
-#[cfg(feature = "chrono")]
 #[test]
 fn round_trip_interval() -> better_duck_core::error::Result<()> {
     let mut conn = Connection::open_in_memory()?;
-    // Use a pure-day interval to avoid month/day approximation ambiguity.
     conn.execute_batch("CREATE TABLE t (iv INTERVAL)")?;
     conn.execute_batch("INSERT INTO t VALUES (INTERVAL '2 days')")?;
     let mut stmt = conn.db().prepare("SELECT iv FROM t")?;
     let mut result = stmt.execute()?;
     let row = result.next().expect("expected one row")?;
-    // 2 days = 2 * 86_400 * 1_000_000 µs
-    let expected = Duration::microseconds(2 * 86_400 * 1_000_000);
-    assert_eq!(row.get("iv"), Some(&DuckValue::Interval(expected)));
+    assert_eq!(row.get("iv"), Some(&DuckValue::Interval(Interval::new(0, 2, 0))));
     assert!(result.next().is_none());
     Ok(())
 }
 
+#[test]
+fn round_trip_interval_keeps_months_and_days() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let expected = Interval::new(1, 2, 3 * 3_600_000_000);
+    let row = conn
+        .query("SELECT INTERVAL '1 month 2 days 3 hours' AS iv")?
+        .next()
+        .expect("expected one row")?;
+    assert_eq!(row.get("iv"), Some(&DuckValue::Interval(expected)));
+
+    // Binding it back must not collapse the month into 30 days. DuckDB normalizes
+    // intervals when comparing them, so read the bound value back instead.
+    let row = conn
+        .execute_with("SELECT ?::INTERVAL AS iv", params![expected])?
+        .next()
+        .expect("expected one row")?;
+    assert_eq!(row.get("iv"), Some(&DuckValue::Interval(expected)));
+    Ok(())
+}
+
 // BLOB
 
 #[test]
@@ -170,3 +230,375 @@ fn round_trip_enum() -> better_duck_core::error::Result<()> {
     assert!(result.next().is_none());
     Ok(())
 }
+
+// integer constants
+
+#[test]
+fn untyped_integer_constant_reads_as_integer() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT 123 AS n, 5000000000 AS big")?;
+    // The constants arrive with concrete integer types, never as INTEGER_LITERAL.
+    assert_eq!(result.column_type(0)?, DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+    let row = result.next().expect("expected a row")?;
+    assert_eq!(row.get("n"), Some(&DuckValue::Int(123)));
+    assert_eq!(row.get("big"), Some(&DuckValue::BigInt(5_000_000_000)));
+    Ok(())
+}
+
+// column_chunk
+
+#[test]
+fn column_chunk_reads_100k_integers_with_nulls() -> better_duck_core::error::Result<()> {
+    let sql = "SELECT CASE WHEN i % 7 = 0 THEN NULL ELSE i::INTEGER END AS v \
+               FROM range(100000) t(i) ORDER BY i";
+    let mut conn = Connection::open_in_memory()?;
+    let expected: Vec<Option<i32>> = conn
+        .query(sql)?
+        .map(|row| {
+            Ok(match row?.get("v") {
+                Some(DuckValue::Int(v)) => Some(*v),
+                _ => None,
+            })
+        })
+        .collect::<better_duck_core::error::Result<_>>()?;
+
+    let mut result = conn.query(sql)?;
+    let values = result.column_chunk::<i32>(0)?;
+    assert_eq!(values.len(), 100_000);
+    assert_eq!(values[0], None);
+    assert_eq!(values[99_999], Some(99_999));
+    assert_eq!(values, expected);
+    assert!(result.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn column_chunk_skips_rows_already_iterated() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT i::BIGINT AS v FROM range(5000) t(i) ORDER BY i")?;
+    result.next().unwrap()?;
+    let values = result.column_chunk::<i64>(0)?;
+    assert_eq!(values.len(), 4999);
+    assert_eq!(values[0], Some(1));
+    Ok(())
+}
+
+#[test]
+fn column_chunk_rejects_mismatched_type() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT 1::INTEGER AS n")?;
+    assert!(result.column_chunk::<i64>(0).is_err());
+    assert!(result.column_chunk::<i32>(5).is_err());
+    Ok(())
+}
+
+#[test]
+fn column_chunk_respects_row_limit() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query_limited("SELECT i::BIGINT AS v FROM range(100000) t(i)", 100)?;
+    assert_eq!(result.column_chunk::<i64>(0).unwrap_err(), Error::ResultTooLarge(100));
+
+    let mut result = conn.query_limited("SELECT i::BIGINT AS v FROM range(100) t(i)", 100)?;
+    assert_eq!(result.column_chunk::<i64>(0)?.len(), 100);
+    Ok(())
+}
+
+// map_rows
+
+#[derive(Debug, PartialEq)]
+struct Person {
+    id: i32,
+    name: String,
+}
+
+impl FromRow for Person {
+    fn from_row(row: &DuckRow) -> better_duck_core::error::Result<Self> {
+        Ok(Person {
+            id: row_field(row, "id")?.try_into()?,
+            name: row_field(row, "name")?.try_into()?,
+        })
+    }
+}
+
+#[test]
+fn map_rows_collects_structs() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query(
+        "SELECT * FROM (VALUES (1, 'Ada'), (2, 'Grace'), (3, 'Edsger')) t(id, name) ORDER BY id",
+    )?;
+    let first: Vec<Person> = result.map_rows().take(1).collect::<Result<_, _>>()?;
+    assert_eq!(first, [Person { id: 1, name: "Ada".to_owned() }]);
+
+    // The result is only borrowed, so decoding picks up where it left off.
+    let rest: Vec<Person> = result.map_rows().collect::<Result<_, _>>()?;
+    assert_eq!(
+        rest,
+        [Person { id: 2, name: "Grace".to_owned() }, Person { id: 3, name: "Edsger".to_owned() }]
+    );
+    assert!(result.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn map_rows_reports_missing_columns() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT 1 AS id")?;
+    let err = result.map_rows::<Person>().next().unwrap().unwrap_err();
+    assert_eq!(err, Error::InvalidColumnName("name".to_owned()));
+    Ok(())
+}
+
+// to_string_matrix
+
+#[test]
+fn to_string_matrix_renders_2x2_result() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result =
+        conn.query("SELECT * FROM (VALUES (1, 'one'), (2, NULL)) t(id, name) ORDER BY id")?;
+    let (headers, rows) = result.to_string_matrix()?;
+    assert_eq!(headers, ["id", "name"]);
+    assert_eq!(rows, [["1", "one"], ["2", "NULL"]]);
+    Ok(())
+}
+
+#[test]
+fn to_string_matrix_with_custom_null_and_nested_values() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query(
+        "SELECT NULL::INTEGER AS n, [1, NULL] AS l, {'b': 2, 'a': 'x'} AS s, '\\x00AB'::BLOB AS b",
+    )?;
+    let (_, rows) = result.to_string_matrix_with_null("")?;
+    assert_eq!(rows, [["", "[1, NULL]", "{'a': x, 'b': 2}", "\\x00AB"]]);
+    Ok(())
+}
+
+// drain_into
+
+#[test]
+fn drain_into_reuses_buffer() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut rows = Vec::new();
+
+    conn.query("SELECT * FROM range(50) t(v)")?.drain_into(&mut rows)?;
+    assert_eq!(rows.len(), 50);
+    let capacity = rows.capacity();
+
+    conn.query("SELECT * FROM range(3) t(v) ORDER BY v")?.drain_into(&mut rows)?;
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows.capacity(), capacity);
+    assert_eq!(rows[2].get("v"), Some(&DuckValue::BigInt(2)));
+    Ok(())
+}
+
+// enumerate_rows
+
+#[test]
+fn enumerate_rows_yields_ordinals() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut indices = Vec::new();
+    for (idx, row) in conn.query("SELECT * FROM range(5) t(v) ORDER BY v")?.enumerate_rows() {
+        assert_eq!(row?.get("v"), Some(&DuckValue::BigInt(idx as i64)));
+        indices.push(idx);
+    }
+    assert_eq!(indices, [0, 1, 2, 3, 4]);
+    Ok(())
+}
+
+#[test]
+fn enumerate_rows_crosses_chunk_boundaries() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    // DuckDB's standard vector size is 2048, so 5000 rows span several chunks.
+    let last = conn.query("SELECT * FROM range(5000)")?.enumerate_rows().last().unwrap().0;
+    assert_eq!(last, 4999);
+    Ok(())
+}
+
+// column names are owned by each row
+
+#[test]
+fn row_column_names_outlive_result() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT 1 AS first_column, 'x' AS second_column")?;
+    let row = result.next().expect("expected one row")?;
+    drop(result);
+    drop(conn);
+    let names: Vec<&str> = row.columns().iter().map(|name| name.as_ref()).collect();
+    assert_eq!(names, ["first_column", "second_column"]);
+    assert_eq!(row.get("second_column"), Some(&DuckValue::Text("x".to_owned())));
+    Ok(())
+}
+
+#[test]
+fn rows_iterates_the_same_result_twice() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    // More than one vector's worth, so the second pass crosses chunk boundaries.
+    let mut result = conn.query("SELECT v, v::VARCHAR AS s FROM range(5000) t(v) ORDER BY v")?;
+    let collect = |result: &mut better_duck_core::DuckResult| {
+        result
+            .rows()
+            .map(|row| Ok(row?.values().to_vec()))
+            .collect::<better_duck_core::error::Result<Vec<_>>>()
+    };
+    let first = collect(&mut result)?;
+    let second = collect(&mut result)?;
+    assert_eq!(first.len(), 5000);
+    assert_eq!(first, second);
+    assert_eq!(first[4999], [DuckValue::BigInt(4999), DuckValue::Text("4999".to_owned())]);
+    // The owning iterator still starts from the first row.
+    assert_eq!(result.next().expect("expected a row")?.get("v"), Some(&DuckValue::BigInt(0)));
+    Ok(())
+}
+
+// random row access
+
+#[test]
+fn row_reads_out_of_order() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT v, v * 10 AS w FROM range(3) t(v) ORDER BY v")?;
+    let row = result.row(2)?;
+    assert_eq!(row.get("v"), Some(&DuckValue::BigInt(2)));
+    assert_eq!(row.get("w"), Some(&DuckValue::BigInt(20)));
+    assert_eq!(result.row(0)?.get("v"), Some(&DuckValue::BigInt(0)));
+    assert_eq!(result.row(2)?.get("w"), Some(&DuckValue::BigInt(20)));
+    assert_eq!(result.row(3).unwrap_err(), Error::InvalidRowIndex(3));
+    Ok(())
+}
+
+#[test]
+fn row_count_spans_chunks_and_keeps_iterator_position() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT * FROM range(5000) t(v) ORDER BY v")?;
+    assert_eq!(result.next().unwrap()?.get("v"), Some(&DuckValue::BigInt(0)));
+    assert_eq!(result.row_count()?, 5000);
+    assert_eq!(result.row(4999)?.get("v"), Some(&DuckValue::BigInt(4999)));
+    // The first chunk was still being iterated, so its rows stay reachable.
+    assert_eq!(result.row(0)?.get("v"), Some(&DuckValue::BigInt(0)));
+    // The iterator carries on from where it was, through the cached chunks.
+    let rest: Vec<_> = result.collect::<better_duck_core::error::Result<_>>()?;
+    assert_eq!(rest.len(), 4999);
+    assert_eq!(rest[0].get("v"), Some(&DuckValue::BigInt(1)));
+    assert_eq!(rest[4998].get("v"), Some(&DuckValue::BigInt(4999)));
+    Ok(())
+}
+
+#[test]
+fn row_before_cache_was_released() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query("SELECT * FROM range(5000) t(v) ORDER BY v")?;
+    // Chunks hold at most 2048 rows, so after 3000 rows the first one has been released.
+    for row in result.by_ref().take(3000) {
+        row?;
+    }
+    assert_eq!(result.row(0).unwrap_err(), Error::InvalidRowIndex(0));
+    // The row the iterator is on is still in a live chunk.
+    assert_eq!(result.row(2999)?.get("v"), Some(&DuckValue::BigInt(2999)));
+    assert_eq!(result.row_count()?, 5000);
+    Ok(())
+}
+
+// errors while producing rows
+
+#[test]
+fn error_partway_through_rows_is_not_a_short_result() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    let outcome = conn
+        .query(
+            "SELECT CASE WHEN i = 500 THEN ('x' || i)::INTEGER ELSE i END AS v FROM range(1000) t(i)",
+        )
+        .and_then(|rows| rows.collect::<better_duck_core::error::Result<Vec<_>>>());
+    let err = outcome.expect_err("a failing cast must not yield a successful result");
+    assert!(err.to_string().contains("x500"), "unexpected error: {err}");
+}
+
+#[test]
+fn streaming_error_after_first_chunk_is_not_a_short_result() -> better_duck_core::error::Result<()>
+{
+    let mut conn = Connection::open_in_memory()?;
+    // One thread keeps the rows in order, so the failing row comes long after the first
+    // chunk has been handed out.
+    conn.execute_batch("SET threads = 1")?;
+    let mut stmt = conn.db().prepare(
+        "SELECT CASE WHEN i = 1000000 THEN ('x' || i)::INTEGER ELSE i END AS v \
+         FROM range(2000000) t(i)",
+    )?;
+    let mut result = stmt.stream()?;
+    assert!(result.is_streaming());
+    assert_eq!(result.next().expect("first row")?.get("v"), Some(&DuckValue::BigInt(0)));
+
+    let mut read = 1;
+    let err = loop {
+        match result.next() {
+            Some(Ok(_)) => read += 1,
+            Some(Err(err)) => break err,
+            None => panic!("the stream ended after {read} rows without reporting the error"),
+        }
+    };
+    assert!(read >= 2048, "the error surfaced within the first chunk, after {read} rows");
+    assert!(err.to_string().contains("x1000000"), "unexpected error: {err}");
+    assert!(result.next().is_none());
+    Ok(())
+}
+
+// column_logical_type
+
+#[test]
+fn column_logical_type_reads_decimal_scale() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let result = conn.query("SELECT 1.5::DECIMAL(10, 3) AS d, 7 AS i")?;
+    let decimal = result.column_logical_type(0)?;
+    assert_eq!(decimal.type_id(), DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL);
+    assert_eq!(decimal.decimal_width(), Some(10));
+    assert_eq!(decimal.decimal_scale(), Some(3));
+    assert_eq!(result.column_logical_type(1)?.decimal_scale(), None);
+    assert_eq!(result.column_logical_type(2).unwrap_err(), Error::InvalidColumnIndex(2));
+    Ok(())
+}
+
+#[test]
+fn column_logical_type_reads_nested_types() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TYPE mood AS ENUM ('happy', 'sad')")?;
+    let result = conn.query(
+        "SELECT [1, 2] AS l, [1.0, 2.0]::DOUBLE[2] AS a, 'sad'::mood AS e, \
+         {'x': 1, 'y': 'z'} AS s, MAP {'k': 1} AS m",
+    )?;
+    let list = result.column_logical_type(0)?;
+    assert_eq!(list.list_child().map(|c| c.type_id()), Some(DUCKDB_TYPE_DUCKDB_TYPE_INTEGER));
+    assert!(list.array_child().is_none());
+
+    let array = result.column_logical_type(1)?;
+    assert_eq!(array.array_size(), Some(2));
+    assert_eq!(array.array_child().map(|c| c.type_id()), Some(DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE));
+
+    let values = result.column_logical_type(2)?.enum_values()?;
+    assert_eq!(values, Some(vec!["happy".to_string(), "sad".to_string()]));
+
+    let fields = result.column_logical_type(3)?.struct_fields()?.expect("a STRUCT column");
+    let fields: Vec<_> = fields.iter().map(|(name, lt)| (name.as_str(), lt.type_id())).collect();
+    assert_eq!(
+        fields,
+        [("x", DUCKDB_TYPE_DUCKDB_TYPE_INTEGER), ("y", DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR)]
+    );
+
+    let (key, value) = result.column_logical_type(4)?.map_key_value().expect("a MAP column");
+    assert_eq!(key.type_id(), DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR);
+    assert_eq!(value.type_id(), DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+    Ok(())
+}
+
+// schema
+
+#[test]
+fn schema_describes_every_column() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let result = conn.query("SELECT 42::INTEGER AS id, 2.5::DECIMAL(9, 2) AS price")?;
+    let schema = result.schema()?;
+    assert_eq!(schema.len(), 2);
+    assert_eq!(schema[0].name, "id");
+    assert_eq!(schema[0].type_id, DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+    assert_eq!(schema[0].logical.type_id(), DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+    assert_eq!(schema[1].name, "price");
+    assert_eq!(schema[1].type_id, DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL);
+    assert_eq!(schema[1].logical.decimal_scale(), Some(2));
+    Ok(())
+}
@@ -1,5 +1,17 @@
 #![allow(missing_docs)]
-use better_duck_core::{connection::Connection, types::value::DuckValue, CachedStatement};
+use better_duck_core::{
+    connection::Connection,
+    ffi::{
+        DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE, DUCKDB_TYPE_DUCKDB_TYPE_INTEGER,
+        DUCKDB_TYPE_DUCKDB_TYPE_INVALID,
+    },
+    params,
+    types::{
+        from_row::{row_field, FromRow},
+        value::DuckValue,
+    },
+    CachedStatement, DuckRow,
+};
 
 // execute (DML paths)
 
@@ -51,6 +63,22 @@ fn execute_dml_no_match_returns_zero() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[test]
+fn execute_returning_changes_counts_inserted_rows() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (v INTEGER)")?;
+    let changed = conn.execute_returning_changes("INSERT INTO t VALUES (1), (2), (3)")?;
+    assert_eq!(changed, 3);
+    Ok(())
+}
+
+#[test]
+fn execute_returning_changes_ddl_returns_zero() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    assert_eq!(conn.execute_returning_changes("CREATE TABLE t (v INTEGER)")?, 0);
+    Ok(())
+}
+
 // execute (query / row-returning paths)
 
 #[test]
@@ -78,6 +106,195 @@ fn query_rows_with_bound_parameter() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+// execute_with + params!
+
+#[test]
+fn execute_with_params_macro_binds_in_order() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER, name TEXT)")?;
+    let changed = conn
+        .execute_with("INSERT INTO t VALUES (?, ?)", params![7i32, String::from("duck")])?
+        .changes();
+    assert_eq!(changed, 1);
+
+    let row = conn.query_row("SELECT id, name FROM t", |row| {
+        Ok((row.get("id").cloned(), row.get("name").cloned()))
+    })?;
+    assert_eq!(row, (Some(DuckValue::Int(7)), Some(DuckValue::text("duck"))));
+    Ok(())
+}
+
+#[test]
+fn execute_with_params_advances_past_tuples() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result =
+        conn.execute_with("SELECT ? AS a, ? AS b, ? AS c", params![(1i32, 2i32), 3i32])?;
+    let row = result.next().unwrap()?;
+    assert_eq!(row.get("a"), Some(&DuckValue::Int(1)));
+    assert_eq!(row.get("b"), Some(&DuckValue::Int(2)));
+    assert_eq!(row.get("c"), Some(&DuckValue::Int(3)));
+    Ok(())
+}
+
+#[test]
+fn execute_with_empty_params() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.execute_with("SELECT 1 AS one", params![])?;
+    assert_eq!(result.next().unwrap()?.get("one"), Some(&DuckValue::Int(1)));
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn bind_naive_date_into_date_column() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (d DATE)")?;
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    conn.execute_with("INSERT INTO t VALUES (?)", params![date])?;
+    let read = conn.query_row("SELECT d FROM t", |row| Ok(row.get("d").cloned()))?;
+    assert_eq!(read, Some(DuckValue::Date(date)));
+    Ok(())
+}
+
+struct Celsius(f64);
+
+impl better_duck_core::types::DuckDialect for Celsius {
+    fn from_duck(
+        value: better_duck_core::ffi::duckdb_value
+    ) -> Result<Self, better_duck_core::error::DuckDBConversionError> {
+        // SAFETY: `value` is a valid DOUBLE duckdb_value.
+        Ok(Celsius(unsafe { better_duck_core::ffi::duckdb_get_double(value) }))
+    }
+    fn to_duck(
+        &self
+    ) -> Result<better_duck_core::ffi::duckdb_value, better_duck_core::error::DuckDBConversionError>
+    {
+        // SAFETY: creating a DOUBLE value cannot fail.
+        Ok(unsafe { better_duck_core::ffi::duckdb_create_double(self.0) })
+    }
+}
+
+better_duck_core::impl_appendable_via_to_duck_native!(Celsius);
+
+#[test]
+fn bind_dialect_only_type_via_value_path() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (c DOUBLE)")?;
+    conn.execute_with("INSERT INTO t VALUES (?)", params![Celsius(21.5)])?;
+    {
+        let mut appender = conn.appender("t", "main")?;
+        appender.append(&mut Celsius(-3.0))?;
+        appender.save()?;
+    }
+    let rows: Vec<_> = conn.query("SELECT c FROM t ORDER BY c")?.collect::<Result<_, _>>()?;
+    assert_eq!(rows[0].get("c"), Some(&DuckValue::Double(-3.0)));
+    assert_eq!(rows[1].get("c"), Some(&DuckValue::Double(21.5)));
+    Ok(())
+}
+
+#[test]
+fn bind_via_duck_value_out_of_range_errors() -> better_duck_core::error::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    let stmt = conn.db().prepare("SELECT ?::DOUBLE AS c")?;
+    assert!(stmt.bind_at(&mut Celsius(1.0), 5).is_err());
+    Ok(())
+}
+
+// query
+
+#[test]
+fn query_iterates_literal_row() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut seen = 0;
+    for row in conn.query("SELECT 42 AS answer, 'duck' AS name")? {
+        let row = row?;
+        assert_eq!(row.get("answer"), Some(&DuckValue::Int(42)));
+        assert_eq!(row.get("name"), Some(&DuckValue::text("duck")));
+        seen += 1;
+    }
+    assert_eq!(seen, 1);
+    Ok(())
+}
+
+#[test]
+fn query_invalid_sql_errors() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    assert!(conn.query("SELEC nonsense").is_err());
+}
+
+#[test]
+fn query_row_maps_first_row() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let answer = conn.query_row("SELECT 42 AS answer", |row| Ok(row.get("answer").cloned()))?;
+    assert_eq!(answer, Some(DuckValue::Int(42)));
+    Ok(())
+}
+
+#[test]
+fn query_row_discards_extra_rows() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let first =
+        conn.query_row("SELECT * FROM range(3) t(v) ORDER BY v", |row| Ok(row.get("v").cloned()))?;
+    assert_eq!(first, Some(DuckValue::BigInt(0)));
+    Ok(())
+}
+
+#[test]
+fn query_row_no_rows_errors() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (v INTEGER)")?;
+    let err = conn.query_row("SELECT v FROM t", |_| Ok(())).unwrap_err();
+    assert_eq!(err, better_duck_core::error::Error::QueryReturnedNoRows);
+    Ok(())
+}
+
+#[test]
+fn query_limited_errors_past_limit() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query_limited("SELECT * FROM range(1000)", 100)?;
+    assert!(result.is_streaming());
+    for _ in 0..100 {
+        result.next().expect("row within limit")?;
+    }
+    let err = result.next().expect("limit error").unwrap_err();
+    assert_eq!(err, better_duck_core::error::Error::ResultTooLarge(100));
+    assert!(result.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn query_limited_under_limit_yields_all_rows() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let rows: Vec<_> =
+        conn.query_limited("SELECT * FROM range(100)", 100)?.collect::<Result<_, _>>()?;
+    assert_eq!(rows.len(), 100);
+    Ok(())
+}
+
+#[test]
+fn query_limited_guards_random_access() -> better_duck_core::error::Result<()> {
+    use better_duck_core::error::Error;
+
+    let mut conn = Connection::open_in_memory()?;
+    let mut result = conn.query_limited("SELECT * FROM range(100000) t(v)", 100)?;
+    assert_eq!(result.row(5)?.get("v"), Some(&DuckValue::BigInt(5)));
+    assert_eq!(result.row(100).unwrap_err(), Error::ResultTooLarge(100));
+    // Counting would cache the whole result, so it stops at the limit instead.
+    assert_eq!(result.row_count().unwrap_err(), Error::ResultTooLarge(100));
+
+    // Iterating through the cache stops at the limit as well.
+    for _ in 0..100 {
+        result.next().expect("row within limit")?;
+    }
+    assert_eq!(result.next().expect("limit error").unwrap_err(), Error::ResultTooLarge(100));
+    assert!(result.next().is_none());
+
+    let mut exact = conn.query_limited("SELECT * FROM range(100) t(v)", 100)?;
+    assert_eq!(exact.row_count()?, 100);
+    assert_eq!(exact.row(100).unwrap_err(), Error::InvalidRowIndex(100));
+    Ok(())
+}
+
 // CachedStatement
 
 #[test]
@@ -119,3 +336,115 @@ fn returning_clause_works() -> better_duck_core::error::Result<()> {
     assert!(result.next().is_none());
     Ok(())
 }
+
+// query_typed
+
+#[derive(Debug, PartialEq)]
+struct User {
+    id: i64,
+    name: String,
+}
+
+impl FromRow for User {
+    fn from_row(row: &DuckRow) -> better_duck_core::error::Result<Self> {
+        Ok(User {
+            id: row_field(row, "id")?.try_into()?,
+            name: row_field(row, "name")?.try_into()?,
+        })
+    }
+}
+
+const USERS_SQL: &str = "SELECT i AS id, 'user_' || i AS name FROM range(100000) t(i) ORDER BY i";
+
+#[test]
+fn query_typed_matches_per_row_from_row() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let expected = conn
+        .query(USERS_SQL)?
+        .map(|row| User::from_row(&row?))
+        .collect::<better_duck_core::error::Result<Vec<_>>>()?;
+
+    let mut stmt = conn.db().prepare(USERS_SQL)?;
+    let typed = stmt.query_typed::<User>()?.collect::<better_duck_core::error::Result<Vec<_>>>()?;
+
+    assert_eq!(typed.len(), 100_000);
+    assert_eq!(typed[99_999], User { id: 99_999, name: "user_99999".to_owned() });
+    assert_eq!(typed, expected);
+    Ok(())
+}
+
+#[test]
+fn query_typed_reports_missing_column() -> better_duck_core::error::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    let mut stmt = conn.db().prepare("SELECT 1::BIGINT AS id")?;
+    let err = stmt.query_typed::<User>()?.next().unwrap().unwrap_err();
+    assert_eq!(err, better_duck_core::error::Error::InvalidColumnName("name".to_owned()));
+    Ok(())
+}
+
+// stream
+
+#[test]
+fn stream_sums_a_large_range_chunk_by_chunk() -> better_duck_core::error::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    let mut stmt = conn.db().prepare("SELECT range AS v FROM range(1000000)")?;
+    let result = stmt.stream()?;
+    assert!(result.is_streaming());
+    let mut sum = 0i64;
+    for row in result {
+        match row?.get("v") {
+            Some(DuckValue::BigInt(v)) => sum += v,
+            other => panic!("expected BIGINT, got {other:?}"),
+        }
+    }
+    assert_eq!(sum, 999_999 * 1_000_000 / 2);
+    Ok(())
+}
+
+#[test]
+fn stream_binds_parameters() -> better_duck_core::error::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    let mut stmt = conn.db().prepare("SELECT count(*) AS n FROM range($1)")?;
+    stmt.bind(&mut 5000i64)?;
+    let row = stmt.stream()?.next().unwrap()?;
+    assert_eq!(row.get("n"), Some(&DuckValue::BigInt(5000)));
+    Ok(())
+}
+
+// parameter_type
+
+#[test]
+fn parameter_type_reports_column_types() -> better_duck_core::error::Result<()> {
+    use better_duck_core::error::Error;
+
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (i INTEGER, d DOUBLE)")?;
+    // DuckDB infers parameter types from what they are compared or assigned to; in
+    // `?::INTEGER + ?::DOUBLE` the first cast is folded into the addition and left untyped.
+    let stmt = conn.db().prepare("INSERT INTO t VALUES (?, ?)")?;
+    assert_eq!(stmt.parameter_type(1)?, DUCKDB_TYPE_DUCKDB_TYPE_INTEGER);
+    assert_eq!(stmt.parameter_type(2)?, DUCKDB_TYPE_DUCKDB_TYPE_DOUBLE);
+    assert_eq!(stmt.parameter_type(0).unwrap_err(), Error::InvalidParameterIndex(0));
+    assert_eq!(stmt.parameter_type(3).unwrap_err(), Error::InvalidParameterIndex(3));
+
+    let untyped = conn.db().prepare("SELECT ? AS v")?;
+    assert_eq!(untyped.parameter_type(1)?, DUCKDB_TYPE_DUCKDB_TYPE_INVALID);
+    Ok(())
+}
+
+#[test]
+fn statement_execute_returning_changes_counts_deleted_rows() -> better_duck_core::error::Result<()>
+{
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (id INTEGER); INSERT INTO t VALUES (1), (2), (3)")?;
+    let mut stmt = conn.db().prepare("DELETE FROM t WHERE id = ?")?;
+    stmt.bind(&mut 2i32)?;
+    assert_eq!(stmt.execute_returning_changes()?, 1);
+
+    stmt.clear_bindings()?;
+    stmt.bind(&mut 2i32)?;
+    assert_eq!(stmt.execute_returning_changes()?, 0);
+    drop(stmt);
+    assert_eq!(conn.query_scalar::<i64>("SELECT count(*) FROM t")?, 2);
+    Ok(())
+}
@@ -0,0 +1,69 @@
+#![allow(missing_docs)]
+use better_duck_core::{
+    connection::Connection,
+    error::Error,
+    ffi::{
+        DUCKDB_TYPE_DUCKDB_TYPE_INTEGER, DUCKDB_TYPE_DUCKDB_TYPE_LIST,
+        DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR,
+    },
+    table_function::TableFunction,
+    types::value::DuckValue,
+};
+
+#[test]
+fn table_function_yields_rust_rows() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.register_table_function(TableFunction::new(
+        "my_func",
+        &[("i", DUCKDB_TYPE_DUCKDB_TYPE_INTEGER)],
+        || (1..=5).map(|i| vec![DuckValue::Int(i)]),
+    )?)?;
+    let mut result = conn.query("SELECT * FROM my_func()")?;
+    assert_eq!(result.collect_column::<i32>(0)?, [1, 2, 3, 4, 5]);
+    // Every scan starts a fresh iterator.
+    assert_eq!(conn.query_scalar::<i64>("SELECT sum(i)::BIGINT FROM my_func()")?, 15);
+    Ok(())
+}
+
+#[test]
+fn table_function_spans_several_chunks() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.register_table_function(TableFunction::new(
+        "labels",
+        &[("id", DUCKDB_TYPE_DUCKDB_TYPE_INTEGER), ("label", DUCKDB_TYPE_DUCKDB_TYPE_VARCHAR)],
+        || {
+            (0..5000).map(|i| {
+                let label =
+                    if i % 10 == 0 { DuckValue::Null } else { DuckValue::text(format!("row {i}")) };
+                vec![DuckValue::Int(i), label]
+            })
+        },
+    )?)?;
+    assert_eq!(conn.query_scalar::<i64>("SELECT count(label) FROM labels()")?, 4500);
+    let label = conn.query_scalar::<String>("SELECT label FROM labels() WHERE id = 4321")?;
+    assert_eq!(label, "row 4321");
+    Ok(())
+}
+
+#[test]
+fn table_function_rejects_mismatched_rows() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.register_table_function(TableFunction::new(
+        "bad",
+        &[("i", DUCKDB_TYPE_DUCKDB_TYPE_INTEGER)],
+        || vec![vec![DuckValue::text("not a number")]],
+    )?)?;
+    let err = conn.query("SELECT * FROM bad()").err().expect("wrong variant is reported");
+    assert!(err.to_string().contains("cannot write"), "{err}");
+    Ok(())
+}
+
+#[test]
+fn table_function_rejects_unsupported_column_types() {
+    let err = TableFunction::new("nested", &[("l", DUCKDB_TYPE_DUCKDB_TYPE_LIST)], || {
+        Vec::<Vec<DuckValue>>::new()
+    })
+    .err()
+    .expect("LIST columns are not supported");
+    assert!(matches!(err, Error::InvalidFunction(name, _) if name == "nested"));
+}
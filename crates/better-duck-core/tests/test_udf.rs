@@ -0,0 +1,42 @@
+#![allow(missing_docs)]
+use better_duck_core::{connection::Connection, udf::ScalarFunction};
+
+#[test]
+fn scalar_function_is_callable_from_sql() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.register_scalar_function(ScalarFunction::new("add_two", |a: i64, b: i64| a + b)?)?;
+    assert_eq!(conn.query_scalar::<i64>("SELECT add_two(40, 2)")?, 42);
+
+    conn.execute_batch("CREATE TABLE t AS SELECT range AS x FROM range(5000)")?;
+    let sum = conn.query_scalar::<i64>("SELECT sum(add_two(x, x))::BIGINT FROM t")?;
+    assert_eq!(sum, 2 * (0..5000).sum::<i64>());
+    Ok(())
+}
+
+#[test]
+fn scalar_function_propagates_null_arguments() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    let function = ScalarFunction::new("is_even", |a: i32| a % 2 == 0)?;
+    assert_eq!(function.name(), "is_even");
+    conn.register_scalar_function(function)?;
+
+    let mut result = conn.query("SELECT is_even(v) FROM (VALUES (1), (NULL), (4)) t(v)")?;
+    assert_eq!(result.collect_column::<Option<bool>>(0)?, [Some(false), None, Some(true)]);
+    Ok(())
+}
+
+#[test]
+fn scalar_function_panic_becomes_query_error() -> better_duck_core::error::Result<()> {
+    let mut conn = Connection::open_in_memory()?;
+    conn.register_scalar_function(
+        ScalarFunction::new("checked_div", |a: i32, b: i32| {
+            assert!(b != 0, "division by zero");
+            a / b
+        })?
+        .volatile(),
+    )?;
+    assert_eq!(conn.query_scalar::<i32>("SELECT checked_div(7, 2)")?, 3);
+    let err = conn.query("SELECT checked_div(1, 0)").err().expect("panic is reported");
+    assert!(err.to_string().contains("division by zero"), "{err}");
+    Ok(())
+}
@@ -1,6 +1,7 @@
 #![allow(missing_docs)]
 use better_duck_core::{
     connection::Connection,
+    params,
     types::{value::DuckValue, Blob},
     AppendAble,
 };
@@ -70,7 +71,8 @@ fn rt_bigint_max() {
     assert_eq!(rt(DuckValue::BigInt(i64::MAX)), DuckValue::BigInt(i64::MAX));
 }
 
-// hugeint_from_i128 supports values up to MAX_SUPPORTED_I128 = 2^127 - 2^63
+// HUGEINT is split exactly into (upper: i64, lower: u64), so the whole i128 range
+// round-trips.
 #[test]
 fn rt_hugeint_positive() {
     let v = 170_141_183_460_469_231_722_463_931_679_029_329_919_i128;
@@ -83,6 +85,38 @@ fn rt_hugeint_negative() {
     assert_eq!(rt(DuckValue::HugeInt(v)), DuckValue::HugeInt(v));
 }
 
+#[test]
+fn rt_hugeint_extremes() {
+    for v in [i128::MAX, i128::MIN, i128::MIN + 1, -1, 0] {
+        assert_eq!(rt(DuckValue::HugeInt(v)), DuckValue::HugeInt(v), "{v}");
+    }
+}
+
+#[test]
+fn rt_hugeint_around_2_pow_64() {
+    let two_64 = 1_i128 << 64;
+    for v in [two_64 - 1, two_64, two_64 + 1, -two_64 - 1, -two_64, -two_64 + 1] {
+        assert_eq!(rt(DuckValue::HugeInt(v)), DuckValue::HugeInt(v), "{v}");
+    }
+}
+
+#[test]
+fn hugeint_max_matches_duckdb_literal() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let row = conn
+        .query(
+            "SELECT 170141183460469231731687303715884105727::HUGEINT AS hi, \
+             (-170141183460469231731687303715884105727 - 1)::HUGEINT AS lo, \
+             18446744073709551616::HUGEINT AS two_64",
+        )?
+        .next()
+        .unwrap()?;
+    assert_eq!(row.get("hi"), Some(&DuckValue::HugeInt(i128::MAX)));
+    assert_eq!(row.get("lo"), Some(&DuckValue::HugeInt(i128::MIN)));
+    assert_eq!(row.get("two_64"), Some(&DuckValue::HugeInt(1 << 64)));
+    Ok(())
+}
+
 // Unsigned integers
 
 #[test]
@@ -110,6 +144,40 @@ fn rt_ubigint_max() {
     assert_eq!(rt(DuckValue::UBigInt(u64::MAX)), DuckValue::UBigInt(u64::MAX));
 }
 
+#[test]
+fn rt_uhugeint_extremes() {
+    for v in [u128::MAX, u128::MAX / 3, 1 << 64, 0] {
+        assert_eq!(rt(DuckValue::UHugeInt(v)), DuckValue::UHugeInt(v), "{v}");
+    }
+}
+
+#[test]
+fn append_and_bind_u128() -> better_duck_core::error::Result<()> {
+    let mid = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128;
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (v UHUGEINT)")?;
+    {
+        let mut appender = conn.appender("t", "main")?;
+        let mut max = u128::MAX;
+        appender.append(&mut max)?;
+        appender.save()?;
+    }
+    let mut bound = mid;
+    conn.execute_with("INSERT INTO t VALUES ($1)", &mut [&mut bound])?;
+    let values: Vec<DuckValue> = conn
+        .query("SELECT v FROM t ORDER BY v")?
+        .map(|row| Ok(row?.get("v").unwrap().clone()))
+        .collect::<better_duck_core::error::Result<_>>()?;
+    assert_eq!(values, vec![DuckValue::UHugeInt(mid), DuckValue::UHugeInt(u128::MAX)]);
+    assert_eq!(
+        conn.query("SELECT v::VARCHAR AS s FROM t ORDER BY v")?
+            .map(|row| Ok(row?.get("s").unwrap().to_string()))
+            .collect::<better_duck_core::error::Result<Vec<_>>>()?,
+        vec![mid.to_string(), u128::MAX.to_string()]
+    );
+    Ok(())
+}
+
 #[test]
 fn rt_uhugeint_max() {
     assert_eq!(rt(DuckValue::UHugeInt(u128::MAX)), DuckValue::UHugeInt(u128::MAX));
@@ -138,6 +206,39 @@ fn rt_double_large() {
     assert_eq!(rt(DuckValue::Double(v)), DuckValue::Double(v));
 }
 
+#[test]
+fn bind_f32_slice_into_float_array_column() {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE emb (v FLOAT[768])").unwrap();
+    let embedding: Vec<f32> = (0..768).map(|i| i as f32 / 768.0).collect();
+    conn.execute_with("INSERT INTO emb VALUES (?)", params![embedding.as_slice()]).unwrap();
+    {
+        let mut appender = conn.appender("emb", "main").unwrap();
+        appender.append(&mut embedding.as_slice()).unwrap();
+        appender.save().unwrap();
+    }
+    let rows = conn
+        .query("SELECT array_length(v) AS n, v[768] AS last FROM emb")
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(rows.len(), 2);
+    for row in &rows {
+        assert_eq!(row.get("n"), Some(&DuckValue::BigInt(768)));
+        assert_eq!(row.get("last"), Some(&DuckValue::Float(767.0 / 768.0)));
+    }
+}
+
+#[test]
+fn bind_f64_slice_into_double_list_column() {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (v DOUBLE[])").unwrap();
+    let values = [1.5_f64, -2.25, 0.0];
+    conn.execute_with("INSERT INTO t VALUES (?)", params![&values[..]]).unwrap();
+    let v = conn.query_row("SELECT v FROM t", |row| Ok(row.get("v").cloned())).unwrap();
+    assert_eq!(v, Some(DuckValue::List(values.iter().copied().map(DuckValue::Double).collect())));
+}
+
 // Text
 
 #[test]
@@ -164,6 +265,100 @@ fn rt_text_long() {
     assert_eq!(rt(DuckValue::text(s.as_str())), DuckValue::text(s));
 }
 
+#[test]
+fn rt_text_with_nul_byte() {
+    assert_eq!(rt(DuckValue::text("a\0b")), DuckValue::text("a\0b"));
+}
+
+/// Strings of up to 12 bytes are inlined in `duckdb_string_t`, longer ones are
+/// stored behind a pointer; both must be read by length, not up to the first NUL.
+#[test]
+fn select_varchar_with_chr_zero() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let row = conn
+        .query("SELECT 'a' || chr(0) || 'b' AS short, repeat('x', 20) || chr(0) || 'y' AS long")?
+        .next()
+        .unwrap()?;
+    let Some(DuckValue::Text(short)) = row.get("short") else {
+        panic!("expected text, got {:?}", row.get("short"));
+    };
+    assert_eq!(short.len(), 3);
+    assert_eq!(short, "a\0b");
+    let Some(DuckValue::Text(long)) = row.get("long") else {
+        panic!("expected text, got {:?}", row.get("long"));
+    };
+    assert_eq!(long.len(), 22);
+    assert_eq!(long, &format!("{}\0y", "x".repeat(20)));
+    Ok(())
+}
+
+#[test]
+fn bind_str_with_nul_byte() {
+    let mut conn = open();
+    let row = conn
+        .execute_with("SELECT ? AS v, length(?) AS n", params!["a\0b", "a\0b"])
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get("v"), Some(&DuckValue::text("a\0b")));
+    assert_eq!(row.get("n"), Some(&DuckValue::BigInt(3)));
+}
+
+#[test]
+fn bind_and_append_vec_slice_as_blob() {
+    let mut conn = open();
+    let bytes = vec![0u8, 255, 128];
+    let row = conn
+        .execute_with("SELECT typeof(?1) AS t, ?1 AS b", params![&bytes[..]])
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get("t"), Some(&DuckValue::text("BLOB")));
+    assert_eq!(row.get("b"), Some(&DuckValue::Blob(Blob::new(bytes.clone()))));
+
+    conn.execute_batch("CREATE TABLE t (b BLOB)").unwrap();
+    {
+        let mut appender = conn.appender("t", "main").unwrap();
+        appender.append(&mut &bytes[..]).unwrap();
+        appender.save().unwrap();
+    }
+    let row = conn.query_row("SELECT b FROM t", |row| Ok(row.get("b").cloned())).unwrap();
+    assert_eq!(row, Some(DuckValue::Blob(Blob::new(bytes))));
+}
+
+// An owned Vec<u8> goes through the generic Vec<T> impl: it is a UTINYINT[] LIST, not a
+// BLOB, and DuckDB will not cast that list into a BLOB column.
+#[test]
+fn owned_vec_binds_as_a_list_not_a_blob() {
+    let mut conn = open();
+    let row = conn
+        .execute_with("SELECT typeof(?) AS t", params![vec![0u8, 255, 128]])
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get("t"), Some(&DuckValue::text("UTINYINT[]")));
+
+    conn.execute_batch("CREATE TABLE t (b BLOB)").unwrap();
+    let mut appender = conn.appender("t", "main").unwrap();
+    assert!(appender.append(&mut vec![0u8, 255, 128]).is_err());
+}
+
+#[test]
+fn append_str_with_nul_byte() {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (v VARCHAR)").unwrap();
+    {
+        let mut appender = conn.appender("t", "main").unwrap();
+        appender.append(&mut "a\0b").unwrap();
+        appender.save().unwrap();
+    }
+    let v = conn.query_row("SELECT v FROM t", |row| Ok(row.get("v").cloned())).unwrap();
+    assert_eq!(v, Some(DuckValue::text("a\0b")));
+}
+
 // BLOB
 
 #[test]
@@ -185,6 +380,51 @@ fn rt_blob_large() {
     assert_eq!(rt(blob.clone()), blob);
 }
 
+#[test]
+fn append_byte_slice_and_blob_with_zeros() {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (b BLOB)").unwrap();
+    let bytes = vec![0u8, 255, 128];
+    {
+        let mut appender = conn.appender("t", "main").unwrap();
+        appender.append(&mut bytes.as_slice()).unwrap();
+        appender.append(&mut Blob::new(bytes.clone())).unwrap();
+        appender.save().unwrap();
+    }
+    let rows = conn.query("SELECT b FROM t").unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(rows.len(), 2);
+    for row in &rows {
+        assert_eq!(row.get("b"), Some(&DuckValue::Blob(Blob::new(bytes.clone()))));
+    }
+}
+
+#[test]
+fn append_blob_wrapping_an_owned_vec() {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (b BLOB)").unwrap();
+    {
+        let mut appender = conn.appender("t", "main").unwrap();
+        appender.append(&mut Blob::from(vec![0u8, 255, 128])).unwrap();
+        appender.save().unwrap();
+    }
+    let row = conn.query_row("SELECT b FROM t", |row| Ok(row.get("b").cloned())).unwrap();
+    assert_eq!(row, Some(DuckValue::Blob(Blob::new(vec![0, 255, 128]))));
+}
+
+#[test]
+fn bind_byte_slice_as_blob() {
+    let mut conn = open();
+    let bytes = [0u8, 255, 128];
+    let row = conn
+        .execute_with("SELECT ? AS b, octet_length(?) AS n", params![&bytes[..], &bytes[..]])
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get("b"), Some(&DuckValue::Blob(Blob::new(bytes.to_vec()))));
+    assert_eq!(row.get("n"), Some(&DuckValue::BigInt(3)));
+}
+
 // NULL paths
 
 /// Binding DuckValue::Null as $1 → SQLNULL column → DuckValue::Null
@@ -220,6 +460,48 @@ fn rt_null_boolean() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+/// A NULL stored in a BOOLEAN column must come back as Null, not as whatever
+/// byte sits in the data buffer, and must not disturb its neighbours.
+#[test]
+fn rt_null_boolean_column() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (id INTEGER, b BOOLEAN)")?;
+    conn.execute_batch("INSERT INTO t VALUES (1, true), (2, NULL), (3, false)")?;
+    conn.execute_with("INSERT INTO t VALUES (4, ?)", params![Option::<bool>::None])?;
+    let rows = conn
+        .query("SELECT b FROM t ORDER BY id")?
+        .collect::<better_duck_core::error::Result<Vec<_>>>()?;
+    let values: Vec<_> = rows.iter().map(|r| r.get("b").cloned()).collect();
+    assert_eq!(
+        values,
+        [
+            Some(DuckValue::Boolean(true)),
+            Some(DuckValue::Null),
+            Some(DuckValue::Boolean(false)),
+            Some(DuckValue::Null),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn append_null_boolean() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t (b BOOLEAN)")?;
+    {
+        let mut appender = conn.appender("t", "main")?;
+        appender.append(&mut Option::<bool>::None)?;
+        appender.append(&mut Some(true))?;
+        appender.save()?;
+    }
+    let rows = conn
+        .query("SELECT b FROM t ORDER BY b NULLS FIRST")?
+        .collect::<better_duck_core::error::Result<Vec<_>>>()?;
+    assert_eq!(rows[0].get("b"), Some(&DuckValue::Null));
+    assert_eq!(rows[1].get("b"), Some(&DuckValue::Boolean(true)));
+    Ok(())
+}
+
 #[test]
 fn rt_null_float() -> better_duck_core::error::Result<()> {
     let mut conn = open();
@@ -238,6 +520,24 @@ fn rt_null_blob() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+/// Untyped NULL columns, including one spanning several chunks.
+#[test]
+fn rt_null_untyped() -> better_duck_core::error::Result<()> {
+    let mut conn = open();
+    let mut result = conn.execute("SELECT NULL AS x")?;
+    let row = result.next().unwrap()?;
+    assert_eq!(row.get("x"), Some(&DuckValue::Null));
+
+    let result = conn.execute("SELECT NULL AS x, range AS i FROM range(5000)")?;
+    let mut rows = 0;
+    for row in result {
+        assert_eq!(row?.get("x"), Some(&DuckValue::Null));
+        rows += 1;
+    }
+    assert_eq!(rows, 5000);
+    Ok(())
+}
+
 /// Three-column row: Null, Int(42), Null — exercises multi-column null handling.
 #[test]
 fn rt_multi_nulls() -> better_duck_core::error::Result<()> {
@@ -295,6 +595,89 @@ fn rt_time_ns() -> better_duck_core::error::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "chrono")]
+#[test]
+fn rt_time_ns_keeps_nanoseconds() -> better_duck_core::error::Result<()> {
+    use chrono::NaiveTime;
+    let expected = NaiveTime::from_hms_nano_opt(23, 59, 59, 123_456_789).unwrap();
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE t_ns (v TIME_NS)")?;
+    conn.execute_batch("INSERT INTO t_ns VALUES ('23:59:59.123456789')")?;
+    let mut result = conn.execute("SELECT v FROM t_ns")?;
+    let row = result.next().unwrap()?;
+    assert_eq!(row.get("v"), Some(&DuckValue::TimeNs(expected)));
+
+    assert_eq!(rt(DuckValue::TimeNs(expected)), DuckValue::TimeNs(expected));
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn append_chrono_date_and_timestamp() -> better_duck_core::error::Result<()> {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE events (d DATE, ts TIMESTAMP)")?;
+    let dates = [
+        NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        NaiveDate::from_ymd_opt(1969, 7, 20).unwrap(),
+    ];
+    let stamps = [
+        NaiveDateTime::new(dates[0], NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999).unwrap()),
+        NaiveDateTime::new(dates[1], NaiveTime::from_hms_micro_opt(20, 17, 40, 500).unwrap()),
+    ];
+    {
+        let mut appender = conn.appender("events", "main")?;
+        for (d, ts) in dates.iter().zip(&stamps) {
+            appender.append_values(&[DuckValue::Date(*d), DuckValue::Timestamp(*ts)])?;
+        }
+        appender.save()?;
+    }
+    // Columns one at a time go through the `AppendAble` impls of the chrono types.
+    conn.execute_batch("CREATE TABLE days (d DATE); CREATE TABLE stamps (ts TIMESTAMP)")?;
+    {
+        let mut appender = conn.appender("days", "main")?;
+        for mut d in dates {
+            appender.append(&mut d)?;
+        }
+        appender.save()?;
+        let mut appender = conn.appender("stamps", "main")?;
+        for mut ts in stamps {
+            appender.append(&mut ts)?;
+        }
+        appender.save()?;
+    }
+
+    let read = |conn: &mut Connection, sql: &str| {
+        conn.query(sql)?
+            .map(|row| Ok(row?.get_idx(0).cloned()))
+            .collect::<better_duck_core::error::Result<Vec<_>>>()
+    };
+    let expected_dates: Vec<_> = dates.iter().map(|d| Some(DuckValue::Date(*d))).collect();
+    let expected_stamps: Vec<_> = stamps.iter().map(|ts| Some(DuckValue::Timestamp(*ts))).collect();
+    assert_eq!(read(&mut conn, "SELECT d FROM days ORDER BY d DESC")?, expected_dates);
+    assert_eq!(read(&mut conn, "SELECT ts FROM stamps ORDER BY ts DESC")?, expected_stamps);
+    assert_eq!(read(&mut conn, "SELECT d FROM events ORDER BY d DESC")?, expected_dates);
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn append_and_bind_chrono_datetime_utc() -> better_duck_core::error::Result<()> {
+    use chrono::{TimeZone, Utc};
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE tz (ts TIMESTAMPTZ)")?;
+    let mut at = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+    {
+        let mut appender = conn.appender("tz", "main")?;
+        appender.append(&mut at)?;
+        appender.save()?;
+    }
+    let mut result = conn.execute_with("SELECT ts FROM tz WHERE ts = $1", &mut [&mut at])?;
+    let row = result.next().unwrap()?;
+    assert_eq!(row.get("ts"), Some(&DuckValue::TimestampTz(at)));
+    Ok(())
+}
+
 // DuckValue::column_count + get_idx
 
 #[test]
@@ -341,3 +724,261 @@ fn bind_duckvalue_directly() -> better_duck_core::error::Result<()> {
     assert!(result.next().is_none());
     Ok(())
 }
+
+// JSON
+
+#[cfg(feature = "json")]
+#[test]
+fn json_appends_and_binds_only_well_formed_text() -> better_duck_core::error::Result<()> {
+    use better_duck_core::{
+        error::{DuckDBConversionError, Error},
+        types::json::Json,
+    };
+
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE docs (body VARCHAR)")?;
+    {
+        let mut appender = conn.appender("docs", "main")?;
+        appender.append(&mut Json::new(r#"{"a":1}"#))?;
+        let err = appender.append(&mut Json::new("{a:1}")).unwrap_err();
+        assert!(matches!(err, Error::ConversionError(DuckDBConversionError::ConversionError(_))));
+        appender.save()?;
+    }
+    let mut result = conn.query("SELECT body FROM docs")?;
+    assert_eq!(result.collect_column::<Json>(0)?, [Json::new(r#"{"a":1}"#)]);
+
+    let mut bad = Json::new("[1,");
+    assert!(conn.execute_with("SELECT ?", &mut [&mut bad as &mut dyn AppendAble]).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_round_trips_through_json_column() -> better_duck_core::error::Result<()> {
+    use better_duck_core::types::json::Json;
+
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE docs (body JSON)")?;
+    {
+        let mut appender = conn.appender("docs", "main")?;
+        appender.append(&mut Json::new(r#"{"a":1}"#))?;
+        appender.save()?;
+    }
+    let row = conn.query("SELECT body, body->>'a' AS a FROM docs")?.next().unwrap()?;
+    assert_eq!(row.get("body"), Some(&DuckValue::text(r#"{"a":1}"#)));
+    assert_eq!(row.get("a"), Some(&DuckValue::text("1")));
+    Ok(())
+}
+
+// DECIMAL
+
+#[cfg(feature = "decimal")]
+#[test]
+fn append_decimal_into_decimal_18_4() -> better_duck_core::error::Result<()> {
+    use rust_decimal::Decimal;
+
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE prices (p DECIMAL(18, 4))")?;
+    let values = [
+        Decimal::new(123_456, 4),    // 12.3456
+        Decimal::new(-5, 1),         // -0.5
+        Decimal::new(0, 0),          // 0
+        Decimal::new(99_999_999, 2), // 999999.99
+    ];
+    {
+        let mut appender = conn.appender("prices", "main")?;
+        for mut v in values {
+            appender.append(&mut v)?;
+        }
+        appender.save()?;
+    }
+
+    let rows: Vec<_> = conn.query("SELECT p FROM prices")?.collect::<Result<_, _>>()?;
+    let read: Vec<Decimal> = rows
+        .iter()
+        .map(|row| match row.get("p") {
+            Some(DuckValue::Decimal(d)) => *d,
+            other => panic!("expected a decimal, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(read, values);
+    Ok(())
+}
+
+#[test]
+fn wide_decimal_round_trips_all_38_digits() -> better_duck_core::error::Result<()> {
+    use better_duck_core::types::numeric::WideDecimal;
+
+    let wide = WideDecimal { value: 12_345_678_901_234_567_890_123_456_789_012_345_678, scale: 10 };
+    let negative = WideDecimal { value: -wide.value, scale: 10 };
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE wide (d DECIMAL(38, 10))")?;
+    {
+        let mut appender = conn.appender("wide", "main")?;
+        appender.append(&mut wide.clone())?;
+        appender.save()?;
+    }
+    conn.execute_with("INSERT INTO wide VALUES ($1)", &mut [&mut negative.clone()])?;
+
+    let rows: Vec<_> =
+        conn.query("SELECT d FROM wide ORDER BY d DESC")?.collect::<Result<_, _>>()?;
+    let read: Vec<_> = rows.iter().map(|row| row.get("d").cloned()).collect();
+    assert_eq!(read, [Some(DuckValue::WideDecimal(wide)), Some(DuckValue::WideDecimal(negative))]);
+    assert_eq!(wide.to_string(), "1234567890123456789012345678.9012345678");
+    assert_eq!(rt(DuckValue::WideDecimal(negative)), DuckValue::WideDecimal(negative));
+
+    let row = conn.query("SELECT d::VARCHAR AS s FROM wide ORDER BY d")?.next().unwrap()?;
+    assert_eq!(row.get("s"), Some(&DuckValue::text("-1234567890123456789012345678.9012345678")));
+    Ok(())
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn decimal_param_binds_with_exact_precision_and_scale() -> better_duck_core::error::Result<()> {
+    use better_duck_core::types::numeric::DecimalParam;
+    use rust_decimal::Decimal;
+
+    let mut conn = open();
+    let param = |value, precision, scale| DecimalParam { value, precision, scale };
+    let mut twelve_and_a_half = param(Decimal::new(125, 1), 10, 4);
+    let mut result = conn.execute_with(
+        "SELECT typeof(?1) AS t, ?1::VARCHAR AS v",
+        &mut [&mut twelve_and_a_half as &mut dyn AppendAble],
+    )?;
+    let row = result.next().unwrap()?;
+    assert_eq!(row.get("t"), Some(&DuckValue::text("DECIMAL(10,4)")));
+    assert_eq!(row.get("v"), Some(&DuckValue::text("12.5000")));
+
+    conn.execute_batch("CREATE TABLE amounts (a DECIMAL(10, 4))")?;
+    {
+        let mut appender = conn.appender("amounts", "main")?;
+        appender.append(&mut twelve_and_a_half)?;
+        // Extra digits round half away from zero, like a cast.
+        appender.append(&mut param(Decimal::new(-123_456, 5), 10, 4))?;
+        appender.save()?;
+    }
+    let mut result = conn.query("SELECT a::VARCHAR FROM amounts")?;
+    assert_eq!(result.collect_column::<String>(0)?, ["12.5000", "-1.2346"]);
+
+    let mut too_wide = param(Decimal::new(123_456, 0), 5, 1);
+    assert!(conn.execute_with("SELECT ?", &mut [&mut too_wide as &mut dyn AppendAble]).is_err());
+    let mut invalid = param(Decimal::ONE, 2, 3);
+    assert!(conn.execute_with("SELECT ?", &mut [&mut invalid as &mut dyn AppendAble]).is_err());
+    Ok(())
+}
+
+// Each width is stored in a different integer type: SMALLINT up to 4 digits, INTEGER up
+// to 9, BIGINT up to 18, HUGEINT beyond.
+#[cfg(feature = "decimal")]
+#[test]
+fn decimal_reads_every_storage_width() -> better_duck_core::error::Result<()> {
+    use rust_decimal::Decimal;
+
+    let mut conn = open();
+    for (ty, literals, expected) in [
+        ("DECIMAL(4, 2)", ["12.34", "-99.99"], [Decimal::new(1_234, 2), Decimal::new(-9_999, 2)]),
+        (
+            "DECIMAL(9, 2)",
+            ["1234567.89", "-0.01"],
+            [Decimal::new(123_456_789, 2), Decimal::new(-1, 2)],
+        ),
+        (
+            "DECIMAL(18, 2)",
+            ["1234567890123456.78", "-42.00"],
+            [Decimal::new(123_456_789_012_345_678, 2), Decimal::new(-4_200, 2)],
+        ),
+        (
+            "DECIMAL(28, 2)",
+            ["12345678901234567890123456.78", "-0.50"],
+            [
+                Decimal::from_i128_with_scale(1_234_567_890_123_456_789_012_345_678, 2),
+                Decimal::new(-50, 2),
+            ],
+        ),
+    ] {
+        let sql = format!(
+            "SELECT v FROM (VALUES ({}::{ty}, 1), ({}::{ty}, 2)) t(v, o) ORDER BY o",
+            literals[0], literals[1]
+        );
+        let read: Vec<_> = conn
+            .query(&sql)?
+            .map(|row| Ok(row?.get("v").cloned()))
+            .collect::<better_duck_core::error::Result<_>>()?;
+        assert_eq!(read, expected.map(|d| Some(DuckValue::Decimal(d))), "{ty}");
+    }
+    Ok(())
+}
+
+// The variant comes from the column's width, not the value, so every row of a
+// DECIMAL(38, 2) column reads as a WideDecimal and equal numbers compare equal.
+#[test]
+fn decimal_38_reads_small_and_large_values_alike() -> better_duck_core::error::Result<()> {
+    use better_duck_core::types::numeric::WideDecimal;
+
+    let mut conn = open();
+    conn.execute_batch(
+        "CREATE TABLE totals (t DECIMAL(38, 2), o INTEGER);
+         INSERT INTO totals VALUES
+             (1.50, 1), (123456789012345678901234567890123456.78, 2), (1.5, 3)",
+    )?;
+    let read: Vec<_> = conn
+        .query("SELECT t FROM totals ORDER BY o")?
+        .map(|row| Ok(row?.get("t").cloned()))
+        .collect::<better_duck_core::error::Result<_>>()?;
+    let wide = |value| Some(DuckValue::WideDecimal(WideDecimal { value, scale: 2 }));
+    assert_eq!(
+        read,
+        [wide(150), wide(12_345_678_901_234_567_890_123_456_789_012_345_678), wide(150)]
+    );
+    assert_eq!(read[0], read[2]);
+    Ok(())
+}
+
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn bigdecimal_round_trips_all_38_digits() -> better_duck_core::error::Result<()> {
+    use bigdecimal::BigDecimal;
+
+    // 38 significant digits: more than a rust_decimal::Decimal can hold.
+    let big: BigDecimal = "-1234567890123456789012345678.9012345678".parse().unwrap();
+    let half: BigDecimal = "0.5".parse().unwrap();
+    let mut conn = open();
+    conn.execute_batch("CREATE TABLE big (d DECIMAL(38, 10))")?;
+    {
+        let mut appender = conn.appender("big", "main")?;
+        appender.append(&mut big.clone())?;
+        appender.save()?;
+    }
+    conn.execute_with("INSERT INTO big VALUES ($1)", &mut [&mut half.clone()])?;
+
+    let mut result = conn.query("SELECT d FROM big ORDER BY d")?;
+    assert_eq!(result.collect_column::<BigDecimal>(0)?, [big.clone(), half.clone()]);
+    let row = conn.query("SELECT d FROM big ORDER BY d")?.next().unwrap()?;
+    assert_eq!(BigDecimal::try_from(row.get("d").unwrap()), Ok(big));
+    // A narrow column reads as a Decimal, which converts just the same.
+    let row = conn.query("SELECT 0.5::DECIMAL(4, 1) AS d")?.next().unwrap()?;
+    assert_eq!(BigDecimal::try_from(row.get("d").unwrap()), Ok(half));
+
+    // 39 digits do not fit any DECIMAL.
+    let mut too_wide: BigDecimal = "1e38".parse().unwrap();
+    assert!(conn.execute_with("SELECT $1", &mut [&mut too_wide]).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn decimal_inside_list_reads_from_vector() -> better_duck_core::error::Result<()> {
+    use rust_decimal::Decimal;
+
+    let mut conn = open();
+    let row = conn.query("SELECT [1.5, NULL, -2.25]::DECIMAL(9, 2)[] AS l")?.next().unwrap()?;
+    assert_eq!(
+        row.get("l"),
+        Some(&DuckValue::List(vec![
+            DuckValue::Decimal(Decimal::new(150, 2)),
+            DuckValue::Null,
+            DuckValue::Decimal(Decimal::new(-225, 2)),
+        ]))
+    );
+    Ok(())
+}
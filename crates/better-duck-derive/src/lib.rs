@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Derive macros for `better-duck-core`.
+//!
+//! - `#[derive(FromRow)]` decodes a struct from a result row, matching each field to the
+//!   column of the same name.
+//! - `#[derive(AppendAble)]` appends a struct as one appender row, or binds it to
+//!   consecutive statement parameters, one field per column in declaration order.
+//!
+//! A field's column name can be overridden with `#[duck(rename = "...")]`.
+//!
+//! Use these through `better-duck-core`'s `derive` feature, which re-exports them next to
+//! the traits they implement; the generated code refers to `::better_duck_core`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, GenericArgument, LitStr,
+    PathArguments, Type,
+};
+
+/// Derives `FromRow` for a struct with named fields.
+///
+/// Each field is read from the column with the field's name, or the name given by
+/// `#[duck(rename = "...")]`, and converted with `TryFrom<&DuckValue>`. An `Option<T>`
+/// field is `None` when the column is `NULL`.
+///
+/// ```rust,ignore
+/// #[derive(FromRow)]
+/// struct User {
+///     id: i32,
+///     #[duck(rename = "user_name")]
+///     name: String,
+///     email: Option<String>,
+/// }
+/// ```
+#[proc_macro_derive(FromRow, attributes(duck))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_row(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `AppendAble` for a struct with named or unnamed fields.
+///
+/// Appending the struct appends every field in declaration order, so the fields must
+/// line up with the table's columns. Binding it to a statement at parameter `idx` binds
+/// the fields from `idx` on. Each field fills as many columns (or parameters) as its
+/// `appender_column_count` reports, one if unknown, so a field holding another derived
+/// struct or a tuple shifts the fields after it.
+///
+/// ```rust,ignore
+/// #[derive(AppendAble)]
+/// struct Row(i32, &'static str);
+///
+/// appender.append(&mut Row(1, "duck"))?;
+/// ```
+#[proc_macro_derive(AppendAble, attributes(duck))]
+pub fn derive_append_able(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_append_able(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand_from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match struct_fields(input, "FromRow")? {
+        Fields::Named(named) => &named.named,
+        other => {
+            return Err(syn::Error::new(
+                other.span(),
+                "FromRow can only be derived for structs with named fields",
+            ))
+        },
+    };
+
+    let inits = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named field");
+            let column = match column_rename(field)? {
+                Some(rename) => rename.value(),
+                None => ident.to_string().trim_start_matches("r#").to_owned(),
+            };
+            let value = quote! { ::better_duck_core::types::from_row::row_field(row, #column)? };
+            let init = match option_inner(&field.ty) {
+                Some(inner) => quote! {
+                    match #value {
+                        ::better_duck_core::types::value::DuckValue::Null => ::core::option::Option::None,
+                        value => ::core::option::Option::Some(
+                            <#inner as ::core::convert::TryFrom<&::better_duck_core::types::value::DuckValue>>::try_from(value)?,
+                        ),
+                    }
+                },
+                None => {
+                    let ty = &field.ty;
+                    quote! {
+                        <#ty as ::core::convert::TryFrom<&::better_duck_core::types::value::DuckValue>>::try_from(#value)?
+                    }
+                },
+            };
+            Ok(quote! { #ident: #init })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::better_duck_core::types::from_row::FromRow for #name #ty_generics #where_clause {
+            fn from_row(
+                row: &::better_duck_core::DuckRow,
+            ) -> ::better_duck_core::error::Result<Self> {
+                ::core::result::Result::Ok(Self { #(#inits,)* })
+            }
+        }
+    })
+}
+
+fn expand_append_able(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = struct_fields(input, "AppendAble")?;
+    for field in fields.iter() {
+        // Positional binding ignores column names, but a malformed attribute is still an error.
+        column_rename(field)?;
+    }
+    let members: Vec<_> = fields.members().collect();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::better_duck_core::AppendAble for #name #ty_generics #where_clause {
+            fn stmt_append(
+                &mut self,
+                idx: u64,
+                stmt: ::better_duck_core::ffi::duckdb_prepared_statement,
+            ) -> ::better_duck_core::error::Result<()> {
+                let next = idx;
+                #(
+                    ::better_duck_core::AppendAble::stmt_append(&mut self.#members, next, stmt)?;
+                    let next = next
+                        + ::better_duck_core::AppendAble::appender_column_count(&self.#members)
+                            .unwrap_or(1) as u64;
+                )*
+                let _ = next;
+                ::core::result::Result::Ok(())
+            }
+
+            fn appender_append(
+                &mut self,
+                appender: ::better_duck_core::ffi::duckdb_appender,
+            ) -> ::better_duck_core::error::Result<()> {
+                #(::better_duck_core::AppendAble::appender_append(&mut self.#members, appender)?;)*
+                ::core::result::Result::Ok(())
+            }
+
+            fn appender_column_count(&self) -> ::core::option::Option<usize> {
+                ::core::option::Option::Some(
+                    0 #(+ ::better_duck_core::AppendAble::appender_column_count(&self.#members).unwrap_or(1))*
+                )
+            }
+        }
+    })
+}
+
+/// Returns the fields of `input`, or an error naming `derive` if it is not a struct.
+fn struct_fields<'a>(
+    input: &'a DeriveInput,
+    derive: &str,
+) -> syn::Result<&'a Fields> {
+    match &input.data {
+        Data::Struct(data) => Ok(&data.fields),
+        _ => Err(syn::Error::new(
+            input.ident.span(),
+            format!("{derive} can only be derived for structs"),
+        )),
+    }
+}
+
+/// Parses the field's `#[duck(rename = "...")]` attribute, if any.
+fn column_rename(field: &syn::Field) -> syn::Result<Option<LitStr>> {
+    let mut rename = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("duck")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported duck attribute, expected `rename = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(rename)
+}
+
+/// Returns `T` if `ty` is spelled `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    if path.qself.is_some() {
+        return None;
+    }
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    match args.args.first()? {
+        GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
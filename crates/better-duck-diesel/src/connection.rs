@@ -2,7 +2,7 @@
 
 use std::{marker::PhantomData, sync::Arc};
 
-use better_duck_core::CachedStatement;
+use better_duck_core::{AppendAble, CachedStatement};
 use diesel::{
     connection::{
         get_default_instrumentation, statement_cache::StatementCache, AnsiTransactionManager,
@@ -117,9 +117,10 @@ impl Connection for DuckDbConnection {
         )?;
 
         stmt.reset_bindings().map_err(|e| diesel::result::Error::from(DuckDbError::new(e)))?;
-        for (i, bind) in bc.binds.iter_mut().enumerate() {
-            stmt.bind((i + 1) as u64, bind)
-                .map_err(|e| diesel::result::Error::from(DuckDbError::new(e)))?;
+        let mut idx = 1;
+        for bind in bc.binds.iter_mut() {
+            stmt.bind(idx, bind).map_err(|e| diesel::result::Error::from(DuckDbError::new(e)))?;
+            idx += bind.appender_column_count().unwrap_or(1) as u64;
         }
 
         let mut res =
@@ -268,9 +269,10 @@ impl LoadConnection for DuckDbConnection {
         )?;
 
         stmt.reset_bindings().map_err(|e| diesel::result::Error::from(DuckDbError::new(e)))?;
-        for (i, bind) in bc.binds.iter_mut().enumerate() {
-            stmt.bind((i + 1) as u64, bind)
-                .map_err(|e| diesel::result::Error::from(DuckDbError::new(e)))?;
+        let mut idx = 1;
+        for bind in bc.binds.iter_mut() {
+            stmt.bind(idx, bind).map_err(|e| diesel::result::Error::from(DuckDbError::new(e)))?;
+            idx += bind.appender_column_count().unwrap_or(1) as u64;
         }
 
         // stmt's cache borrow ends here (NLL: last use of stmt is execute()).
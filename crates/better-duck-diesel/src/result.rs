@@ -48,8 +48,8 @@ impl From<DuckDbError> for diesel::result::Error {
             CE::InvalidColumnName(n) => {
                 DE::DeserializationError(format!("unknown column '{n}'").into())
             },
-            CE::DuckDBFailure(_, msg) => {
-                let msg = msg.unwrap_or_else(|| "duckdb error".to_owned());
+            CE::DuckDBFailure { message, .. } => {
+                let msg = message.unwrap_or_else(|| "duckdb error".to_owned());
                 DE::DatabaseError(K::Unknown, Box::new(msg))
             },
             other => DE::DatabaseError(K::Unknown, Box::new(format!("{other}"))),
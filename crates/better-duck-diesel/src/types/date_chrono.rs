@@ -75,7 +75,8 @@ impl ToSql<Timestamp, DuckDb> for NaiveDateTime {
 impl FromSql<Interval, DuckDb> for Duration {
     fn from_sql(val: DuckValueRef) -> deserialize::Result<Self> {
         match val {
-            DuckValueRef::Interval(v) => Ok(v),
+            // Months are collapsed to 30 days; `Duration` cannot hold them separately.
+            DuckValueRef::Interval(v) => Ok(v.to_duration_lossy()),
             _ => Err("Unexpected data for Duration type".into()),
         }
     }
@@ -86,7 +87,7 @@ impl ToSql<Interval, DuckDb> for Duration {
         &'b self,
         out: &mut Output<'b, '_, DuckDb>,
     ) -> serialize::Result {
-        out.set_value(DuckValueRef::Interval(*self));
+        out.set_value(DuckValueRef::Interval((*self).into()));
         Ok(IsNull::No)
     }
 }
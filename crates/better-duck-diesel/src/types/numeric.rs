@@ -52,5 +52,32 @@ impl_numeric_sql!(u128, DuckUHugeInt, UHugeInt);
 #[cfg(feature = "decimal")]
 use diesel::sql_types::Numeric;
 
+/// Implementation of `FromSql` for decimal values.
+///
+/// Columns wider than 28 digits (such as a `SUM` over a decimal) read as a
+/// `WideDecimal`; those convert when the value fits a `Decimal`.
 #[cfg(feature = "decimal")]
-impl_numeric_sql!(Decimal, Numeric, Decimal);
+impl FromSql<Numeric, DuckDb> for Decimal {
+    fn from_sql(val: DuckValueRef<'_>) -> deserialize::Result<Self> {
+        match val {
+            DuckValueRef::Decimal(v) => Ok(v),
+            DuckValueRef::WideDecimal(v) => {
+                Decimal::try_from_i128_with_scale(v.value, v.scale.into())
+                    .map_err(|_| format!("{v} does not fit a Decimal").into())
+            },
+            _ => Err("Unexpected data for numeric type".into()),
+        }
+    }
+}
+
+/// Implementation of `ToSql` for decimal values.
+#[cfg(feature = "decimal")]
+impl ToSql<Numeric, DuckDb> for Decimal {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, DuckDb>,
+    ) -> serialize::Result {
+        out.set_value(DuckValueRef::Decimal(*self));
+        Ok(IsNull::No)
+    }
+}
@@ -376,6 +376,24 @@ fn rt_f64() {
     assert!((v - x).abs() < 1e-14);
 }
 
+#[cfg(feature = "decimal")]
+#[test]
+fn rt_decimal_from_wide_column() {
+    // SUM over a DECIMAL(18, 2) column is a DECIMAL(38, 2), read as a WideDecimal.
+    use diesel::sql_types::Numeric;
+    use rust_decimal::Decimal;
+    #[derive(diesel::QueryableByName, Debug)]
+    struct Row {
+        #[diesel(sql_type = Numeric)]
+        val: Decimal,
+    }
+    let mut conn = conn_with("CREATE TABLE t_decimal (id INTEGER PRIMARY KEY, val DECIMAL(18, 2))");
+    conn.batch_execute("INSERT INTO t_decimal VALUES (1, 1.25), (2, -0.50)").unwrap();
+    let row: Row =
+        diesel::sql_query("SELECT sum(val) AS val FROM t_decimal").get_result(&mut conn).unwrap();
+    assert_eq!(row.val, Decimal::new(75, 2));
+}
+
 // ══════════════════════════════════════════════════════════════════════════
 // Tests: text
 // ══════════════════════════════════════════════════════════════════════════